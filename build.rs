@@ -0,0 +1,387 @@
+//! Cuts the generated icons down to the ones listed in the `DRUID_MATERIAL_ICONS` environment
+//! variable, e.g. `DRUID_MATERIAL_ICONS="add,delete,search"`, for apps that only use a few icons
+//! and don't want to compile the rest.
+//!
+//! The icons are listed by their upstream name. The cut-down code is written to `OUT_DIR` and
+//! `lib.rs` includes it instead of `src/icons.rs.in` when the `icon_allowlist` cfg is set.
+//! Without the variable, this does nothing.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::PathBuf,
+};
+
+const ALLOWLIST_VAR: &str = "DRUID_MATERIAL_ICONS";
+const ICONS: &str = "src/icons.rs.in";
+
+/// Icons the crate uses itself, which are always kept.
+const REQUIRED: &[&str] = &[
+    // `DEFAULT_ICON`.
+    "broken_image",
+    // The widgets.
+    "add",
+    "check_circle",
+    "chevron_right",
+    "clear",
+    "more_horiz",
+    "more_vert",
+    "remove",
+    "search",
+    // The prelude.
+    "arrow_back",
+    "arrow_forward",
+    "check",
+    "close",
+    "delete",
+    "edit",
+    "favorite",
+    "home",
+    "info",
+    "menu",
+    "refresh",
+    "settings",
+    "share",
+];
+
+fn main() {
+    println!("cargo:rerun-if-env-changed={}", ALLOWLIST_VAR);
+    println!("cargo:rerun-if-changed={}", ICONS);
+    println!("cargo:rustc-check-cfg=cfg(icon_allowlist)");
+    let allowlist = match env::var(ALLOWLIST_VAR) {
+        Ok(allowlist) => allowlist,
+        Err(_) => return,
+    };
+    let code = fs::read_to_string(ICONS).expect("reading the generated icons");
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+    fs::write(out.join("icons.rs.in"), allow(&code, &allowlist))
+        .expect("writing the allowed icons");
+    println!("cargo:rustc-cfg=icon_allowlist");
+}
+
+/// The icons the allowlist keeps, by the names the generated code refers to them with.
+struct Kept {
+    /// Upstream names.
+    names: BTreeSet<String>,
+    /// Constant names, by category.
+    consts: BTreeMap<String, BTreeSet<String>>,
+    /// `IconName` variants.
+    variants: BTreeSet<String>,
+}
+
+impl Kept {
+    fn has_category(&self, category: &str) -> bool {
+        self.consts.contains_key(category)
+    }
+
+    fn has_const(&self, category: &str, name: &str) -> bool {
+        self.consts
+            .get(category)
+            .is_some_and(|consts| consts.contains(name))
+    }
+
+    /// Whether every `IconName` variant `element` mentions is kept.
+    fn has_variants_of(&self, element: &str) -> bool {
+        element
+            .split("IconName::")
+            .skip(1)
+            .all(|rest| self.variants.contains(ident(rest)))
+    }
+}
+
+/// `code` with only the icons in `allowlist` and those the crate needs.
+fn allow(code: &str, allowlist: &str) -> String {
+    let aggregate = code
+        .find("    use crate::{Category, IconPaths};")
+        .expect("the generated code has an aggregate `ALL`");
+    let all_header = "pub const ALL: &[(&str, IconPaths)] = &[";
+    let all = elements(code, aggregate, all_header);
+    let variants = elements(code, 0, "pub enum IconName {");
+    assert_eq!(all.len(), variants.len(), "`IconName` lines up with `ALL`");
+
+    let mut names: BTreeSet<_> = allowlist
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect();
+    for name in &names {
+        if !all.iter().any(|element| icon_name(element) == name) {
+            panic!("{} lists `{}`, which isn't an icon", ALLOWLIST_VAR, name);
+        }
+    }
+    names.extend(REQUIRED.iter().map(|name| name.to_string()));
+    let mut kept = Kept {
+        names,
+        consts: BTreeMap::new(),
+        variants: BTreeSet::new(),
+    };
+    for (element, variant) in all.iter().zip(&variants) {
+        if kept.names.contains(icon_name(element)) {
+            // `("name", category::CONST)`
+            let path = all_entry_path(element);
+            let (category, name) = path.split_once("::").unwrap();
+            kept.consts
+                .entry(category.to_string())
+                .or_default()
+                .insert(ident(name).to_string());
+            kept.variants
+                .insert(ident(strip_attrs(variant)).to_string());
+        }
+    }
+
+    let mut code = allow_modules(code, &kept);
+    let aggregate = code.find("    use crate::{Category, IconPaths};").unwrap();
+    code = filter_list(&code, aggregate, all_header, |element| {
+        kept.names.contains(icon_name(element))
+    });
+    code = filter_list(
+        &code,
+        aggregate,
+        "pub const CATEGORIES: &[Category] = &[",
+        |element| kept.has_category(icon_name(element)),
+    );
+    code = allow_root_items(&code, &kept);
+    code = filter_list(&code, 0, "pub enum IconName {", |element| {
+        kept.variants.contains(ident(strip_attrs(element)))
+    });
+    for header in [
+        "pub const ALL: &'static [IconName] = &[",
+        "pub const MIRRORABLE: &[IconName] = &[",
+        "pub const ANCHORS: &[(IconName, Point)] = &[",
+        "pub const FILL_PAIRS: &[(IconName, IconName)] = &[",
+    ] {
+        code = filter_list(&code, 0, header, |element| kept.has_variants_of(element));
+    }
+    code
+}
+
+/// Drop the category modules and icon constants that aren't kept, relying on the generated
+/// code being formatted by rustfmt.
+fn allow_modules(code: &str, kept: &Kept) -> String {
+    let mut out = String::with_capacity(code.len() / 8);
+    // Attributes are held back until we know whether the item they belong to is kept.
+    let mut attrs = String::new();
+    let mut category: Option<&str> = None;
+    let mut skip_until: Option<&str> = None;
+    let mut in_all = false;
+    // An `ALL` entry too long for one line, which rustfmt wraps over several.
+    let mut entry: Option<String> = None;
+    for line in code.split_inclusive('\n') {
+        if let Some(wrapped) = &mut entry {
+            wrapped.push_str(line);
+            if line == "            ),\n" {
+                let wrapped = entry.take().unwrap();
+                if kept.has_const(category.unwrap(), all_entry_path(&wrapped)) {
+                    out.push_str(&attrs);
+                    out.push_str(&wrapped);
+                }
+                attrs.clear();
+            }
+            continue;
+        }
+        if let Some(end) = skip_until {
+            if line.trim_end() == end {
+                skip_until = None;
+            }
+            continue;
+        }
+        if line.trim_start().starts_with("#[") {
+            attrs.push_str(line);
+            continue;
+        }
+        if let Some(name) = line
+            .strip_prefix("    pub mod ")
+            .and_then(|rest| rest.strip_suffix(" {\n"))
+        {
+            if !kept.has_category(name) {
+                attrs.clear();
+                skip_until = Some("    }");
+                continue;
+            }
+            category = Some(name);
+            in_all = false;
+        } else if let (Some(category), Some(rest)) =
+            (category, line.strip_prefix("        pub const "))
+        {
+            let name = ident(rest);
+            if rest[name.len()..].starts_with(": IconPaths =") {
+                if !kept.has_const(category, name) {
+                    attrs.clear();
+                    skip_until = Some("        };");
+                    continue;
+                }
+            } else {
+                in_all = name == "ALL";
+            }
+        } else if in_all && line == "            (\n" {
+            entry = Some(line.to_string());
+            continue;
+        } else if in_all && line.starts_with("            (\"") {
+            if !kept.has_const(category.unwrap(), all_entry_path(line)) {
+                attrs.clear();
+                continue;
+            }
+        } else if line == "    }\n" {
+            category = None;
+            in_all = false;
+        }
+        out.push_str(&attrs);
+        attrs.clear();
+        out.push_str(line);
+    }
+    out
+}
+
+/// The path to the constant an entry of an `ALL` table refers to, whether it's on one line, like
+/// `("name", category::NAME),`, or rustfmt wrapped it over several.
+fn all_entry_path(entry: &str) -> &str {
+    entry
+        .trim_end()
+        .trim_end_matches(',')
+        .trim_end_matches(')')
+        .trim_end()
+        .trim_end_matches(',')
+        .rsplit(|c: char| c == ',' || c.is_whitespace())
+        .next()
+        .unwrap()
+}
+
+/// Drop or cut down the root re-exports and deprecated renames of icons that aren't kept.
+fn allow_root_items(code: &str, kept: &Kept) -> String {
+    let mut out = String::with_capacity(code.len());
+    let mut rest = code;
+    while let Some(start) = find_root_item(rest) {
+        let end = start + rest[start..].find(';').unwrap() + 1;
+        let item = &rest[start..end];
+        let body = match item.find("\npub ") {
+            Some(idx) if !item.starts_with("pub ") => &item[idx + 1..],
+            _ => item,
+        };
+        let path = body.split("normal::").nth(1).unwrap();
+        let (category, path) = path.split_once("::").unwrap();
+        out.push_str(&rest[..start]);
+        let keep = |element: &str| kept.has_const(category, ident(element.trim_start()));
+        if body.starts_with("pub use") && elements(item, 0, "{").into_iter().any(keep) {
+            out.push_str(&filter_list(item, 0, "{", keep));
+        } else if body.starts_with("pub const") && kept.has_const(category, ident(path)) {
+            out.push_str(item);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The start of the next root `pub use normal::` or `pub const _: IconPaths = normal::` item in
+/// `code`, including the attributes before it.
+fn find_root_item(code: &str) -> Option<usize> {
+    let mut attrs = None;
+    let mut offset = 0;
+    for line in code.split_inclusive('\n') {
+        if line.starts_with("#[") {
+            attrs.get_or_insert(offset);
+        } else if line.starts_with("pub use normal::")
+            || (line.starts_with("pub const ") && line.contains(": IconPaths = normal::"))
+        {
+            return Some(attrs.unwrap_or(offset));
+        } else {
+            attrs = None;
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// `code` with the elements of the list that opens at the end of `header` (the first after
+/// `from`) filtered by `keep`.
+fn filter_list(code: &str, from: usize, header: &str, keep: impl Fn(&str) -> bool) -> String {
+    let (open, close) = list(code, from, header);
+    let mut out = String::with_capacity(code.len());
+    out.push_str(&code[..open]);
+    for element in split_elements(&code[open..close]) {
+        if keep(element) {
+            out.push_str(element);
+            out.push(',');
+        }
+    }
+    out.push('\n');
+    out.push_str(&code[close..]);
+    out
+}
+
+/// The elements of the list that opens at the end of `header`.
+fn elements<'a>(code: &'a str, from: usize, header: &str) -> Vec<&'a str> {
+    let (open, close) = list(code, from, header);
+    split_elements(&code[open..close])
+}
+
+/// The span between the brackets of the list that opens at the end of `header`.
+fn list(code: &str, from: usize, header: &str) -> (usize, usize) {
+    let open = from
+        + code[from..]
+            .find(header)
+            .unwrap_or_else(|| panic!("the generated code has `{}`", header))
+        + header.len();
+    let mut depth = 0;
+    let mut in_str = false;
+    for (idx, c) in code[open..].char_indices() {
+        match c {
+            '"' => in_str = !in_str,
+            _ if in_str => (),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => return (open, open + idx),
+            ')' | ']' | '}' => depth -= 1,
+            _ => (),
+        }
+    }
+    panic!("`{}` isn't closed", header)
+}
+
+/// The comma separated elements of `body`, with their attributes.
+fn split_elements(body: &str) -> Vec<&str> {
+    let mut elements = vec![];
+    let mut depth = 0;
+    let mut in_str = false;
+    let mut start = 0;
+    for (idx, c) in body.char_indices() {
+        match c {
+            '"' => in_str = !in_str,
+            _ if in_str => (),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                elements.push(&body[start..idx]);
+                start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+    if !body[start..].trim().is_empty() {
+        elements.push(&body[start..]);
+    }
+    elements
+}
+
+/// `element` without the attributes before it.
+fn strip_attrs(element: &str) -> &str {
+    let mut element = element.trim_start();
+    while element.starts_with("#[") {
+        element = element[element.find(']').unwrap() + 1..].trim_start();
+    }
+    element
+}
+
+/// The first string literal in `element` after its attributes, which is the icon's name in an
+/// `ALL` table and the category's name in `CATEGORIES`.
+fn icon_name(element: &str) -> &str {
+    strip_attrs(element).split('"').nth(1).unwrap_or_default()
+}
+
+/// The identifier `s` starts with.
+fn ident(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
@@ -0,0 +1,13 @@
+//! Feeds arbitrary bytes to the generator's SVG conversion, which should reject malformed SVGs
+//! with an error rather than panic. Run with `cargo fuzz run svg` from `generate-icons`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../src/svg.rs"]
+mod svg;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = svg::paths(data, "fuzz");
+});
@@ -0,0 +1,110 @@
+//! Packing rasterized icons into a single spritesheet, for game engines and other consumers
+//! that want a texture rather than vector paths.
+
+use crate::{raster, Icons};
+use qu::ick_use::*;
+use serde::Serialize;
+use std::{collections::BTreeMap, fs, path::PathBuf};
+use tiny_skia::Pixmap;
+
+/// Empty pixels between icons, so that filtering doesn't bleed neighbours into each other.
+const PADDING: u32 = 1;
+
+#[derive(Parser)]
+pub struct Atlas {
+    /// The size of each icon in pixels.
+    #[clap(long, default_value = "32")]
+    size: u32,
+    /// The upstream names of the icons to include. Includes every icon if empty.
+    #[clap(long, multiple_values = true)]
+    icons: Vec<String>,
+    /// Where to write the PNG spritesheet. The metadata is written next to it, with a `.json`
+    /// extension.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+}
+
+impl Atlas {
+    pub fn run(&self, icons: &Icons) -> Result {
+        ensure!(self.size > 0, "icon size must be positive");
+        let selected = icons.select(&self.icons)?;
+        ensure!(!selected.is_empty(), "no icons to pack");
+
+        // Every sprite is the same size, so a near-square grid packs them perfectly.
+        let columns = (selected.len() as f64).sqrt().ceil() as u32;
+        let rows = (selected.len() as u32).div_ceil(columns);
+        let cell = self.size + PADDING;
+        let (width, height) = (columns * cell + PADDING, rows * cell + PADDING);
+        let mut pixmap = Pixmap::new(width, height).context("atlas too large")?;
+
+        let mut sprites = BTreeMap::new();
+        for (idx, icon) in selected.iter().enumerate() {
+            let idx = idx as u32;
+            let (x, y) = (
+                PADDING + (idx % columns) * cell,
+                PADDING + (idx / columns) * cell,
+            );
+            raster::render_into(icon, self.size, raster::WHITE, &mut pixmap.as_mut(), x, y)?;
+            sprites.insert(&*icon.name, Sprite::new(x, y, self.size, width, height));
+        }
+
+        pixmap
+            .save_png(&self.out)
+            .context(format!("writing {}", self.out.display()))?;
+        let meta_path = self.out.with_extension("json");
+        let meta = Metadata {
+            width,
+            height,
+            size: self.size,
+            sprites,
+        };
+        fs::write(&meta_path, serde_json::to_vec_pretty(&meta)?)
+            .context(format!("writing {}", meta_path.display()))?;
+        log::info!(
+            "packed {} icons into {} and {}",
+            selected.len(),
+            self.out.display(),
+            meta_path.display()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct Metadata<'a> {
+    /// The size of the spritesheet in pixels.
+    width: u32,
+    height: u32,
+    /// The size of each sprite in pixels.
+    size: u32,
+    sprites: BTreeMap<&'a str, Sprite>,
+}
+
+/// Where an icon is in the spritesheet, in pixels and in texture coordinates.
+#[derive(Serialize)]
+struct Sprite {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    u0: f64,
+    v0: f64,
+    u1: f64,
+    v1: f64,
+}
+
+impl Sprite {
+    fn new(x: u32, y: u32, size: u32, atlas_width: u32, atlas_height: u32) -> Self {
+        let (atlas_width, atlas_height) = (atlas_width as f64, atlas_height as f64);
+        Sprite {
+            x,
+            y,
+            width: size,
+            height: size,
+            u0: x as f64 / atlas_width,
+            v0: y as f64 / atlas_height,
+            u1: (x + size) as f64 / atlas_width,
+            v1: (y + size) as f64 / atlas_height,
+        }
+    }
+}
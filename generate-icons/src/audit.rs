@@ -0,0 +1,283 @@
+//! Auditing which icons survived into a compiled binary, to show apps what the icons cost them
+//! and which of the crate's pruning options would help.
+//!
+//! The path elements of an icon end up as arrays of `kurbo::PathEl` in the binary's data
+//! sections, so each icon is recognized by the coordinates of a run of elements that no other
+//! icon has. Icons with the same geometry as another icon can't be told apart, and are reported
+//! as unrecognizable. This relies on rustc's current layout of `PathEl`, which isn't
+//! guaranteed.
+
+use crate::{diff, Gates};
+use object::{Object, ObjectSection, SectionKind};
+use once_cell::sync::Lazy;
+use qu::ick_use::*;
+use regex::Regex;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::PathBuf,
+};
+
+static POINT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Point \{ x: (-?[\d.]+), y: (-?[\d.]+) \}").unwrap());
+
+/// The size of a `kurbo::PathEl` in the library, with its coordinates after an 8 byte tag.
+const PATH_EL_SIZE: usize = 56;
+
+/// The most elements in a row an icon is recognized by.
+const MAX_RUN: usize = 4;
+
+/// Above this fraction of a category's icons kept, its tables were probably kept whole.
+const WHOLE_TABLE: f64 = 0.9;
+
+#[derive(Parser)]
+pub struct Audit {
+    /// The compiled binary (or library) to audit.
+    #[clap(parse(from_os_str))]
+    binary: PathBuf,
+    /// The generated code the binary was built from.
+    #[clap(long, parse(from_os_str), default_value = "../src/icons.rs.in")]
+    file: PathBuf,
+    /// List the icons that were kept, not just the counts.
+    #[clap(long)]
+    list: bool,
+}
+
+/// An icon of the generated code, and how to spot it.
+struct Icon<'a> {
+    category: &'a str,
+    name: String,
+    /// The coordinates of each element of each path, as bytes.
+    paths: Vec<Vec<Vec<u8>>>,
+    /// Coordinates only this icon has, with their offsets from the first, if there are any.
+    signature: Option<Vec<(usize, Vec<u8>)>>,
+}
+
+/// Icon counts for a category.
+#[derive(Default)]
+struct Tally<'a> {
+    total: usize,
+    kept: Vec<&'a str>,
+    unrecognizable: usize,
+    kept_bytes: usize,
+}
+
+impl Audit {
+    pub fn run(&self) -> Result {
+        let code =
+            fs::read_to_string(&self.file).context(format!("reading {}", self.file.display()))?;
+        let mut icons = parse_icons(&code);
+        add_signatures(&mut icons);
+
+        let data = fs::read(&self.binary).context(format!("reading {}", self.binary.display()))?;
+        let binary = object::File::parse(&*data).context(format!(
+            "parsing {} as an executable",
+            self.binary.display()
+        ))?;
+        let mut found = vec![false; icons.len()];
+        for section in binary.sections().filter(|section| {
+            matches!(
+                section.kind(),
+                SectionKind::ReadOnlyData
+                    | SectionKind::ReadOnlyDataWithRel
+                    | SectionKind::ReadOnlyString
+                    | SectionKind::Data
+            )
+        }) {
+            let bytes = section
+                .data()
+                .context(format!("reading section {}", section.name().unwrap_or("?")))?;
+            find_signatures(bytes, &icons, &mut found);
+        }
+
+        let mut tallies: BTreeMap<&str, Tally> = BTreeMap::new();
+        for (icon, &found) in icons.iter().zip(&found) {
+            let tally = tallies.entry(icon.category).or_default();
+            tally.total += 1;
+            if icon.signature.is_none() {
+                tally.unrecognizable += 1;
+            } else if found {
+                tally.kept.push(&icon.name);
+                tally.kept_bytes += icon.paths.iter().map(Vec::len).sum::<usize>() * PATH_EL_SIZE;
+            }
+        }
+        self.report(&tallies)
+    }
+
+    fn report(&self, tallies: &BTreeMap<&str, Tally>) -> Result {
+        let gates = Gates::load()?;
+        let (mut kept, mut total, mut kept_bytes) = (0, 0, 0);
+        let mut whole = vec![];
+        println!(
+            "{:<16} {:>6} {:>6} {:>10}",
+            "category", "kept", "icons", "path data"
+        );
+        for (category, tally) in tallies {
+            println!(
+                "{:<16} {:>6} {:>6} {:>9}K",
+                category,
+                tally.kept.len(),
+                tally.total,
+                tally.kept_bytes / 1024
+            );
+            if self.list && !tally.kept.is_empty() {
+                println!("    {}", tally.kept.join(", "));
+            }
+            let recognizable = tally.total - tally.unrecognizable;
+            if recognizable > 0 && tally.kept.len() as f64 >= WHOLE_TABLE * recognizable as f64 {
+                whole.push(*category);
+            }
+            kept += tally.kept.len();
+            total += tally.total;
+            kept_bytes += tally.kept_bytes;
+        }
+        let unrecognizable: usize = tallies.values().map(|tally| tally.unrecognizable).sum();
+        println!(
+            "{} of {} icons kept, about {}K of path data ({} icons couldn't be recognized)",
+            kept,
+            total,
+            kept_bytes / 1024,
+            unrecognizable
+        );
+
+        if whole.is_empty() {
+            println!(
+                "the linker stripped the icons that aren't used, so there is nothing to prune"
+            );
+            return Ok(());
+        }
+        println!(
+            "the icon tables of {} were kept whole, probably because the binary looks icons up \
+            by name or iterates `ALL`, `CATEGORIES` or `IconName::ALL`",
+            whole.join(", ")
+        );
+        for category in whole.iter().filter(|category| gates.is_heavy(category)) {
+            println!(
+                "if the binary doesn't need the {} icons, build with `--no-default-features \
+                --features druid`, without `category-{}`",
+                category, category
+            );
+        }
+        println!(
+            "to keep only the icons the binary needs, build with \
+            DRUID_MATERIAL_ICONS=\"<comma separated icon names>\""
+        );
+        Ok(())
+    }
+}
+
+/// The `normal` icons in the generated code.
+fn parse_icons(code: &str) -> Vec<Icon<'_>> {
+    diff::chunks(code)
+        .into_iter()
+        .filter_map(|chunk| match chunk {
+            diff::Chunk::Icon(("normal", category, _), item) => Some((category, item)),
+            _ => None,
+        })
+        .map(|(category, item)| {
+            let name = item
+                .split('"')
+                .nth(1)
+                .expect("icon constants start with their name")
+                .to_owned();
+            let paths = item
+                .split("IconPath {")
+                .skip(1)
+                .map(|path| path.split("PathEl::").skip(1).map(to_bytes).collect())
+                .collect();
+            Icon {
+                category,
+                name,
+                paths,
+                signature: None,
+            }
+        })
+        .collect()
+}
+
+/// Give each icon the longest run of elements that no other icon has, as its signature.
+fn add_signatures(icons: &mut [Icon]) {
+    let mut owners: HashMap<&[Vec<u8>], usize> = HashMap::new();
+    for icon in icons.iter() {
+        for run in runs(icon) {
+            *owners.entry(run).or_default() += 1;
+        }
+    }
+    let signatures: Vec<_> = icons
+        .iter()
+        .map(|icon| {
+            runs(icon)
+                .into_iter()
+                .filter(|run| owners[run] == 1)
+                .max_by_key(|run| run.iter().map(Vec::len).sum::<usize>())
+                .map(|run| {
+                    // Each element's coordinates, relative to the first's.
+                    run.iter()
+                        .enumerate()
+                        .filter(|(_, coords)| !coords.is_empty())
+                        .map(|(idx, coords)| (idx * PATH_EL_SIZE, coords.clone()))
+                        .collect()
+                })
+        })
+        .collect();
+    for (icon, signature) in icons.iter_mut().zip(signatures) {
+        icon.signature = signature;
+    }
+}
+
+/// The runs of up to `MAX_RUN` elements of `icon` that start with a point.
+fn runs<'a>(icon: &'a Icon) -> Vec<&'a [Vec<u8>]> {
+    let mut runs: Vec<&[Vec<u8>]> = icon
+        .paths
+        .iter()
+        .flat_map(|els| {
+            (0..els.len()).flat_map(move |start| {
+                (start + 1..=els.len().min(start + MAX_RUN)).map(move |end| &els[start..end])
+            })
+        })
+        // The search starts from the first element's coordinates.
+        .filter(|run| !run[0].is_empty())
+        .collect();
+    runs.sort();
+    runs.dedup();
+    runs
+}
+
+/// Mark the icons whose signatures appear in `bytes`.
+fn find_signatures(bytes: &[u8], icons: &[Icon], found: &mut [bool]) {
+    // Look signatures up by their first point, then check the rest.
+    let mut by_start: HashMap<&[u8], Vec<usize>> = HashMap::new();
+    for (idx, icon) in icons.iter().enumerate() {
+        if let Some(signature) = &icon.signature {
+            by_start.entry(&signature[0].1[..16]).or_default().push(idx);
+        }
+    }
+    // Coordinates are `f64`s, so they are aligned to 8 bytes.
+    for offset in (0..bytes.len().saturating_sub(15)).step_by(8) {
+        if let Some(candidates) = by_start.get(&bytes[offset..offset + 16]) {
+            for &idx in candidates {
+                let signature = icons[idx].signature.as_ref().unwrap();
+                if signature.iter().all(|(at, coords)| {
+                    bytes
+                        .get(offset + at..)
+                        .is_some_and(|bytes| bytes.starts_with(coords))
+                }) {
+                    found[idx] = true;
+                }
+            }
+        }
+    }
+}
+
+/// The coordinates of the points of a generated element, as they are laid out in memory on a
+/// little-endian target.
+fn to_bytes(el: &str) -> Vec<u8> {
+    POINT_REGEX
+        .captures_iter(el)
+        .flat_map(|caps| {
+            let (x, y): (f64, f64) = (caps[1].parse().unwrap(), caps[2].parse().unwrap());
+            [x.to_le_bytes(), y.to_le_bytes()]
+        })
+        .flatten()
+        .collect()
+}
@@ -0,0 +1,124 @@
+//! Comparing freshly generated code with what is checked in, for reviewing upstream bumps.
+
+use similar::TextDiff;
+use std::collections::BTreeMap;
+
+/// Icon constants keyed by `(variant, category, name)`, with the code defining them.
+type IconItems<'a> = BTreeMap<(&'a str, &'a str, &'a str), String>;
+
+/// Print a summary of the icons that differ between `existing` and `generated` code.
+///
+/// If `show_diff` is set, print a diff of each changed icon as well.
+pub fn report(existing: &str, generated: &str, show_diff: bool) {
+    let (old_icons, old_rest) = split(existing);
+    let (new_icons, new_rest) = split(generated);
+
+    let added = new_icons
+        .keys()
+        .filter(|key| !old_icons.contains_key(*key))
+        .collect::<Vec<_>>();
+    let removed = old_icons
+        .keys()
+        .filter(|key| !new_icons.contains_key(*key))
+        .collect::<Vec<_>>();
+    let changed = new_icons
+        .iter()
+        .filter_map(|(key, new)| Some((key, old_icons.get(key)?, new)))
+        .filter(|(_, old, new)| old != new)
+        .collect::<Vec<_>>();
+
+    for (label, keys) in [("added", &added), ("removed", &removed)] {
+        for (variant, category, name) in keys.iter() {
+            println!("{} {}::{}::{}", label, variant, category, name);
+        }
+    }
+    for ((variant, category, name), old, new) in &changed {
+        println!("changed {}::{}::{}", variant, category, name);
+        if show_diff {
+            print_diff(old, new);
+        }
+    }
+    if old_rest != new_rest {
+        println!("changed code outside of icon constants");
+        if show_diff {
+            print_diff(&old_rest, &new_rest);
+        }
+    }
+    println!(
+        "{} added, {} removed, {} changed, {} unchanged",
+        added.len(),
+        removed.len(),
+        changed.len(),
+        new_icons.len() - added.len() - changed.len()
+    );
+}
+
+/// Print a unified diff from `old` to `new`.
+fn print_diff(old: &str, new: &str) {
+    print!(
+        "{}",
+        TextDiff::from_lines(old, new)
+            .unified_diff()
+            .context_radius(2)
+    );
+}
+
+/// Split generated code into its icon constants and everything else.
+fn split(code: &str) -> (IconItems<'_>, String) {
+    let mut icons = IconItems::new();
+    let mut rest = String::new();
+    for chunk in chunks(code) {
+        match chunk {
+            Chunk::Icon(key, item) => {
+                icons.insert(key, item);
+            }
+            Chunk::Line(line) => {
+                rest.push_str(line);
+                rest.push('\n');
+            }
+        }
+    }
+    (icons, rest)
+}
+
+/// A piece of generated code.
+pub enum Chunk<'a> {
+    /// An icon constant keyed by `(variant, category, name)`, with the code defining it.
+    Icon((&'a str, &'a str, &'a str), String),
+    /// A line of anything else.
+    Line(&'a str),
+}
+
+/// Split generated code into icon constants and the lines between them, in order.
+///
+/// This relies on the code being formatted the way the generator formats it: variant and
+/// category modules open at the start of a line and one indent in respectively, and each icon
+/// constant is a block that starts with `pub const` and ends with `};` at the same indent.
+pub fn chunks(code: &str) -> Vec<Chunk<'_>> {
+    let mut chunks = vec![];
+    let (mut variant, mut category) = ("", "");
+    let mut lines = code.lines();
+    while let Some(line) = lines.next() {
+        if let Some(name) = line.strip_prefix("pub mod ") {
+            variant = name.trim_end_matches(" {");
+        } else if let Some(name) = line.strip_prefix("    pub mod ") {
+            category = name.trim_end_matches(" {");
+        } else if let Some(name) = line
+            .strip_prefix("        pub const ")
+            .and_then(|decl| decl.strip_suffix(": IconPaths = IconPaths {"))
+        {
+            let mut item = format!("{}\n", line);
+            for line in lines.by_ref() {
+                item.push_str(line);
+                item.push('\n');
+                if line == "        };" {
+                    break;
+                }
+            }
+            chunks.push(Chunk::Icon((variant, category, name), item));
+            continue;
+        }
+        chunks.push(Chunk::Line(line));
+    }
+    chunks
+}
@@ -0,0 +1,123 @@
+//! Finding icons that upstream ships under more than one name with the same geometry.
+//!
+//! Such icons could be generated as aliases of each other instead of as separate tables.
+
+use crate::{is_generated, Icon, Icons};
+use kurbo::PathEl;
+use qu::ick_use::*;
+use std::collections::BTreeMap;
+
+#[derive(Parser)]
+pub struct Duplicates {
+    /// How far apart (in icon units) matching points may be for icons to count as near-identical.
+    /// Generated coordinates are rounded to 0.01, so anything smaller only finds exact
+    /// duplicates.
+    #[clap(long, default_value = "0.05")]
+    tolerance: f64,
+}
+
+impl Duplicates {
+    pub fn run(&self, icons: &Icons) -> Result {
+        ensure!(self.tolerance >= 0., "the tolerance can't be negative");
+        // Only icons with the same shape of path data can match, so bucket them by that first.
+        let mut buckets: BTreeMap<String, Vec<&Icon>> = BTreeMap::new();
+        for icon in icons
+            .0
+            .iter()
+            .filter(|(variant, _)| is_generated(variant))
+            .flat_map(|(_, categories)| categories.values())
+            .flat_map(|icons| icons.values())
+        {
+            buckets.entry(structure(icon)).or_default().push(icon);
+        }
+
+        let (mut identical, mut similar) = (0, 0);
+        for bucket in buckets.values().filter(|bucket| bucket.len() > 1) {
+            // Icons that generate exactly the same code.
+            let mut exact: BTreeMap<Vec<String>, Vec<&Icon>> = BTreeMap::new();
+            for &icon in bucket {
+                exact.entry(generated(icon)).or_default().push(icon);
+            }
+            for group in exact.values().filter(|group| group.len() > 1) {
+                identical += 1;
+                println!("identical: {}", names(group));
+            }
+            // Then groups of those that are close, each matched against its first member.
+            let mut groups: Vec<(Vec<&Icon>, f64)> = vec![];
+            for group in exact.values() {
+                let close = groups
+                    .iter_mut()
+                    .find(|(members, _)| distance(members[0], group[0]) <= self.tolerance);
+                match close {
+                    Some((members, worst)) => {
+                        *worst = worst.max(distance(members[0], group[0]));
+                        members.extend(group);
+                    }
+                    None => groups.push((group.clone(), 0.)),
+                }
+            }
+            for (members, worst) in groups.iter().filter(|(_, worst)| *worst > 0.) {
+                similar += 1;
+                println!("near-identical (within {:.2}): {}", worst, names(members));
+            }
+        }
+        log::info!(
+            "found {} groups of identical icons and {} groups of near-identical icons",
+            identical,
+            similar
+        );
+        Ok(())
+    }
+}
+
+/// A key that is the same for icons whose geometry could match: the size, and the kind of
+/// every path element and the opacity of every path.
+fn structure(icon: &Icon) -> String {
+    let mut key = format!("{}", icon.size);
+    for path in &icon.paths {
+        key.push_str(&format!("|{:.2}:", path.opacity));
+        key.extend(path.path.iter().map(|el| match el {
+            PathEl::MoveTo(_) => 'M',
+            PathEl::LineTo(_) => 'L',
+            PathEl::QuadTo(..) => 'Q',
+            PathEl::CurveTo(..) => 'C',
+            PathEl::ClosePath => 'Z',
+        }));
+    }
+    key
+}
+
+/// The largest distance between corresponding points of two icons with the same structure.
+fn distance(left: &Icon, right: &Icon) -> f64 {
+    let points = |icon: &Icon| {
+        icon.paths
+            .iter()
+            .flat_map(|path| path.path.iter())
+            .flat_map(|el| match el {
+                PathEl::MoveTo(p) | PathEl::LineTo(p) => vec![p],
+                PathEl::QuadTo(p1, p2) => vec![p1, p2],
+                PathEl::CurveTo(p1, p2, p3) => vec![p1, p2, p3],
+                PathEl::ClosePath => vec![],
+            })
+            .collect::<Vec<_>>()
+    };
+    points(left)
+        .iter()
+        .zip(points(right))
+        .map(|(l, r)| l.distance(r))
+        .fold(0., f64::max)
+}
+
+/// The generated code for each of the icon's paths.
+fn generated(icon: &Icon) -> Vec<String> {
+    icon.paths.iter().map(|path| path.to_string()).collect()
+}
+
+/// `category/name` of each icon, comma separated.
+fn names(icons: &[&Icon]) -> String {
+    icons
+        .iter()
+        .map(|icon| format!("{}/{}", icon.category, icon.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,74 @@
+//! Generating the crate's `examples` module: a few icons of each category in a small widget
+//! tree, as doctests, so that the generated icons are checked to work with the widgets.
+
+use crate::{is_generated, Gates, Icons};
+use qu::ick_use::*;
+use std::fmt::Write;
+
+/// How many icons of each category to show.
+const PER_CATEGORY: usize = 3;
+
+/// The source of the `examples` module.
+pub fn generate(icons: &Icons, gates: &Gates) -> Result<String> {
+    let mut out = String::from(
+        "//! A few icons of each category in small widget trees, to show how they are used.\n\
+        //!\n\
+        //! Generated by `generate-icons` along with the icons, so that the doctests here check\n\
+        //! every category against the widgets. Categories behind a feature are only checked with\n\
+        //! it.\n",
+    );
+    for (variant, categories) in icons.0.iter().filter(|(variant, _)| is_generated(variant)) {
+        for (category, icons) in categories {
+            // Icons named by numbers make for poor examples.
+            let shown = icons
+                .values()
+                .filter(|icon| !icon.name.starts_with(|c: char| c.is_ascii_digit()))
+                .take(PER_CATEGORY)
+                .collect::<Vec<_>>();
+            let (first, rest) = match shown.split_first() {
+                Some(split) => split,
+                None => continue,
+            };
+            let heavy = gates.is_heavy(category);
+            writeln!(out, "//!\n//! # {}\n//!", category)?;
+            if heavy {
+                writeln!(out, "//! Needs the `category-{}` feature.\n//!", category)?;
+            }
+            writeln!(out, "//! ```")?;
+            if heavy {
+                writeln!(
+                    out,
+                    "//! # #[cfg(feature = \"category-{}\")]\n//! # {{",
+                    category
+                )?;
+            }
+            writeln!(
+                out,
+                "//! use druid::widget::Flex;\n\
+                //! use druid::{{Color, Widget, WidgetExt}};\n\
+                //! use druid_material_icons::{{{}::{}, IconButton}};\n\
+                //!\n\
+                //! fn {1}_toolbar() -> impl Widget<()> {{\n\
+                //!     Flex::row()\n\
+                //!         .with_child(IconButton::new({1}::{}))",
+                variant,
+                category,
+                first.const_name()
+            )?;
+            for icon in rest {
+                writeln!(
+                    out,
+                    "//!         .with_child({}::{}.new(Color::BLACK).fix_size(24., 24.))",
+                    category,
+                    icon.const_name()
+                )?;
+            }
+            writeln!(out, "//! }}\n//! let _ = {}_toolbar();", category)?;
+            if heavy {
+                writeln!(out, "//! # }}")?;
+            }
+            writeln!(out, "//! ```")?;
+        }
+    }
+    Ok(out)
+}
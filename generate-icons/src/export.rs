@@ -0,0 +1,126 @@
+//! Exporting icon data for consumers outside of Rust.
+//!
+//! The exported data matches the generated Rust constants: the same icons, with coordinates
+//! rounded to the same precision.
+
+use crate::{is_generated, Icon, Icons};
+use clap::ArgEnum;
+use qu::ick_use::*;
+use serde::Serialize;
+use std::{fmt::Write, fs, path::PathBuf};
+
+#[derive(Parser)]
+pub struct Export {
+    /// The format to write.
+    #[clap(long, arg_enum, default_value = "json")]
+    format: Format,
+    /// Where to write the data.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum Format {
+    Json,
+    #[clap(name = "msgpack")]
+    MessagePack,
+}
+
+impl Export {
+    pub fn run(&self, icons: &Icons) -> Result {
+        let exported = icons
+            .0
+            .iter()
+            .filter(|(variant, _)| is_generated(variant))
+            .flat_map(|(_, categories)| categories.values())
+            .flat_map(|icons| icons.values())
+            .map(ExportedIcon::new)
+            .collect::<Vec<_>>();
+        let raw = match self.format {
+            Format::Json => serde_json::to_vec(&exported)?,
+            Format::MessagePack => rmp_serde::to_vec_named(&exported)?,
+        };
+        fs::write(&self.out, raw).context(format!("writing {}", self.out.display()))?;
+        log::info!(
+            "exported {} icons to {}",
+            exported.len(),
+            self.out.display()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct ExportedIcon<'a> {
+    name: &'a str,
+    category: &'a str,
+    variant: &'a str,
+    width: f64,
+    height: f64,
+    paths: Vec<ExportedPath>,
+}
+
+#[derive(Serialize)]
+struct ExportedPath {
+    /// SVG path data.
+    d: String,
+    opacity: f64,
+}
+
+impl<'a> ExportedIcon<'a> {
+    fn new(icon: &'a Icon) -> Self {
+        ExportedIcon {
+            name: &icon.name,
+            category: &icon.category,
+            variant: &icon.variant,
+            width: icon.size,
+            height: icon.size,
+            paths: icon
+                .paths
+                .iter()
+                .map(|path| ExportedPath {
+                    d: svg_path_data(&path.path),
+                    opacity: round(path.opacity),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Round to the precision used in the generated code.
+fn round(v: f64) -> f64 {
+    (v * 100.).round() / 100.
+}
+
+/// Format a path as SVG path data, rounding coordinates like the generated code does.
+pub fn svg_path_data(path: &kurbo::BezPath) -> String {
+    use kurbo::PathEl;
+    let mut out = String::new();
+    for el in path.iter() {
+        // Writing to a string can't fail.
+        let _ = match el {
+            PathEl::MoveTo(p) => write!(out, "M{} {}", round(p.x), round(p.y)),
+            PathEl::LineTo(p) => write!(out, "L{} {}", round(p.x), round(p.y)),
+            PathEl::QuadTo(p1, p2) => write!(
+                out,
+                "Q{} {} {} {}",
+                round(p1.x),
+                round(p1.y),
+                round(p2.x),
+                round(p2.y)
+            ),
+            PathEl::CurveTo(p1, p2, p3) => write!(
+                out,
+                "C{} {} {} {} {} {}",
+                round(p1.x),
+                round(p1.y),
+                round(p2.x),
+                round(p2.y),
+                round(p3.x),
+                round(p3.y)
+            ),
+            PathEl::ClosePath => write!(out, "Z"),
+        };
+    }
+    out
+}
@@ -0,0 +1,194 @@
+//! Downloading the upstream icon sources.
+//!
+//! We shell out to `git` rather than pulling in an http client: the upstream repositories are
+//! only published as git repositories, and a shallow fetch of a single revision is the cheapest
+//! way to get at them.
+//!
+//! Every revision we fetch is pinned to the commit it resolved to in `upstream.lock`, so if a tag
+//! is ever moved upstream we refuse to generate from it rather than silently picking up
+//! different icons.
+
+use qu::ick_use::*;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The repository holding the classic material icons.
+const DEFAULT_REPO: &str = "https://github.com/google/material-design-icons";
+
+#[derive(Parser)]
+pub struct Fetch {
+    /// The upstream tag, branch or commit to check out.
+    #[clap(long)]
+    rev: String,
+    /// The commit `rev` is expected to resolve to. Overrides any entry in `upstream.lock`.
+    #[clap(long)]
+    commit: Option<String>,
+    /// The git repository to fetch from (e.g. the Material Symbols repository).
+    #[clap(long, default_value = DEFAULT_REPO)]
+    repo: String,
+}
+
+impl Fetch {
+    /// Fetch `rev` from the default repository, checking it against `upstream.lock`.
+    pub fn release(rev: &str) -> Self {
+        Fetch {
+            rev: rev.to_owned(),
+            commit: None,
+            repo: DEFAULT_REPO.to_owned(),
+        }
+    }
+
+    /// Fetch `self.rev` into `dest`, creating the checkout if necessary.
+    pub fn run(&self, dest: &Path) -> Result {
+        if !dest.join(".git").exists() {
+            log::info!("initializing upstream checkout at {}", dest.display());
+            fs::create_dir_all(dest).context(format!("creating {}", dest.display()))?;
+            git(dest, &["init", "--quiet"])?;
+        }
+        log::info!("fetching {} from {}", self.rev, self.repo);
+        git(dest, &["fetch", "--depth", "1", &self.repo, &self.rev])?;
+        // Check what we fetched before checking it out, so a moved revision never replaces the
+        // sources we generated from last time.
+        let commit = git(dest, &["rev-parse", "FETCH_HEAD"])?;
+
+        let mut lock = Lock::load()?;
+        let key = (self.repo.clone(), self.rev.clone());
+        let expected = self.commit.as_ref().or_else(|| lock.0.get(&key));
+        match expected {
+            Some(expected) => ensure!(
+                *expected == commit,
+                "{} resolved to {}, but expected {} (if this is intended, update {})",
+                self.rev,
+                commit,
+                expected,
+                Lock::path().display()
+            ),
+            None => log::warn!(
+                "pinning {} to previously unseen commit {}",
+                self.rev,
+                commit
+            ),
+        }
+        git(dest, &["checkout", "--quiet", "--detach", &commit])?;
+        lock.0.insert(key, commit);
+        lock.save()
+    }
+}
+
+/// The commit checked out in `dir`, or `None` if it isn't a git checkout.
+pub fn head_commit(dir: &Path) -> Result<Option<String>> {
+    if !dir.join(".git").exists() {
+        return Ok(None);
+    }
+    git(dir, &["rev-parse", "HEAD"]).map(Some)
+}
+
+/// Check the commit checked out in `dir` against `upstream.lock`, returning it.
+///
+/// When generating from `release`, the checkout must be the commit that release is pinned to.
+/// Otherwise it must be one of the pinned commits. Sources that aren't a git checkout can't be
+/// checked, and are trusted.
+pub fn verify(dir: &Path, release: Option<&str>) -> Result<Option<String>> {
+    let commit = match head_commit(dir)? {
+        Some(commit) => commit,
+        None => return Ok(None),
+    };
+    let lock = Lock::load()?;
+    ensure!(
+        !lock.0.is_empty(),
+        "nothing is pinned in {} (run `generate-icons fetch`)",
+        Lock::path().display()
+    );
+    match release {
+        Some(release) => {
+            let key = (DEFAULT_REPO.to_owned(), release.to_owned());
+            let expected = lock.0.get(&key).with_context(|| {
+                format!(
+                    "{} isn't pinned in {} (run `generate-icons fetch --rev {}`)",
+                    release,
+                    Lock::path().display(),
+                    release
+                )
+            })?;
+            ensure!(
+                *expected == commit,
+                "{} has {} checked out, but {} is pinned to {}",
+                dir.display(),
+                commit,
+                release,
+                expected
+            );
+        }
+        None => ensure!(
+            lock.0.values().any(|pinned| *pinned == commit),
+            "{} has {} checked out, which isn't pinned in {} (run `generate-icons fetch`)",
+            dir.display(),
+            commit,
+            Lock::path().display()
+        ),
+    }
+    Ok(Some(commit))
+}
+
+/// Pinned commits, keyed by `(repository, revision)`.
+struct Lock(BTreeMap<(String, String), String>);
+
+impl Lock {
+    fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("upstream.lock")
+    }
+
+    fn load() -> Result<Self> {
+        let path = Self::path();
+        let mut lock = Lock(BTreeMap::new());
+        // The lock is checked in, so a missing one is an error rather than nothing pinned.
+        let raw = fs::read_to_string(&path).context(format!("reading {}", path.display()))?;
+        for line in raw.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(repo), Some(rev), Some(commit), None) => {
+                    lock.0
+                        .insert((repo.to_owned(), rev.to_owned()), commit.to_owned());
+                }
+                _ => bail!("malformed line in {}: {:?}", path.display(), line),
+            }
+        }
+        Ok(lock)
+    }
+
+    fn save(&self) -> Result {
+        let path = Self::path();
+        let mut out = String::from("# repository revision commit\n");
+        for ((repo, rev), commit) in &self.0 {
+            out.push_str(&format!("{} {} {}\n", repo, rev, commit));
+        }
+        fs::write(&path, out).context(format!("writing {}", path.display()))
+    }
+}
+
+/// Run a git command in `dir`, returning its trimmed stdout.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("running git (is it installed?)")?;
+    ensure!(
+        output.status.success(),
+        "`git {}` failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(String::from_utf8(output.stdout)
+        .context("non-utf8 git output")?
+        .trim()
+        .to_owned())
+}
@@ -0,0 +1,54 @@
+//! Formatting of the generated code.
+//!
+//! The generated code is checked in, so it should only change when the icons do. We format it
+//! ourselves rather than leaving it to whoever runs the generator, with every setting that
+//! affects the output pinned so that the local `rustfmt.toml` (or lack of one) doesn't matter.
+
+use qu::ick_use::*;
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Settings passed to rustfmt, overriding any config file it finds.
+const CONFIG: &str = "max_width=100,newline_style=Unix,hard_tabs=false,tab_spaces=4,\
+    reorder_imports=true,use_small_heuristics=Default";
+
+/// Format generated `source` with rustfmt.
+///
+/// The result always ends in exactly one newline, and has no trailing whitespace.
+pub fn rustfmt(source: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2018", "--emit", "stdout", "--quiet"])
+        .args(["--config", CONFIG])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("running rustfmt (is it installed?)")?;
+    // Write from another thread so that rustfmt can't block on a full stdout pipe while we block
+    // on a full stdin pipe.
+    let mut stdin = child.stdin.take().unwrap();
+    let source = source.to_owned();
+    let writer = std::thread::spawn(move || stdin.write_all(source.as_bytes()));
+    let output = child.wait_with_output().context("running rustfmt")?;
+    writer
+        .join()
+        .expect("rustfmt writer panicked")
+        .context("writing to rustfmt")?;
+    if !output.status.success() {
+        bail!(
+            "rustfmt failed on the generated code:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    let formatted = String::from_utf8(output.stdout).context("rustfmt output wasn't UTF-8")?;
+    let mut formatted = formatted
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    formatted.truncate(formatted.trim_end().len());
+    formatted.push('\n');
+    Ok(formatted)
+}
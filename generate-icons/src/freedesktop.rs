@@ -0,0 +1,157 @@
+//! Writing icons as a freedesktop.org icon theme, so Linux desktop apps can install them
+//! alongside the system icons.
+//!
+//! See the [icon theme specification](https://specifications.freedesktop.org/icon-theme-spec/)
+//! for the directory layout and `index.theme` format.
+
+use crate::{export::svg_path_data, raster, Icon, Icons};
+use qu::ick_use::*;
+use std::{fmt::Write, fs, path::PathBuf};
+use tiny_skia::Pixmap;
+
+#[derive(Parser)]
+pub struct Freedesktop {
+    /// The directory to create the theme in.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+    /// The name of the theme, which is also the name of its directory.
+    #[clap(long, default_value = "material-icons")]
+    name: String,
+    /// The sizes in pixels to rasterize icons at. A scalable SVG copy is always written too.
+    #[clap(long, multiple_values = true, default_values = &["16", "22", "24", "32", "48", "64", "128", "256"])]
+    sizes: Vec<u32>,
+    /// The theme context to put the icons in (e.g. `actions`, `apps`, `status`).
+    #[clap(long, default_value = "actions")]
+    context: String,
+    /// Prepended to each icon's upstream name, to avoid clashing with other themes' icons.
+    #[clap(long, default_value = "material-")]
+    prefix: String,
+    /// The color to paint icons, as `RRGGBB` hex.
+    #[clap(long, default_value = "000000", parse(try_from_str = parse_color))]
+    color: [u8; 3],
+    /// The upstream names of the icons to include. Includes every icon if empty.
+    #[clap(long, multiple_values = true)]
+    icons: Vec<String>,
+}
+
+impl Freedesktop {
+    pub fn run(&self, icons: &Icons) -> Result {
+        let selected = icons.select(&self.icons)?;
+        let root = self.out.join(&self.name);
+        let mut directories = vec![];
+        for &size in &self.sizes {
+            ensure!(size > 0, "icon sizes must be positive");
+            let dir = format!("{0}x{0}/{1}", size, self.context);
+            fs::create_dir_all(root.join(&dir))
+                .context(format!("creating {}", root.join(&dir).display()))?;
+            for icon in &selected {
+                let mut pixmap = Pixmap::new(size, size).context("zero-sized icon")?;
+                raster::render_into(icon, size, self.color, &mut pixmap.as_mut(), 0, 0)?;
+                let path = root.join(&dir).join(self.file_name(icon, "png"));
+                pixmap
+                    .save_png(&path)
+                    .context(format!("writing {}", path.display()))?;
+            }
+            directories.push((dir, size, "Fixed"));
+        }
+
+        let dir = format!("scalable/{}", self.context);
+        fs::create_dir_all(root.join(&dir))
+            .context(format!("creating {}", root.join(&dir).display()))?;
+        for icon in &selected {
+            let path = root.join(&dir).join(self.file_name(icon, "svg"));
+            fs::write(&path, self.svg(icon)).context(format!("writing {}", path.display()))?;
+        }
+        directories.push((dir, 48, "Scalable"));
+
+        let path = root.join("index.theme");
+        fs::write(&path, self.index_theme(&directories))
+            .context(format!("writing {}", path.display()))?;
+        log::info!(
+            "wrote {} icons at {} sizes to {}",
+            selected.len(),
+            directories.len(),
+            root.display()
+        );
+        Ok(())
+    }
+
+    fn file_name(&self, icon: &Icon, extension: &str) -> String {
+        format!("{}{}.{}", self.prefix, icon.name, extension)
+    }
+
+    fn svg(&self, icon: &Icon) -> String {
+        let [r, g, b] = self.color;
+        let mut svg = format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="0 0 {0} {0}" fill="#{1:02x}{2:02x}{3:02x}">"##,
+            icon.size, r, g, b
+        );
+        for path in &icon.paths {
+            // Writing to a string can't fail.
+            let _ = if path.opacity < 1. {
+                write!(
+                    svg,
+                    r#"<path d="{}" fill-opacity="{:.2}"/>"#,
+                    svg_path_data(&path.path),
+                    path.opacity
+                )
+            } else {
+                write!(svg, r#"<path d="{}"/>"#, svg_path_data(&path.path))
+            };
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn index_theme(&self, directories: &[(String, u32, &str)]) -> String {
+        let mut out = String::new();
+        let names = directories
+            .iter()
+            .map(|(dir, _, _)| dir.as_str())
+            .collect::<Vec<_>>();
+        // Writing to a string can't fail.
+        let _ = writeln!(
+            out,
+            "[Icon Theme]\n\
+            Name={}\n\
+            Comment=Material design icons\n\
+            Inherits=hicolor\n\
+            Directories={}",
+            self.name,
+            names.join(",")
+        );
+        let context = capitalize(&self.context);
+        for (dir, size, kind) in directories {
+            let _ = writeln!(
+                out,
+                "\n[{}]\nSize={}\nContext={}\nType={}",
+                dir, size, context, kind
+            );
+            if *kind == "Scalable" {
+                let _ = writeln!(out, "MinSize=8\nMaxSize=512");
+            }
+        }
+        out
+    }
+}
+
+/// Parse a color written as `RRGGBB` hex, optionally with a leading `#`.
+fn parse_color(raw: &str) -> Result<[u8; 3]> {
+    let hex = raw.trim_start_matches('#');
+    ensure!(
+        hex.len() == 6 && hex.is_ascii(),
+        "expected a color like `1f1f1f`, got {:?}",
+        raw
+    );
+    let channel = |idx: usize| u8::from_str_radix(&hex[idx..idx + 2], 16);
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// `actions` -> `Actions`, as the spec's context names are capitalized.
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
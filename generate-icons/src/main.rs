@@ -1,138 +1,967 @@
-use heck::ShoutySnakeCase;
+use clap::Subcommand;
+use heck::{CamelCase, ShoutySnakeCase};
 use once_cell::sync::Lazy;
 use qu::ick_use::*;
 use regex::Regex;
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
+    ffi::OsStr,
     fmt::{self, Display, Write},
     fs,
-    io::Write as IoWrite,
     path::{Path, PathBuf},
     sync::Arc,
 };
-use usvg::Visibility;
+use svg::OpacityPath;
 
-static ICON_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+)px\.svg$").unwrap());
-const USE: &str = r#"
-use crate::{PathEl, Point, Size, IconPath, IconPaths};
-"#;
+mod atlas;
+mod audit;
+mod diff;
+mod duplicates;
+mod examples;
+mod export;
+mod fetch;
+mod format;
+mod freedesktop;
+mod masters;
+mod pack;
+// Shared with the crate, so that both write PDFs the same way.
+#[path = "../../src/pdf.rs"]
+mod pdf;
+mod preview;
+mod progress;
+mod raster;
+mod raw;
+mod svg;
+mod template;
+mod update;
+
+static ICON_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+)px$").unwrap());
+const USE: &str = "use crate::{IconPath, IconPaths, PathEl, Point, Size};";
+
+/// The `COUNT` and `NAMES` of a module with an `ALL` table, for tools that want to know what
+/// is in it without going through the icons. They're derived from `ALL` so that they stay right
+/// when features or the build script leave icons out.
+const COUNT_AND_NAMES: &str = "/// The number of icons in [`ALL`].
+pub const COUNT: usize = ALL.len();
+/// The names of the icons in [`ALL`], in the same order.
+pub const NAMES: &[&str] = &crate::names::<COUNT>(ALL);";
+
+/// Where we expect the upstream icons to be checked out, relative to this crate.
+const DEFAULT_UPSTREAM_PATH: &[&str] = &["..", "material-design-icons"];
 
 #[derive(Parser)]
 struct Opt {
-    #[clap(long, short, parse(from_os_str))]
+    /// Location of the upstream icon repository, `../material-design-icons` from this crate by
+    /// default.
+    #[clap(long, short, visible_alias = "input", parse(from_os_str))]
     path: Option<PathBuf>,
+    /// Generate icons even if their geometry spills outside their declared size.
+    #[clap(long)]
+    allow_out_of_bounds: bool,
+    /// Leave out the upstream icons that can't be loaded, with a warning for each, rather than
+    /// failing.
+    #[clap(long)]
+    skip_broken: bool,
+    /// Fetch and generate from this upstream release tag (e.g. `4.0.0`), rather than whatever
+    /// is checked out. The release is recorded in the generated code.
+    #[clap(long)]
+    release: Option<String>,
+    /// Where to write the generated code.
+    #[clap(long, parse(from_os_str), default_value = "icons.rs")]
+    out: PathBuf,
+    /// Where to write the generated `examples` module.
+    #[clap(long, parse(from_os_str), default_value = "examples.rs")]
+    examples_out: PathBuf,
+    /// Don't write anything, just summarize which icons would change compared to `--existing`.
+    #[clap(long)]
+    dry_run: bool,
+    /// Like `--dry-run`, but also print a diff of every changed icon.
+    #[clap(long)]
+    diff: bool,
+    /// The checked-in generated code to compare against with `--dry-run` or `--diff`.
+    #[clap(long, parse(from_os_str), default_value = "../src/icons.rs.in")]
+    existing: PathBuf,
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download or update the upstream icon sources.
+    Fetch(fetch::Fetch),
+    /// Report icons that upstream ships more than once under different names.
+    Duplicates(duplicates::Duplicates),
+    /// Export the generated icon data for consumers outside of Rust.
+    Export(export::Export),
+    /// Generate the weight and optical size masters of some Material Symbols, for animating
+    /// their weight and keeping their strokes consistent across sizes.
+    Masters(masters::Masters),
+    /// Pack rasterized icons into a PNG spritesheet with JSON metadata.
+    Atlas(atlas::Atlas),
+    /// Write icons as a freedesktop.org icon theme.
+    Freedesktop(freedesktop::Freedesktop),
+    /// Write icons as macOS template images for menu bar and tray icons.
+    Template(template::Template),
+    /// Write icons as flat plain-old-data tables, for memory-mapping or GPU upload.
+    Raw(raw::Raw),
+    /// Print an icon to the terminal.
+    Preview(preview::Preview),
+    /// Generate a companion pack of icons from a directory of SVGs of your own.
+    Pack(pack::Pack),
+    /// Report which icons a compiled binary kept, and how to prune the rest.
+    Audit(audit::Audit),
+    /// Regenerate some icons in the existing generated code, leaving everything else alone.
+    Update(update::Update),
 }
 
 #[qu::ick]
 fn main(opt: Opt) -> Result {
-    let icons = Icons::load(
-        opt.path
-            .as_deref()
-            .unwrap_or(Path::new("../material-design-icons")),
+    let default_upstream = DEFAULT_UPSTREAM_PATH
+        .iter()
+        .fold(PathBuf::from(env!("CARGO_MANIFEST_DIR")), |path, part| {
+            path.join(part)
+        });
+    let upstream = opt.path.as_deref().unwrap_or(&default_upstream);
+    match &opt.command {
+        Some(Command::Fetch(fetch)) => fetch.run(upstream),
+        Some(Command::Duplicates(duplicates)) => {
+            duplicates.run(&Icons::load(upstream, opt.skip_broken)?)
+        }
+        Some(Command::Export(export)) => export.run(&Icons::load(upstream, opt.skip_broken)?),
+        Some(Command::Atlas(atlas)) => atlas.run(&Icons::load(upstream, opt.skip_broken)?),
+        Some(Command::Freedesktop(theme)) => theme.run(&Icons::load(upstream, opt.skip_broken)?),
+        Some(Command::Template(template)) => template.run(&Icons::load(upstream, opt.skip_broken)?),
+        Some(Command::Raw(raw)) => raw.run(&Icons::load(upstream, opt.skip_broken)?),
+        Some(Command::Preview(preview)) => preview.run(&Icons::load(upstream, opt.skip_broken)?),
+        Some(Command::Pack(pack)) => pack.run(),
+        Some(Command::Masters(masters)) => masters.run(upstream),
+        Some(Command::Audit(audit)) => audit.run(),
+        Some(Command::Update(update)) => update.run(&Icons::load(upstream, opt.skip_broken)?),
+        None => {
+            if let Some(release) = &opt.release {
+                fetch::Fetch::release(release).run(upstream)?;
+            }
+            generate(upstream, &opt)
+        }
+    }
+}
+
+/// Generate `icons.rs` from the upstream sources.
+fn generate(upstream: &Path, opt: &Opt) -> Result {
+    let commit = fetch::verify(upstream, opt.release.as_deref())?;
+    let icons = Icons::load(upstream, opt.skip_broken)?;
+    check_bounds(&icons, opt.allow_out_of_bounds)?;
+    let _emit = progress::Phase::start("generating the code");
+    let mut out = String::new();
+    writeln!(
+        out,
+        "/// The upstream release the icons were generated from, if generated from a release.\n\
+        pub const UPSTREAM_RELEASE: Option<&str> = {};\n\
+        /// The upstream commit the icons were generated from, if known.\n\
+        pub const UPSTREAM_COMMIT: Option<&str> = {};\n\
+        /// The SPDX identifier of the license the upstream icons are released under.\n\
+        pub const UPSTREAM_LICENSE: &str = {:?};\n\
+        /// The height of capital letters in Roboto, as a fraction of the font size.\n\
+        pub const CAP_HEIGHT: f64 = {:?};",
+        option_literal(opt.release.as_deref()),
+        option_literal(commit.as_deref()),
+        upstream_license(upstream)?,
+        text_metric("cap-height")?
     )?;
-    let mut out = fs::File::create("icons.rs").context("creating `icons.rs`")?;
+    let gates = Gates::load()?;
+    let mut variants = vec![];
     for (variant, icons) in icons.0.iter() {
-        // We are generating way too much output, which slows down rustc a lot. I would love to
-        // hear any suggestions on how to improve this...
-        if &**variant != "normal" {
+        if !is_generated(variant) {
             continue;
         }
+        for category in &gates.heavy {
+            if !icons.contains_key(&**category) {
+                log::warn!("heavy category {} doesn't exist", category);
+            }
+        }
+        for name in &gates.always_built {
+            if !icons.values().any(|icons| icons.contains_key(&**name)) {
+                log::warn!("always built icon {} doesn't exist", name);
+            }
+        }
+        variants.push(variant);
         //writeln!(out, "#[cfg(feature = \"{0}\")]\npub mod {0} {{", variant)?;
+        // Generated coordinates sometimes happen to look like well-known constants.
+        writeln!(out, "#[allow(clippy::approx_constant)]")?;
         writeln!(out, "pub mod {0} {{", variant)?;
+        let mut code_sizes = BTreeMap::new();
         for (category, icons) in icons.iter() {
-            //writeln!(out, "#[cfg(feature = \"{0}\")]\npub mod {0} {{", category)?;
-            writeln!(out, "pub mod {0} {{", category)?;
-            writeln!(out, "{}", USE)?;
+            let mut module = String::new();
+            writeln!(module, "pub mod {0} {{", category)?;
+            writeln!(module, "//! The icons of the `{}` category.", category)?;
+            writeln!(module, "\n{}\n", USE)?;
+            let gates_icons = gates.gates_icons(category, icons.keys().map(|name| &**name));
+            let no_gates = Gates::default();
+            let module_gates = if gates_icons { &gates } else { &no_gates };
             for icon in icons.values() {
-                writeln!(out, "{}", icon.implement())?;
+                // `implement` starts with a blank line, which the gate has to go after.
+                let gate = module_gates.gate_icon(category, &icon.name);
+                writeln!(
+                    module,
+                    "\n{}{}",
+                    gate,
+                    icon.implement().to_string().trim_start()
+                )?;
             }
-            writeln!(out, "}}")?;
+            let all = icons.values().map(|icon| (icon, None)).collect();
+            writeln!(module, "{}", AllTable(all, module_gates))?;
+            writeln!(module, "{}", COUNT_AND_NAMES)?;
+            writeln!(module, "}}")?;
+            log::info!(
+                "{}: {} icons, {} bytes of code",
+                category,
+                icons.len(),
+                module.len()
+            );
+            // A module gated icon by icon still exists, with just its always built icons.
+            let module_gate = if gates_icons {
+                String::new()
+            } else {
+                gates.gate(category)
+            };
+            write!(out, "{}{}", module_gate, module)?;
+            code_sizes.insert(category, (module.len(), module_gate));
         }
+        // Aggregate every category's icons into a single table, sorted by name.
+        let mut all = icons
+            .iter()
+            .flat_map(|(category, icons)| icons.values().map(move |icon| (icon, Some(&**category))))
+            .collect::<Vec<_>>();
+        all.sort_by(|(l, _), (r, _)| l.name.cmp(&r.name));
+        writeln!(out, "use crate::{{Category, IconPaths}};")?;
+        writeln!(out, "{}", AllTable(all.clone(), &gates))?;
+        write!(out, "pub const CATEGORIES: &[Category] = &[")?;
+        for (category, (code_size, module_gate)) in &code_sizes {
+            write!(
+                out,
+                "{}Category {{ name: \"{1}\", variant: \"{2}\", icons: {1}::ALL, names: {1}::NAMES, \
+                code_size: {3} }},",
+                module_gate,
+                category,
+                variant,
+                code_size
+            )?;
+        }
+        writeln!(out, "];")?;
+        writeln!(out, "{}", COUNT_AND_NAMES)?;
         writeln!(out, "}}")?;
+
+        // Make every icon available at the crate root as well.
+        let flat = flat_names(icons)?;
+        for (category, icons) in icons.iter() {
+            let mut by_gate = BTreeMap::<_, Vec<_>>::new();
+            for icon in icons.values() {
+                by_gate
+                    .entry(gates.gate_icon(category, &icon.name))
+                    .or_default()
+                    .push(icon);
+            }
+            for (gate, icons) in by_gate {
+                writeln!(
+                    out,
+                    "{}#[doc(no_inline)]\npub use {}::{}::{{",
+                    gate, variant, category
+                )?;
+                for icon in icons {
+                    let const_name = icon.const_name();
+                    let flat_name = &flat[&(category.clone(), icon.name.clone())];
+                    if *flat_name == const_name {
+                        write!(out, "{},", const_name)?;
+                    } else {
+                        write!(out, "{} as {},", const_name, flat_name)?;
+                    }
+                }
+                writeln!(out, "}};")?;
+            }
+        }
+
+        // Keep renamed icons compiling under their old names.
+        let taken = flat.values().collect::<BTreeSet<_>>();
+        for (old, new) in load_renames()? {
+            let old_name = default_const_name(&old);
+            let target = icons
+                .iter()
+                .find_map(|(category, icons)| icons.get(&*new).map(|icon| (category, icon)));
+            let (category, icon) = match target {
+                Some(target) => target,
+                None => {
+                    log::warn!("{} was renamed to {}, which doesn't exist", old, new);
+                    continue;
+                }
+            };
+            if taken.contains(&old_name) || RESERVED_NAMES.contains(&&*old_name) {
+                log::warn!(
+                    "{} was renamed to {}, but the old name is still in use",
+                    old,
+                    new
+                );
+                continue;
+            }
+            writeln!(
+                out,
+                "{}#[deprecated(note = \"renamed to `{}` upstream, use `{}` instead\")]\n\
+                pub const {}: IconPaths = {}::{}::{};",
+                gates.gate_icon(category, &icon.name),
+                new,
+                flat[&(category.clone(), icon.name.clone())],
+                old_name,
+                variant,
+                category,
+                icon.const_name()
+            )?;
+        }
+
+        // The discriminant of each name is its index in `ALL`, which the hand-written half of
+        // `IconName` relies on.
+        let mut variant_names = BTreeSet::new();
+        let mut names = vec![];
+        for (icon, category) in &all {
+            let flat_name = &flat[&(Arc::from(category.unwrap()), icon.name.clone())];
+            let mut name = flat_name.to_camel_case();
+            if matches!(name.chars().next(), Some(d) if d.is_ascii_digit()) {
+                name = format!("_{}", name);
+            }
+            ensure!(
+                variant_names.insert(name.clone()),
+                "more than one icon would be called IconName::{}",
+                name
+            );
+            names.push(name);
+        }
+        // Names of icons in heavy categories only exist with their category's feature.
+        let gated_names = all
+            .iter()
+            .zip(&names)
+            .map(|((icon, category), name)| {
+                format!("{}{}", gates.gate_icon(category.unwrap(), &icon.name), name)
+            })
+            .collect::<Vec<_>>();
+        writeln!(
+            out,
+            "/// The name of every `{0}` icon, in the same order as [`{0}::ALL`].\n\
+            #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]\n\
+            pub enum IconName {{ {1} }}\n\
+            impl IconName {{\n\
+            /// Every icon name, in the same order as [`{0}::ALL`].\n\
+            pub const ALL: &'static [IconName] = &[{2}];\n\
+            }}",
+            variant,
+            gated_names.join(","),
+            all.iter()
+                .zip(&names)
+                .map(|((icon, category), name)| format!(
+                    "{}IconName::{},",
+                    gates.gate_icon(category.unwrap(), &icon.name),
+                    name
+                ))
+                .collect::<String>()
+        )?;
+
+        let mirrorable = load_name_list("mirrorable.txt")?;
+        for name in &mirrorable {
+            if !all.iter().any(|(icon, _)| &*icon.name == name) {
+                log::warn!("mirrorable icon {} doesn't exist", name);
+            }
+        }
+        writeln!(
+            out,
+            "/// Icons that should be mirrored in right-to-left layouts, in name order.\n\
+            pub const MIRRORABLE: &[IconName] = &[{}];",
+            all.iter()
+                .zip(&names)
+                .filter(|((icon, _), _)| mirrorable.contains(&*icon.name))
+                .map(|((icon, category), name)| format!(
+                    "{}IconName::{},",
+                    gates.gate_icon(category.unwrap(), &icon.name),
+                    name
+                ))
+                .collect::<String>()
+        )?;
+
+        // Map pins point at what they mark with the bottom of the glyph.
+        let bottom_anchored = load_name_list("bottom-anchored.txt")?;
+        for name in &bottom_anchored {
+            if !all.iter().any(|(icon, _)| &*icon.name == name) {
+                log::warn!("bottom-anchored icon {} doesn't exist", name);
+            }
+        }
+        let mut anchors = String::new();
+        for ((icon, category), name) in all.iter().zip(&names) {
+            if bottom_anchored.contains(&*icon.name) {
+                use kurbo::Shape;
+                let bottom = icon
+                    .paths
+                    .iter()
+                    .map(|path| path.path.bounding_box().y1)
+                    .fold(f64::NEG_INFINITY, f64::max);
+                let anchor = kurbo::Point::new(icon.size / 2., bottom);
+                write!(
+                    anchors,
+                    "{}(IconName::{}, {}),",
+                    gates.gate_icon(category.unwrap(), &icon.name),
+                    name,
+                    KurboPoint(anchor)
+                )?;
+            }
+        }
+        writeln!(
+            out,
+            "/// Icons that mark a position with a point other than their center, with that point, \
+            in name order.\n\
+            pub const ANCHORS: &[(IconName, Point)] = &[{}];",
+            anchors
+        )?;
+
+        // Upstream names outlined versions of icons by suffixing the filled version's name.
+        let index = all
+            .iter()
+            .zip(&names)
+            .map(|((icon, category), name)| (&*icon.name, (name, category.unwrap(), &*icon.name)))
+            .collect::<BTreeMap<_, _>>();
+        let mut pairs = String::new();
+        for ((icon, category), name) in all.iter().zip(&names) {
+            let filled = ["_outline", "_outlined", "_border"]
+                .iter()
+                .find_map(|suffix| index.get(icon.name.strip_suffix(suffix)?));
+            if let Some((filled, filled_category, filled_icon)) = filled {
+                // Both icons have to exist for the pair to.
+                let mut gate = gates.gate_icon(category.unwrap(), &icon.name);
+                let filled_gate = gates.gate_icon(filled_category, filled_icon);
+                if filled_gate != gate {
+                    gate.push_str(&filled_gate);
+                }
+                write!(pairs, "{}(IconName::{}, IconName::{}),", gate, name, filled)?;
+            }
+        }
+        writeln!(
+            out,
+            "/// `(outlined, filled)` pairs of icons that are versions of each other, in name \
+            order of the outlined icon.\n\
+            pub const FILL_PAIRS: &[(IconName, IconName)] = &[{}];",
+            pairs
+        )?;
     }
+    writeln!(
+        out,
+        "/// Every generated style variant, as `(variant, icons)` pairs.\n\
+        pub const VARIANTS: &[(&str, &[(&str, IconPaths)])] = &["
+    )?;
+    for variant in variants {
+        write!(out, "(\"{0}\", {0}::ALL),", variant)?;
+    }
+    writeln!(out, "];")?;
+    let out = format::rustfmt(&out)?;
+    if opt.dry_run || opt.diff {
+        let existing = fs::read_to_string(&opt.existing)
+            .context(format!("reading {}", opt.existing.display()))?;
+        diff::report(&existing, &out, opt.diff);
+        return Ok(());
+    }
+    fs::write(&opt.out, out).context(format!("writing {}", opt.out.display()))?;
+    let examples = examples::generate(&icons, &gates)?;
+    fs::write(&opt.examples_out, examples)
+        .context(format!("writing {}", opt.examples_out.display()))?;
     Ok(())
 }
 
-struct Icons(BTreeMap<Arc<str>, BTreeMap<Arc<str>, BTreeMap<Arc<str>, Icon>>>);
+/// The SPDX identifier of the upstream license.
+///
+/// Upstream has always been Apache-2.0, but check so that a relicensing doesn't go unnoticed.
+fn upstream_license(upstream: &Path) -> Result<&'static str> {
+    let path = upstream.join("LICENSE");
+    let license = fs::read_to_string(&path).context(format!("reading {}", path.display()))?;
+    if !(license.contains("Apache License") && license.contains("Version 2.0")) {
+        bail!("{} is no longer the Apache License 2.0", path.display());
+    }
+    Ok("Apache-2.0")
+}
+
+/// Format an optional string as a Rust literal.
+fn option_literal(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".into(),
+    }
+}
+
+/// Whether we emit code for the given variant.
+fn is_generated(variant: &str) -> bool {
+    // We are generating way too much output, which slows down rustc a lot. I would love to
+    // hear any suggestions on how to improve this...
+    variant == "normal"
+}
+
+/// Check that every generated icon's geometry lies within its declared size.
+///
+/// Geometry outside the icon usually means we mishandled a transform or clip path upstream.
+/// Icons that are known to be fine are listed in `out-of-bounds.txt`.
+fn check_bounds(icons: &Icons, allow_out_of_bounds: bool) -> Result {
+    let known = load_name_list("out-of-bounds.txt")?;
+    let mut offenders = vec![];
+    for (variant, categories) in icons.0.iter().filter(|(v, _)| is_generated(v)) {
+        for icon in categories.values().flat_map(|icons| icons.values()) {
+            if known.contains(&*icon.name) {
+                continue;
+            }
+            if let Some(bbox) = icon.out_of_bounds() {
+                offenders.push(format!(
+                    "{}/{}/{} (size {}, geometry {:?})",
+                    variant, icon.category, icon.name, icon.size, bbox
+                ));
+            }
+        }
+    }
+    if offenders.is_empty() {
+        return Ok(());
+    }
+    if allow_out_of_bounds {
+        for offender in &offenders {
+            log::warn!("icon geometry out of bounds: {}", offender);
+        }
+        return Ok(());
+    }
+    bail!(
+        "{} icons have geometry outside their bounds (pass --allow-out-of-bounds to generate \
+        them anyway):\n{}",
+        offenders.len(),
+        offenders.join("\n")
+    )
+}
+
+/// Load a list of icon names, one per line, from a file next to this crate's manifest.
+///
+/// Blank lines and lines starting with `#` are ignored.
+fn load_name_list(file_name: &str) -> Result<BTreeSet<String>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(file_name);
+    let raw = fs::read_to_string(&path).context(format!("reading {}", path.display()))?;
+    Ok(raw
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_owned)
+        .collect())
+}
+
+/// The heavy categories, listed in `heavy-categories.txt`, which are only built with their
+/// `category-*` feature.
+///
+/// The icons listed in `always-built.txt` are built without their category's feature.
+#[derive(Default)]
+pub struct Gates {
+    heavy: BTreeSet<String>,
+    always_built: BTreeSet<String>,
+}
+
+impl Gates {
+    fn load() -> Result<Self> {
+        Ok(Gates {
+            heavy: load_name_list("heavy-categories.txt")?,
+            always_built: load_name_list("always-built.txt")?,
+        })
+    }
+
+    fn is_heavy(&self, category: &str) -> bool {
+        self.heavy.contains(category)
+    }
+
+    /// The attribute gating an item from `category`, or nothing if it isn't heavy.
+    fn gate(&self, category: &str) -> String {
+        if self.is_heavy(category) {
+            format!("#[cfg(feature = \"category-{}\")]", category)
+        } else {
+            String::new()
+        }
+    }
+
+    /// The attribute gating the icon `name` from `category`, or nothing if it's always built.
+    fn gate_icon(&self, category: &str, name: &str) -> String {
+        if self.always_built.contains(name) {
+            String::new()
+        } else {
+            self.gate(category)
+        }
+    }
+
+    /// Whether the module of `category` is gated icon by icon, because some of its icons are
+    /// always built, rather than as a whole.
+    fn gates_icons<'a>(&self, category: &str, mut names: impl Iterator<Item = &'a str>) -> bool {
+        self.is_heavy(category) && names.any(|name| self.always_built.contains(name))
+    }
+}
+
+/// Load the `(old, new)` pairs of upstream names from `renames.txt`.
+fn load_renames() -> Result<Vec<(String, String)>> {
+    load_name_list("renames.txt")?
+        .into_iter()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(old), Some(new), None) => Ok((old.to_owned(), new.to_owned())),
+                _ => bail!("malformed line in renames.txt: {:?}", line),
+            }
+        })
+        .collect()
+}
+
+/// The metric called `name` from `text-metrics.txt`.
+fn text_metric(name: &str) -> Result<f64> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("text-metrics.txt");
+    let raw = fs::read_to_string(&path).context(format!("reading {}", path.display()))?;
+    for line in raw.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once(' ') {
+            Some((key, value)) if key == name => {
+                return value.trim().parse().context(format!(
+                    "parsing {} in {}",
+                    name,
+                    path.display()
+                ))
+            }
+            Some(_) => (),
+            None => bail!("malformed line in {}: {:?}", path.display(), line),
+        }
+    }
+    bail!("{} is missing {}", path.display(), name)
+}
+
+/// Crate-root names, keyed by `(category, name)`.
+type FlatNames = BTreeMap<(Arc<str>, Arc<str>), String>;
+
+/// Names that are already taken at the crate root.
+const RESERVED_NAMES: &[&str] = &[
+    "ALL",
+    "ANCHORS",
+    "CAP_HEIGHT",
+    "DEFAULT_ICON",
+    "FILL_PAIRS",
+    "ICON_COUNT",
+    "MIRRORABLE",
+    "VARIANTS",
+];
+
+/// Choose a unique crate-root name for every icon in a variant.
+///
+/// Icons keep their constant name where possible. Where the same name appears in more than one
+/// category, the alphabetically first category keeps it and the others are suffixed with their
+/// category name, so the result doesn't depend on directory iteration order.
+fn flat_names(variant: &Variant) -> Result<FlatNames> {
+    let mut by_name: BTreeMap<String, Vec<(&Arc<str>, &Icon)>> = BTreeMap::new();
+    for (category, icons) in variant.iter() {
+        for icon in icons.values() {
+            by_name
+                .entry(icon.const_name().to_owned())
+                .or_default()
+                .push((category, icon));
+        }
+    }
+    let mut out = BTreeMap::new();
+    let mut taken = RESERVED_NAMES
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<BTreeSet<_>>();
+    taken.extend(by_name.keys().cloned());
+    for (name, icons) in by_name {
+        for (idx, (category, icon)) in icons.into_iter().enumerate() {
+            let flat_name = if idx == 0 && !RESERVED_NAMES.contains(&&*name) {
+                name.clone()
+            } else {
+                let suffixed = format!("{}_{}", name, category.to_shouty_snake_case());
+                ensure!(
+                    taken.insert(suffixed.clone()),
+                    "cannot disambiguate icon {}/{}: {} is already taken",
+                    category,
+                    icon.name,
+                    suffixed
+                );
+                log::warn!(
+                    "{} appears in more than one category, exporting {}/{} as {}",
+                    name,
+                    category,
+                    icon.name,
+                    suffixed
+                );
+                suffixed
+            };
+            out.insert((category.clone(), icon.name.clone()), flat_name);
+        }
+    }
+    Ok(out)
+}
+
+/// The constant name for an upstream icon name, before any disambiguation.
+fn default_const_name(name: &str) -> String {
+    let name = name.to_shouty_snake_case();
+    if matches!(name.chars().next(), Some(d) if d.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Whether an icon drawn at size `new` should replace one drawn at size `old`.
+///
+/// We prefer the 24px design size that most icons are drawn at, then the largest.
+fn prefer_size(new: f64, old: f64) -> bool {
+    const DESIGN_SIZE: f64 = 24.;
+    if old == DESIGN_SIZE {
+        false
+    } else {
+        new == DESIGN_SIZE || new > old
+    }
+}
+
+/// A file or directory of the upstream icons that couldn't be loaded.
+struct Failure {
+    path: PathBuf,
+    error: Error,
+}
+
+/// The entries of the directory at `path`, sorted so that runs don't depend on the order the
+/// filesystem lists them in. Entries that can't be read are added to `failures` instead.
+fn entries(path: &Path, failures: &mut Vec<Failure>) -> Vec<PathBuf> {
+    let dir = match fs::read_dir(path) {
+        Ok(dir) => dir,
+        Err(error) => {
+            failures.push(Failure {
+                path: path.to_owned(),
+                error: Error::new(error).context("reading directory"),
+            });
+            return vec![];
+        }
+    };
+    let mut entries = vec![];
+    for entry in dir {
+        match entry {
+            Ok(entry) => entries.push(entry.path()),
+            Err(error) => failures.push(Failure {
+                path: path.to_owned(),
+                error: Error::new(error).context("reading directory entry"),
+            }),
+        }
+    }
+    entries.sort();
+    entries
+}
+
+/// The subdirectories of `path`, with their names. Other entries, like the `desktop.ini` and
+/// `.DS_Store` files file managers leave around, are skipped.
+///
+/// Names end up in the generated code, so subdirectories whose names aren't UTF-8 are added to
+/// `failures` instead.
+fn subdirs(path: &Path, failures: &mut Vec<Failure>) -> Vec<(Arc<str>, PathBuf)> {
+    let mut subdirs = vec![];
+    for path in entries(path, failures) {
+        if !path.is_dir() {
+            log::debug!("skipping {}, which isn't a directory", path.display());
+            continue;
+        }
+        match path.file_name().and_then(OsStr::to_str) {
+            Some(name) => subdirs.push((name.into(), path)),
+            None => failures.push(Failure {
+                path,
+                error: format_err!("the directory name isn't UTF-8"),
+            }),
+        }
+    }
+    subdirs
+}
+
+/// The size of the icon at `path` from its filename, e.g. 24 for `24px.svg`.
+fn icon_size(path: &Path) -> Result<f64> {
+    let captures = path
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .and_then(|stem| ICON_REGEX.captures(stem))
+        .context("icon filename not in expected format")?;
+    captures
+        .get(1)
+        .unwrap()
+        .as_str()
+        .parse::<f64>()
+        .context("parsing icon size")
+}
+
+/// Report the upstream files that couldn't be loaded, as warnings if `skip_broken` is set and as
+/// an error otherwise.
+fn report_failures(failures: &[Failure], skip_broken: bool) -> Result {
+    if failures.is_empty() {
+        return Ok(());
+    }
+    let report = failures
+        .iter()
+        .map(|failure| format!("{}: {:#}", failure.path.display(), failure.error))
+        .collect::<Vec<_>>();
+    if skip_broken {
+        for line in &report {
+            log::warn!("skipping icon that couldn't be loaded: {}", line);
+        }
+        return Ok(());
+    }
+    bail!(
+        "{} upstream files couldn't be loaded (pass --skip-broken to leave them out and generate \
+        the rest anyway):\n{}",
+        failures.len(),
+        report.join("\n")
+    )
+}
+
+/// Icons in a category, keyed by name.
+type Category = BTreeMap<Arc<str>, Icon>;
+/// Categories in a variant, keyed by name.
+type Variant = BTreeMap<Arc<str>, Category>;
+
+struct Icons(BTreeMap<Arc<str>, Variant>);
 
 impl Icons {
     /// Load all found icons into memory.
-    fn load(root: impl AsRef<Path>) -> Result<Self> {
-        let mut icons = Icons(BTreeMap::new());
+    ///
+    /// Every icon is tried before icons that couldn't be loaded are reported together: as
+    /// warnings if `skip_broken` is set, leaving those icons out, and as an error otherwise.
+    fn load(root: impl AsRef<Path>, skip_broken: bool) -> Result<Self> {
+        let mut failures = vec![];
         let root = root.as_ref().join("src");
-        for entry in
-            fs::read_dir(&root).context(format!("reading root directory ({})", root.display()))?
-        {
-            let entry = entry?;
-            let category: Arc<str> = entry
-                .file_name()
-                .into_string()
-                .ok()
-                .context("non-utf8 category filename")?
-                .into();
-            for entry in fs::read_dir(&entry.path())? {
-                let entry = entry?;
-                let name: Arc<str> = entry
-                    .file_name()
-                    .into_string()
-                    .ok()
-                    .context("non-utf8 icon filename")?
-                    .into();
-                for entry in fs::read_dir(&entry.path())? {
-                    let entry = entry?;
-                    let variant = entry
-                        .file_name()
-                        .into_string()
-                        .ok()
-                        .context("non-utf8 variant filename")?;
-                    let variant = variant
-                        .strip_prefix("materialicons")
-                        .context("unexpected variant format")?;
-                    let variant: Arc<str> = if variant.is_empty() {
-                        "normal"
-                    } else {
-                        variant
-                    }
-                    .into();
-                    for entry in fs::read_dir(&entry.path())? {
-                        let entry = entry?;
-                        let filename = entry
-                            .file_name()
-                            .into_string()
-                            .ok()
-                            .context("non-utf8 file filename")?;
-                        let captures = ICON_REGEX
-                            .captures(&filename)
-                            .context("icon filename not in expected format")?;
-                        let size = captures
-                            .get(1)
-                            .unwrap()
-                            .as_str()
-                            .parse::<f64>()
-                            .context("parsing icon size")?;
-                        let path = entry.path();
-                        log::trace!("loading icon {}", path.display());
-                        let icon = Icon::from_path(
-                            &path,
-                            category.clone(),
-                            name.clone(),
-                            variant.clone(),
-                            size,
-                        )
-                        .with_context(|| format!("loading icon {}", path.display()))?;
-                        icons
-                            .0
-                            .entry(variant.clone())
-                            .or_default()
-                            .entry(category.clone())
-                            .or_default()
-                            .insert(name.clone(), icon);
+        fs::metadata(&root).context(format!("reading root directory ({})", root.display()))?;
+
+        let scan = progress::Phase::start("scanning the upstream icons");
+        let mut files = vec![];
+        for (category, path) in subdirs(&root, &mut failures) {
+            for (name, path) in subdirs(&path, &mut failures) {
+                for (variant, path) in subdirs(&path, &mut failures) {
+                    let variant: Arc<str> = match variant.strip_prefix("materialicons") {
+                        Some("") => "normal".into(),
+                        Some(variant) => variant.into(),
+                        None => {
+                            failures.push(Failure {
+                                path,
+                                error: format_err!("unexpected variant format"),
+                            });
+                            continue;
+                        }
+                    };
+                    for path in entries(&path, &mut failures) {
+                        if path.extension() != Some(OsStr::new("svg")) {
+                            log::debug!("skipping {}, which isn't an SVG", path.display());
+                            continue;
+                        }
+                        files.push((category.clone(), name.clone(), variant.clone(), path));
                     }
                 }
             }
         }
+        drop(scan);
+
+        let parse = progress::Phase::start("parsing the upstream icons");
+        let bar = progress::bar(files.len(), "parsing");
+        let mut icons = Icons(BTreeMap::new());
+        for (category, name, variant, path) in files {
+            bar.inc(1);
+            log::trace!("loading icon {}", path.display());
+            let loaded = icon_size(&path).and_then(|size| {
+                Icon::from_path(&path, category.clone(), name.clone(), variant.clone(), size)
+            });
+            let icon = match loaded {
+                Ok(icon) => icon,
+                Err(error) => {
+                    failures.push(Failure { path, error });
+                    continue;
+                }
+            };
+            let icons = icons
+                .0
+                .entry(variant)
+                .or_default()
+                .entry(category)
+                .or_default();
+            // Some icons come in more than one size. Keep the preferred one rather than
+            // whichever the filesystem listed last.
+            match icons.get(&name) {
+                Some(existing) if !prefer_size(icon.size, existing.size) => (),
+                _ => {
+                    icons.insert(name, icon);
+                }
+            }
+        }
+        bar.finish_and_clear();
+        drop(parse);
+        report_failures(&failures, skip_broken)?;
+        icons.disambiguate_const_names();
         Ok(icons)
     }
+
+    /// Make sure no two icons in a category share a constant name.
+    ///
+    /// Names that differ only in punctuation (e.g. `foo-bar` and `foo_bar`) map to the same
+    /// constant. The first in name order keeps it, and the rest get a numeric suffix.
+    fn disambiguate_const_names(&mut self) {
+        for (variant, categories) in self.0.iter_mut() {
+            for (category, icons) in categories.iter_mut() {
+                let mut taken = icons
+                    .values()
+                    .map(|icon| icon.const_name.clone())
+                    .collect::<BTreeSet<_>>();
+                let mut seen = BTreeSet::new();
+                for icon in icons.values_mut() {
+                    if seen.insert(icon.const_name.clone()) {
+                        continue;
+                    }
+                    let renamed = (2..)
+                        .map(|n| format!("{}_{}", icon.const_name, n))
+                        .find(|candidate| !taken.contains(candidate))
+                        .unwrap();
+                    log::warn!(
+                        "{}/{}/{} collides with another icon as {}, generating it as {}",
+                        variant,
+                        category,
+                        icon.name,
+                        icon.const_name,
+                        renamed
+                    );
+                    taken.insert(renamed.clone());
+                    seen.insert(renamed.clone());
+                    icon.const_name = renamed;
+                }
+            }
+        }
+    }
+
+    /// The generated icons with the given upstream names, or every generated icon if `names` is
+    /// empty, in name order.
+    fn select(&self, names: &[String]) -> Result<Vec<&Icon>> {
+        let mut by_name = BTreeMap::new();
+        for icon in self
+            .0
+            .iter()
+            .filter(|(variant, _)| is_generated(variant))
+            .flat_map(|(_, categories)| categories.values())
+            .flat_map(|icons| icons.values())
+        {
+            // Names are unique within a category but not across them. Categories are visited in
+            // name order, so the first category wins, like the crate-root re-exports.
+            by_name.entry(&*icon.name).or_insert(icon);
+        }
+        if names.is_empty() {
+            return Ok(by_name.into_values().collect());
+        }
+        let mut selected = names
+            .iter()
+            .map(|name| {
+                by_name
+                    .get(name.as_str())
+                    .copied()
+                    .context(format!("no icon named {}", name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        selected.sort_by(|l, r| l.name.cmp(&r.name));
+        selected.dedup_by(|l, r| l.name == r.name);
+        Ok(selected)
+    }
 }
 
 #[derive(Debug)]
@@ -140,6 +969,8 @@ struct Icon {
     category: Arc<str>,
     name: Arc<str>,
     variant: Arc<str>,
+    /// The name of the generated constant, unique within the icon's category.
+    const_name: String,
     size: f64,
     paths: Vec<OpacityPath>,
 }
@@ -153,26 +984,9 @@ impl Icon {
         size: f64,
     ) -> Result<Self> {
         let raw = fs::read(path).context(format!("reading {}", path.display()))?;
-        let opts = usvg::Options::default();
-        let doc = usvg::Tree::from_data(&raw, &opts.to_ref())?;
-        //println!("{:?}", doc.to_string(&Default::default()));
-        let mut children = doc.root().children();
-        let defs = children.next().context("expected children")?;
-        if defs.has_children() {
-            log::warn!(
-                "ignoring defs, will probably output incorrect icon ({}/{}/{})",
-                category,
-                name,
-                variant
-            );
-        }
-
-        let mut paths = vec![];
-        let mut transform = vec![];
-        for child in children {
-            handle_child(child, &mut transform, 1., &mut paths)?;
-        }
+        let paths = svg::paths(&raw, &format!("{}/{}/{}", category, name, variant))?;
         Ok(Self {
+            const_name: default_const_name(&name),
             category,
             name,
             variant,
@@ -181,110 +995,55 @@ impl Icon {
         })
     }
 
-    fn const_name(&self) -> String {
-        let name = self.name.to_shouty_snake_case();
-        if matches!(name.chars().next(), Some(d) if d.is_digit(10)) {
-            format!("_{}", name)
-        } else {
-            name
-        }
-    }
-
-    fn implement(&self) -> Implement {
-        Implement(self)
+    fn const_name(&self) -> &str {
+        &self.const_name
     }
-}
 
-/// Function to be called recursively to extract paths from svg.
-fn handle_child(
-    node: usvg::Node,
-    transform: &mut Vec<kurbo::Affine>,
-    mut opacity: f64,
-    paths: &mut Vec<OpacityPath>,
-) -> Result {
-    match &*node.borrow() {
-        usvg::NodeKind::Path(path) => {
-            if let Some(mut path) = handle_path(path) {
-                for aff in transform.iter().rev() {
-                    path = *aff * path;
-                }
-                paths.push(OpacityPath { path, opacity });
-            }
-        }
-        usvg::NodeKind::Group(group) => {
-            let (aff, opacity_change) = handle_group(group)?;
-            if let Some(aff) = aff {
-                transform.push(aff);
-            }
-            if let Some(op) = opacity_change {
-                opacity *= op
-            }
-            for child in node.children() {
-                handle_child(child, transform, opacity, paths)?;
-            }
-            if aff.is_some() {
-                transform.pop();
-            }
+    /// The bounding box of the icon's geometry, if it reaches outside of the icon's size.
+    fn out_of_bounds(&self) -> Option<kurbo::Rect> {
+        use kurbo::Shape;
+        // Allow for the rounding applied to the generated coordinates.
+        const EPSILON: f64 = 0.01;
+        let bbox = self
+            .paths
+            .iter()
+            .map(|path| path.path.bounding_box())
+            .reduce(|l, r| l.union(r))?;
+        let inside = bbox.x0 >= -EPSILON
+            && bbox.y0 >= -EPSILON
+            && bbox.x1 <= self.size + EPSILON
+            && bbox.y1 <= self.size + EPSILON;
+        if inside {
+            None
+        } else {
+            Some(bbox)
         }
-        other => log::warn!("unexpected node type {:?}", other),
     }
-    Ok(())
-}
 
-/// Check that the group makes no difference
-fn handle_group(input: &usvg::Group) -> Result<(Option<kurbo::Affine>, Option<f64>)> {
-    ensure!(input.id == "");
-    // transform below
-    let transform = if input.transform != usvg::Transform::new(1., 0., 0., 1., 0., 0.) {
-        let t = input.transform;
-        Some(kurbo::Affine::new([t.a, t.b, t.c, t.d, t.e, t.f]))
-    } else {
-        None
-    };
-    let opacity = if input.opacity.value() != 1. {
-        Some(input.opacity.value())
-    } else {
-        None
-    };
-    if input.clip_path.is_some() {
-        log::warn!("unhandled clip path");
+    /// A rough measure of how expensive the icon is to paint.
+    ///
+    /// Each path costs one, plus one for every point in it, so curves cost more than lines.
+    fn complexity(&self) -> usize {
+        use kurbo::PathEl;
+        self.paths
+            .iter()
+            .map(|path| {
+                1 + path
+                    .path
+                    .iter()
+                    .map(|el| match el {
+                        PathEl::MoveTo(_) | PathEl::LineTo(_) | PathEl::ClosePath => 1,
+                        PathEl::QuadTo(..) => 2,
+                        PathEl::CurveTo(..) => 3,
+                    })
+                    .sum::<usize>()
+            })
+            .sum()
     }
-    ensure!(input.mask.is_none());
-    ensure!(input.filter.is_empty());
-    ensure!(input.filter_fill.is_none());
-    ensure!(input.filter_stroke.is_none());
-    // ignore enable_background
-
-    Ok((transform, opacity))
-}
 
-fn handle_path(input: &usvg::Path) -> Option<kurbo::BezPath> {
-    if matches!(input.visibility, Visibility::Hidden) || input.fill.is_none() {
-        return None;
-    }
-    let mut bez_path = kurbo::BezPath::new();
-    for segment in input.data.0.iter().cloned() {
-        match segment {
-            usvg::PathSegment::MoveTo { x, y } => bez_path.move_to((x, y)),
-            usvg::PathSegment::LineTo { x, y } => bez_path.line_to((x, y)),
-            usvg::PathSegment::CurveTo {
-                x1,
-                y1,
-                x2,
-                y2,
-                x,
-                y,
-            } => bez_path.curve_to((x1, y1), (x2, y2), (x, y)),
-            usvg::PathSegment::ClosePath => bez_path.close_path(),
-        }
-    }
-    Some(bez_path)
-}
-
-#[derive(Debug)]
-pub struct OpacityPath {
-    path: kurbo::BezPath,
-    opacity: f64,
+    fn implement(&self) -> Implement<'_> {
+        Implement(self)
+    }
 }
 
 impl Display for OpacityPath {
@@ -355,17 +1114,56 @@ impl Display for Implement<'_> {
             f,
             r#"
 pub const {}: IconPaths = IconPaths {{
+    name: "{}",
+    category: "{}",
+    variant: "{}",
     paths: &[{}],
     size: {},
-}};
-        "#,
+    complexity: {},
+}};"#,
             self.0.const_name(),
+            self.0.name,
+            self.0.category,
+            self.0.variant,
             paths,
-            KurboSize(kurbo::Size::new(self.0.size, self.0.size))
+            KurboSize(kurbo::Size::new(self.0.size, self.0.size)),
+            self.0.complexity()
         )
     }
 }
 
+/// A table of `(name, icon)` pairs, each icon optionally qualified by its category module.
+///
+/// Icons from heavy categories are gated behind their category's feature, unless they're always
+/// built.
+pub struct AllTable<'a>(Vec<(&'a Icon, Option<&'a str>)>, &'a Gates);
+
+impl Display for AllTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("pub const ALL: &[(&str, IconPaths)] = &[")?;
+        for (icon, category) in &self.0 {
+            match category {
+                Some(category) => write!(
+                    f,
+                    "{}(\"{}\", {}::{}),",
+                    self.1.gate_icon(category, &icon.name),
+                    icon.name,
+                    category,
+                    icon.const_name()
+                )?,
+                None => write!(
+                    f,
+                    "{}(\"{}\", {}),",
+                    self.1.gate_icon(&icon.category, &icon.name),
+                    icon.name,
+                    icon.const_name()
+                )?,
+            }
+        }
+        f.write_str("];")
+    }
+}
+
 pub struct MaterialSize(kurbo::Size);
 
 impl Display for MaterialSize {
@@ -0,0 +1,197 @@
+//! Generating the weight and optical size masters of Material Symbols icons, for
+//! `Icon::with_weight_masters` and `Icon::with_optical_size_masters`.
+//!
+//! Upstream ships each symbol drawn at every weight and optical size, as separate SVGs under
+//! `symbols/web/{name}/materialsymbols{style}/`. All of them would make the crate many times
+//! bigger, so this writes a module with the masters of just the icons listed, for an app to
+//! include in its own crate.
+//!
+//! The weight masters are drawn at the default optical size, and the optical size masters at the
+//! default weight, both unfilled and at the default grade.
+
+use crate::{default_const_name, format, pack, svg, Icon};
+use once_cell::sync::Lazy;
+use qu::ick_use::*;
+use regex::Regex;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The axes in an upstream filename, between the icon's name and its optical size, e.g.
+/// `wght100gradN25fill1`.
+static AXES_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:wght(\d+))?(?:grad(N?\d+))?(fill1)?$").unwrap());
+
+/// The weight symbols are drawn at unless their filename says otherwise.
+const DEFAULT_WEIGHT: u32 = 400;
+/// The optical size the weight masters are drawn at.
+const DEFAULT_OPTICAL_SIZE: u32 = 24;
+
+#[derive(Parser)]
+pub struct Masters {
+    /// A file listing the icons to generate masters for, one name per line.
+    #[clap(long, parse(from_os_str))]
+    names: PathBuf,
+    /// The style of Material Symbols to use: `outlined`, `rounded` or `sharp`.
+    #[clap(long, default_value = "outlined")]
+    style: String,
+    /// The name the generated module uses for this crate.
+    #[clap(long, default_value = "druid_material_icons")]
+    crate_name: String,
+    /// Where to write the module.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+}
+
+impl Masters {
+    pub fn run(&self, upstream: &Path) -> Result {
+        let names = pack::read_names(&self.names)?;
+        ensure!(
+            !names.is_empty(),
+            "no icons listed in {}",
+            self.names.display()
+        );
+        let mut out = format!(
+            "//! Weight and optical size masters of Material Symbols ({}).\n\
+            //!\n\
+            //! Generated by `generate-icons masters`; don't edit by hand.\n\n\
+            use {}::{{IconPath, IconPaths, PathEl, Point, Size}};\n",
+            self.style, self.crate_name
+        );
+        for name in &names {
+            self.write_icon(&mut out, upstream, name)?;
+        }
+        let out = format::rustfmt(&out)?;
+        fs::write(&self.out, out).context(format!("writing {}", self.out.display()))?;
+        log::info!(
+            "wrote the masters of {} icons to {}",
+            names.len(),
+            self.out.display()
+        );
+        Ok(())
+    }
+
+    /// Write the masters of the icon called `name`, and tables of them along each axis.
+    fn write_icon(&self, out: &mut String, upstream: &Path, name: &str) -> Result {
+        let dir = upstream
+            .join("symbols")
+            .join("web")
+            .join(name)
+            .join(format!("materialsymbols{}", self.style));
+        let mut masters = BTreeMap::new();
+        let mut weights = vec![];
+        let mut optical_sizes = vec![];
+        for entry in fs::read_dir(&dir).context(format!("reading {}", dir.display()))? {
+            let path = entry?.path();
+            let axes = path
+                .file_name()
+                .and_then(|file| file.to_str())
+                .and_then(|file| parse_filename(name, file));
+            let (weight, optical_size) = match axes {
+                Some(axes) => axes,
+                None => continue,
+            };
+            if optical_size == DEFAULT_OPTICAL_SIZE {
+                weights.push((weight, optical_size));
+            }
+            if weight == DEFAULT_WEIGHT {
+                optical_sizes.push((weight, optical_size));
+            }
+            masters.insert((weight, optical_size), path);
+        }
+        ensure!(
+            !weights.is_empty() || !optical_sizes.is_empty(),
+            "no masters of {} in {}",
+            name,
+            dir.display()
+        );
+        weights.sort_unstable();
+        optical_sizes.sort_unstable_by_key(|&(_, optical_size)| optical_size);
+
+        let const_name = default_const_name(name);
+        let master_name = |(weight, optical_size): (u32, u32)| {
+            format!("{}_WGHT{}_OPSZ{}", const_name, weight, optical_size)
+        };
+        // The default weight at the default size is on both axes, so only write it once.
+        let used = weights
+            .iter()
+            .chain(&optical_sizes)
+            .collect::<BTreeSet<_>>();
+        for (&master, path) in masters.iter().filter(|(master, _)| used.contains(master)) {
+            let mut icon = self
+                .load(path, name)
+                .with_context(|| format!("loading {}", path.display()))?;
+            icon.const_name = master_name(master);
+            writeln!(out, "{}", icon.implement())?;
+        }
+        for (table, axis, masters, position) in [
+            ("WEIGHTS", "weight", &weights, 0),
+            ("OPTICAL_SIZES", "optical size", &optical_sizes, 1),
+        ] {
+            if masters.is_empty() {
+                continue;
+            }
+            writeln!(
+                out,
+                "\n/// The {} masters of `{}`, as `({}, icon)` pairs.\n\
+                pub const {}_{}: &[(f64, IconPaths)] = &[",
+                axis, name, axis, const_name, table
+            )?;
+            for &master in masters {
+                let value = [master.0, master.1][position];
+                writeln!(out, "({:?}, {}),", f64::from(value), master_name(master))?;
+            }
+            writeln!(out, "];")?;
+        }
+        Ok(())
+    }
+
+    /// Load the master at `path`, moved so that its view box is at the origin.
+    fn load(&self, path: &Path, name: &str) -> Result<Icon> {
+        let raw = fs::read(path).context(format!("reading {}", path.display()))?;
+        let doc = svg::parse(&raw).context(format!("parsing {}", path.display()))?;
+        let rect = doc.svg_node().view_box.rect;
+        ensure!(
+            rect.width() == rect.height(),
+            "{} doesn't have a square view box",
+            path.display()
+        );
+        let mut icon = Icon::from_path(
+            path,
+            "symbols".into(),
+            name.into(),
+            self.style.as_str().into(),
+            rect.width(),
+        )?;
+        // Newer releases draw symbols in a view box above the origin, at `0 -960 960 960`.
+        let offset = kurbo::Affine::translate((-rect.x(), -rect.y()));
+        for path in &mut icon.paths {
+            path.path.apply_affine(offset);
+        }
+        Ok(icon)
+    }
+}
+
+/// The weight and optical size of the upstream file called `file`, if it is an unfilled master of
+/// `name` at the default grade.
+fn parse_filename(name: &str, file: &str) -> Option<(u32, u32)> {
+    let rest = file.strip_prefix(name)?.strip_prefix('_')?;
+    let rest = rest.strip_suffix("px.svg")?;
+    let (axes, optical_size) = match rest.rsplit_once('_') {
+        Some((axes, optical_size)) => (axes, optical_size),
+        None => ("", rest),
+    };
+    let optical_size = optical_size.parse().ok()?;
+    let captures = AXES_REGEX.captures(axes)?;
+    if captures.get(2).is_some() || captures.get(3).is_some() {
+        return None;
+    }
+    let weight = match captures.get(1) {
+        Some(weight) => weight.as_str().parse().ok()?,
+        None => DEFAULT_WEIGHT,
+    };
+    Some((weight, optical_size))
+}
@@ -0,0 +1,157 @@
+//! Generating a companion pack: a module of icons from a directory of SVGs, e.g. brand logos,
+//! in the same format as the material icons so that the widgets can draw them.
+//!
+//! Each `name.svg` in the directory becomes an icon called `name`, whose category and variant
+//! are both the pack's name. The SVGs must have a square view box at the origin, and are
+//! converted like the upstream icons. The module's `ATTRIBUTION` credits whoever drew them, for
+//! `IconPaths::attribution`.
+
+use crate::{format, svg, AllTable, Gates, Icon, Icons, COUNT_AND_NAMES, USE};
+use qu::ick_use::*;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+#[derive(Parser)]
+pub struct Pack {
+    /// The directory of SVGs to generate the pack from.
+    #[clap(long, parse(from_os_str))]
+    dir: PathBuf,
+    /// The name of the pack, used as the category and variant of its icons.
+    #[clap(long)]
+    name: String,
+    /// Only generate the icons listed in this file, one name per line, e.g. to pick a few icons
+    /// out of a big collection.
+    #[clap(long, parse(from_os_str))]
+    names: Option<PathBuf>,
+    /// A file holding the module's doc comment, in markdown.
+    #[clap(long, parse(from_os_str))]
+    doc: PathBuf,
+    /// The SPDX identifier of the license the SVGs are under, e.g. `CC0-1.0`.
+    #[clap(long)]
+    license: String,
+    /// Who holds the copyright to the SVGs.
+    #[clap(long)]
+    copyright: String,
+    /// Where the SVGs come from.
+    #[clap(long)]
+    source: String,
+    /// Where to write the module.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+}
+
+impl Pack {
+    pub fn run(&self) -> Result {
+        let wanted = match &self.names {
+            Some(path) => Some(read_names(path)?),
+            None => None,
+        };
+        let pack: Arc<str> = self.name.as_str().into();
+        let mut icons = BTreeMap::new();
+        for entry in fs::read_dir(&self.dir).context(format!("reading {}", self.dir.display()))? {
+            let path = entry?.path();
+            let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(name) if path.extension().is_some_and(|ext| ext == "svg") => name,
+                _ => continue,
+            };
+            if matches!(&wanted, Some(wanted) if !wanted.iter().any(|wanted| wanted == name)) {
+                continue;
+            }
+            let icon = Icon::from_path(
+                &path,
+                pack.clone(),
+                name.into(),
+                pack.clone(),
+                square_size(&path)?,
+            )
+            .with_context(|| format!("loading icon {}", path.display()))?;
+            if let Some(bbox) = icon.out_of_bounds() {
+                log::warn!("{} reaches outside its view box, to {:?}", name, bbox);
+            }
+            icons.insert(icon.name.clone(), icon);
+        }
+        if let Some(wanted) = &wanted {
+            for name in wanted {
+                ensure!(
+                    icons.contains_key(name.as_str()),
+                    "{} isn't in {}",
+                    name,
+                    self.dir.display()
+                );
+            }
+        }
+        ensure!(!icons.is_empty(), "no SVGs in {}", self.dir.display());
+
+        // Packs are one category of one variant.
+        let mut icons = Icons(BTreeMap::from([(
+            pack.clone(),
+            BTreeMap::from([(pack.clone(), icons)]),
+        )]));
+        icons.disambiguate_const_names();
+        let icons = &icons.0[&pack][&pack];
+
+        let mut out = String::new();
+        let doc =
+            fs::read_to_string(&self.doc).context(format!("reading {}", self.doc.display()))?;
+        for line in doc.lines() {
+            writeln!(out, "//! {}", line)?;
+        }
+        writeln!(
+            out,
+            "//!\n//! Generated by `generate-icons pack`; don't edit by hand.\n\n{}",
+            USE
+        )?;
+        for icon in icons.values() {
+            writeln!(out, "{}", icon.implement())?;
+        }
+        writeln!(
+            out,
+            "/// Every icon in the pack as `(name, icon)` pairs, sorted by name.\n{}",
+            AllTable(
+                icons.values().map(|icon| (icon, None)).collect(),
+                &Gates::default()
+            )
+        )?;
+        writeln!(out, "{}", COUNT_AND_NAMES)?;
+        writeln!(
+            out,
+            "/// Where the pack's icons come from and the license they are under.\n\
+            pub const ATTRIBUTION: crate::Attribution = crate::Attribution {{ license: {:?}, \
+            copyright: {:?}, source: {:?}, release: None, commit: None }};",
+            self.license, self.copyright, self.source
+        )?;
+        let out = format::rustfmt(&out)?;
+        fs::write(&self.out, out).context(format!("writing {}", self.out.display()))?;
+        log::info!("wrote {} icons to {}", icons.len(), self.out.display());
+        Ok(())
+    }
+}
+
+/// The icon names listed in the file at `path`, one per line, skipping blanks and `#` comments.
+pub fn read_names(path: &Path) -> Result<Vec<String>> {
+    Ok(fs::read_to_string(path)
+        .context(format!("reading {}", path.display()))?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_owned)
+        .collect())
+}
+
+/// The side of the square view box of the SVG at `path`.
+fn square_size(path: &Path) -> Result<f64> {
+    let raw = fs::read(path).context(format!("reading {}", path.display()))?;
+    let doc = svg::parse(&raw).context(format!("parsing {}", path.display()))?;
+    let rect = doc.svg_node().view_box.rect;
+    ensure!(
+        rect.x() == 0. && rect.y() == 0. && rect.width() == rect.height(),
+        "{} doesn't have a square view box at the origin",
+        path.display()
+    );
+    Ok(rect.width())
+}
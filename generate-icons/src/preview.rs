@@ -0,0 +1,78 @@
+//! Printing an icon to the terminal, for inspecting the generated geometry without a GUI.
+//!
+//! Each character cell shows two pixels using the upper half block, the top pixel as the
+//! foreground color and the bottom pixel as the background.
+
+use crate::{raster, Icons};
+use qu::ick_use::*;
+use std::fmt::Write;
+use tiny_skia::Pixmap;
+
+/// The gray the icon's box is drawn in, so that stray geometry near the edges stands out.
+const BACKGROUND: u8 = 48;
+
+#[derive(Parser)]
+pub struct Preview {
+    /// The upstream name of the icon to show.
+    name: String,
+    /// The size of the icon in pixels. Each line of output shows two rows of pixels.
+    #[clap(long, default_value = "32")]
+    size: u32,
+    /// Only use block characters, without color, for terminals that don't support 24-bit color.
+    /// Pixels are either set or not.
+    #[clap(long)]
+    plain: bool,
+}
+
+impl Preview {
+    pub fn run(&self, icons: &Icons) -> Result {
+        ensure!(self.size > 0, "the icon size must be positive");
+        let icon = icons.select(std::slice::from_ref(&self.name))?[0];
+        let mut pixmap = Pixmap::new(self.size, self.size).context("zero-sized icon")?;
+        raster::render_into(icon, self.size, raster::WHITE, &mut pixmap.as_mut(), 0, 0)?;
+        // Round up to whole character cells; the missing bottom row counts as empty.
+        let alpha = |x: u32, y: u32| match pixmap.pixel(x, y) {
+            Some(pixel) => pixel.alpha(),
+            None => 0,
+        };
+        let mut out = format!(
+            "{}/{} ({}px, {} paths)\n",
+            icon.category,
+            icon.name,
+            icon.size,
+            icon.paths.len()
+        );
+        for y in (0..self.size).step_by(2) {
+            for x in 0..self.size {
+                let (top, bottom) = (alpha(x, y), alpha(x, y + 1));
+                if self.plain {
+                    out.push(match (top >= 128, bottom >= 128) {
+                        (false, false) => ' ',
+                        (true, false) => '▀',
+                        (false, true) => '▄',
+                        (true, true) => '█',
+                    });
+                } else {
+                    let (top, bottom) = (shade(top), shade(bottom));
+                    write!(
+                        out,
+                        "\x1b[38;2;{0};{0};{0}m\x1b[48;2;{1};{1};{1}m▀",
+                        top, bottom
+                    )?;
+                }
+            }
+            if !self.plain {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+        print!("{}", out);
+        Ok(())
+    }
+}
+
+/// The gray level of a white pixel with `alpha` drawn over the background.
+fn shade(alpha: u8) -> u8 {
+    let alpha = u32::from(alpha);
+    (u32::from(BACKGROUND) + (255 - u32::from(BACKGROUND)) * alpha / 255) as u8
+}
@@ -0,0 +1,42 @@
+//! Showing how a run of the generator is going: a progress bar while the icons are parsed, and
+//! how long each phase of the run took, to spot slow phases. Both follow the log level set by
+//! `-v` and `-q`, so `-q` hides them.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use qu::ick_use::*;
+use std::time::Instant;
+
+/// A phase of the run, whose duration is logged when it's dropped.
+pub struct Phase {
+    name: &'static str,
+    start: Instant,
+}
+
+impl Phase {
+    pub fn start(name: &'static str) -> Self {
+        log::debug!("{}", name);
+        Phase {
+            name,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for Phase {
+    fn drop(&mut self) {
+        log::info!("{} took {:.2?}", self.name, self.start.elapsed());
+    }
+}
+
+/// A progress bar up to `len`, hidden unless info messages are logged.
+pub fn bar(len: usize, message: &'static str) -> ProgressBar {
+    if log::max_level() < log::LevelFilter::Info {
+        return ProgressBar::hidden();
+    }
+    ProgressBar::new(len as u64)
+        .with_style(
+            ProgressStyle::with_template("{msg} {wide_bar} {pos}/{len} ({elapsed})")
+                .expect("the template is valid"),
+        )
+        .with_message(message)
+}
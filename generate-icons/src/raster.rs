@@ -0,0 +1,58 @@
+//! Rasterizing icons, for the exporters that produce images.
+
+use crate::Icon;
+use qu::ick_use::*;
+use tiny_skia::{FillRule, Paint, PathBuilder, PixmapMut, Transform};
+
+/// White glyphs let consumers tint them by multiplying with any color.
+pub const WHITE: [u8; 3] = [255, 255, 255];
+
+/// Render `icon` in `color` into `target`, with its top left corner at `(x, y)` and `size`
+/// pixels square.
+pub fn render_into(
+    icon: &Icon,
+    size: u32,
+    [r, g, b]: [u8; 3],
+    target: &mut PixmapMut,
+    x: u32,
+    y: u32,
+) -> Result {
+    let scale = size as f32 / icon.size as f32;
+    let transform = Transform::from_row(scale, 0., 0., scale, x as f32, y as f32);
+    for path in &icon.paths {
+        let skia_path = match to_skia(&path.path) {
+            Some(skia_path) => skia_path,
+            None => continue,
+        };
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(r, g, b, (path.opacity * 255.).round() as u8);
+        paint.anti_alias = true;
+        target.fill_path(&skia_path, &paint, FillRule::Winding, transform, None);
+    }
+    Ok(())
+}
+
+/// Convert a path to tiny-skia's representation, or `None` if it is empty.
+fn to_skia(path: &kurbo::BezPath) -> Option<tiny_skia::Path> {
+    use kurbo::PathEl;
+    let mut builder = PathBuilder::new();
+    for el in path.iter() {
+        match el {
+            PathEl::MoveTo(p) => builder.move_to(p.x as f32, p.y as f32),
+            PathEl::LineTo(p) => builder.line_to(p.x as f32, p.y as f32),
+            PathEl::QuadTo(p1, p2) => {
+                builder.quad_to(p1.x as f32, p1.y as f32, p2.x as f32, p2.y as f32)
+            }
+            PathEl::CurveTo(p1, p2, p3) => builder.cubic_to(
+                p1.x as f32,
+                p1.y as f32,
+                p2.x as f32,
+                p2.y as f32,
+                p3.x as f32,
+                p3.y as f32,
+            ),
+            PathEl::ClosePath => builder.close(),
+        }
+    }
+    builder.finish()
+}
@@ -0,0 +1,169 @@
+//! Writing icons in the plain-old-data layout of the crate's `raw` module.
+//!
+//! Icons are written in the same order as `IconName::ALL`, so the icon at index `i` is
+//! `IconName::ALL[i]`, with coordinates rounded like the generated code.
+
+use crate::{is_generated, Icon, Icons};
+use clap::ArgEnum;
+use kurbo::PathEl;
+use qu::ick_use::*;
+use std::{fmt::Write, fs, path::PathBuf};
+
+/// Must match `druid_material_icons::raw::MAGIC`.
+const MAGIC: u32 = u32::from_le_bytes(*b"MIR1");
+
+#[derive(Parser)]
+pub struct Raw {
+    /// The format to write.
+    #[clap(long, arg_enum, default_value = "rust")]
+    format: Format,
+    /// Where to write the data.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum Format {
+    /// Rust statics of the `raw` types.
+    Rust,
+    /// The binary layout read by `RawData::from_bytes`.
+    Bin,
+}
+
+/// The raw tables, as `RawIcon`, `RawPath` and `RawEl` fields.
+#[derive(Default)]
+struct Tables {
+    icons: Vec<(u32, u32, f32)>,
+    paths: Vec<(u32, u32, f32)>,
+    els: Vec<(u32, [f32; 6])>,
+}
+
+impl Raw {
+    pub fn run(&self, icons: &Icons) -> Result {
+        let mut all = icons
+            .0
+            .iter()
+            .filter(|(variant, _)| is_generated(variant))
+            .flat_map(|(_, categories)| categories.values())
+            .flat_map(|icons| icons.values())
+            .collect::<Vec<_>>();
+        // The same order as `ALL` and `IconName::ALL`.
+        all.sort_by(|l, r| l.name.cmp(&r.name));
+        let tables = Tables::new(&all);
+        let raw = match self.format {
+            Format::Rust => tables.to_rust().into_bytes(),
+            Format::Bin => tables.to_bin(),
+        };
+        fs::write(&self.out, raw).context(format!("writing {}", self.out.display()))?;
+        log::info!(
+            "wrote {} icons, {} paths and {} elements to {}",
+            tables.icons.len(),
+            tables.paths.len(),
+            tables.els.len(),
+            self.out.display()
+        );
+        Ok(())
+    }
+}
+
+impl Tables {
+    fn new(icons: &[&Icon]) -> Self {
+        let mut tables = Tables::default();
+        for icon in icons {
+            tables.icons.push((
+                tables.paths.len() as u32,
+                icon.paths.len() as u32,
+                icon.size as f32,
+            ));
+            for path in &icon.paths {
+                let first_el = tables.els.len() as u32;
+                tables.els.extend(path.path.iter().map(raw_el));
+                tables.paths.push((
+                    first_el,
+                    tables.els.len() as u32 - first_el,
+                    round(path.opacity),
+                ));
+            }
+        }
+        tables
+    }
+
+    fn to_rust(&self) -> String {
+        let mut out = String::new();
+        // Writing to a string can't fail.
+        let _ = writeln!(
+            out,
+            "use druid_material_icons::raw::{{RawEl, RawIcon, RawPath}};\n\
+            pub static ICONS: [RawIcon; {}] = [",
+            self.icons.len()
+        );
+        for (first_path, path_count, size) in &self.icons {
+            let _ = writeln!(
+                out,
+                "RawIcon {{ first_path: {}, path_count: {}, size: {:?} }},",
+                first_path, path_count, size
+            );
+        }
+        let _ = writeln!(
+            out,
+            "];\npub static PATHS: [RawPath; {}] = [",
+            self.paths.len()
+        );
+        for (first_el, el_count, opacity) in &self.paths {
+            let _ = writeln!(
+                out,
+                "RawPath {{ first_el: {}, el_count: {}, opacity: {:?} }},",
+                first_el, el_count, opacity
+            );
+        }
+        let _ = writeln!(
+            out,
+            "];\npub static ELEMENTS: [RawEl; {}] = [",
+            self.els.len()
+        );
+        for (op, coords) in &self.els {
+            let _ = writeln!(out, "RawEl {{ op: {}, coords: {:?} }},", op, coords);
+        }
+        out.push_str("];\n");
+        out
+    }
+
+    fn to_bin(&self) -> Vec<u8> {
+        let mut words = vec![
+            MAGIC,
+            self.icons.len() as u32,
+            self.paths.len() as u32,
+            self.els.len() as u32,
+        ];
+        for &(first, count, value) in self.icons.iter().chain(&self.paths) {
+            words.extend([first, count, value.to_bits()]);
+        }
+        for (op, coords) in &self.els {
+            words.push(*op);
+            words.extend(coords.iter().map(|coord| coord.to_bits()));
+        }
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+}
+
+/// `el` as a `RawEl` operation and coordinates.
+fn raw_el(el: PathEl) -> (u32, [f32; 6]) {
+    let mut coords = [0.; 6];
+    let (op, points) = match el {
+        PathEl::MoveTo(p) => (0, vec![p]),
+        PathEl::LineTo(p) => (1, vec![p]),
+        PathEl::QuadTo(p1, p2) => (2, vec![p1, p2]),
+        PathEl::CurveTo(p1, p2, p3) => (3, vec![p1, p2, p3]),
+        PathEl::ClosePath => (4, vec![]),
+    };
+    for (coords, point) in coords.chunks_exact_mut(2).zip(points) {
+        coords[0] = round(point.x);
+        coords[1] = round(point.y);
+    }
+    (op, coords)
+}
+
+/// Round to the precision used in the generated code.
+fn round(v: f64) -> f32 {
+    ((v * 100.).round() / 100.) as f32
+}
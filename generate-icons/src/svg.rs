@@ -0,0 +1,379 @@
+//! Converting an SVG to the paths of an icon.
+//!
+//! This is kept apart from the rest of the generator, which reads files and writes code, so that
+//! the fuzz target in `fuzz/` can feed it arbitrary input. Malformed SVGs should give an error,
+//! never a panic.
+
+use qu::ick_use::*;
+use usvg::Visibility;
+
+/// The largest number, in magnitude, allowed in an SVG. Icons are drawn on grids of a few dozen
+/// units, but usvg splits arcs into curves, and runs out of memory on arcs of astronomical size.
+const MAX_NUMBER: f64 = 1e9;
+
+/// Parse the SVG in `raw` with usvg, giving an error for input that would make it panic or run
+/// out of memory.
+pub fn parse(raw: &[u8]) -> Result<usvg::Tree> {
+    let text = std::str::from_utf8(raw).context("the SVG isn't UTF-8")?;
+    // Parse the XML the way usvg does, so that we check what it will read.
+    let opts = roxmltree::ParsingOptions { allow_dtd: true };
+    let doc = roxmltree::Document::parse_with_options(text, opts)?;
+    for node in doc.descendants() {
+        for attr in node.attributes() {
+            check_attribute(attr.name(), attr.value())?;
+        }
+        if let Some(style) = node.attribute("style") {
+            for declaration in simplecss::DeclarationTokenizer::from(style) {
+                check_attribute(declaration.name, declaration.value)?;
+            }
+        }
+        if node.has_tag_name("style") {
+            let sheet = simplecss::StyleSheet::parse(node.text().unwrap_or_default());
+            for declaration in sheet.rules.iter().flat_map(|rule| &rule.declarations) {
+                check_attribute(declaration.name, declaration.value)?;
+            }
+        }
+    }
+    let opts = usvg::Options::default();
+    Ok(usvg::Tree::from_xmltree(&doc, &opts.to_ref())?)
+}
+
+/// Check the numbers in an attribute, or a style declaration, as far as usvg would parse them.
+fn check_attribute(name: &str, value: &str) -> Result {
+    match name {
+        "d" => check_path(value),
+        "transform" | "gradientTransform" | "patternTransform" => {
+            use svgtypes::TransformListToken as Token;
+            for token in svgtypes::TransformListParser::from(value).map_while(Result::ok) {
+                match token {
+                    Token::Matrix { a, b, c, d, e, f } => check_numbers([a, b, c, d, e, f])?,
+                    Token::Translate { tx: x, ty: y } | Token::Scale { sx: x, sy: y } => {
+                        check_numbers([x, y])?
+                    }
+                    Token::Rotate { angle } | Token::SkewX { angle } | Token::SkewY { angle } => {
+                        check_numbers([angle])?
+                    }
+                }
+            }
+            Ok(())
+        }
+        "points" => check_numbers(svgtypes::PointsParser::from(value).flat_map(|(x, y)| [x, y])),
+        // Anything else with numbers in is a length or a list of them, like `r` or `viewBox`.
+        // Other values, like colors and ids, don't start with one.
+        _ => check_numbers(
+            svgtypes::LengthListParser::from(value)
+                .map_while(Result::ok)
+                .map(|length| length.number),
+        ),
+    }
+}
+
+/// Check the coordinates of path data, made absolute the way usvg does, up to the first error,
+/// where usvg stops reading it.
+fn check_path(data: &str) -> Result {
+    use kurbo::{Point, Vec2};
+    use svgtypes::PathSegment as Seg;
+
+    let mut current = Point::ORIGIN;
+    let mut subpath_start = Point::ORIGIN;
+    let mut closed = false;
+    for segment in svgtypes::PathParser::from(data).map_while(Result::ok) {
+        let at = |abs: bool, x: f64, y: f64| {
+            if abs {
+                Point::new(x, y)
+            } else {
+                current + Vec2::new(x, y)
+            }
+        };
+        let (points, other_numbers) = match segment {
+            Seg::MoveTo { abs, x, y }
+            | Seg::LineTo { abs, x, y }
+            | Seg::SmoothQuadratic { abs, x, y } => (vec![at(abs, x, y)], vec![]),
+            Seg::HorizontalLineTo { abs, x } => {
+                (vec![Point::new(at(abs, x, 0.).x, current.y)], vec![])
+            }
+            Seg::VerticalLineTo { abs, y } => {
+                (vec![Point::new(current.x, at(abs, 0., y).y)], vec![])
+            }
+            Seg::CurveTo {
+                abs,
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => (
+                vec![at(abs, x1, y1), at(abs, x2, y2), at(abs, x, y)],
+                vec![],
+            ),
+            Seg::SmoothCurveTo { abs, x2, y2, x, y } => {
+                (vec![at(abs, x2, y2), at(abs, x, y)], vec![])
+            }
+            Seg::Quadratic { abs, x1, y1, x, y } => (vec![at(abs, x1, y1), at(abs, x, y)], vec![]),
+            Seg::EllipticalArc {
+                abs,
+                rx,
+                ry,
+                x_axis_rotation,
+                x,
+                y,
+                ..
+            } => (vec![at(abs, x, y)], vec![rx, ry, x_axis_rotation]),
+            Seg::ClosePath { .. } => (vec![subpath_start], vec![]),
+        };
+        // usvg starts these from the end of the previous segment, and panics if that was a `z`.
+        let from_previous = matches!(
+            segment,
+            Seg::Quadratic { .. } | Seg::SmoothQuadratic { .. } | Seg::EllipticalArc { .. }
+        );
+        ensure!(
+            !(closed && from_previous),
+            "the path data has a quadratic curve or arc straight after a `z`"
+        );
+        check_numbers(
+            points
+                .iter()
+                .flat_map(|point| [point.x, point.y])
+                .chain(other_numbers),
+        )?;
+        current = *points.last().unwrap();
+        if let Seg::MoveTo { .. } = segment {
+            subpath_start = current;
+        }
+        closed = matches!(segment, Seg::ClosePath { .. });
+    }
+    Ok(())
+}
+
+/// Check that none of `numbers` is too big, or infinite or NaN.
+fn check_numbers(numbers: impl IntoIterator<Item = f64>) -> Result {
+    for number in numbers {
+        ensure!(
+            number.abs() <= MAX_NUMBER,
+            "the SVG has a number too big to be part of an icon: {:e}",
+            number
+        );
+    }
+    Ok(())
+}
+
+/// The paths of the SVG in `raw`, grouped by opacity. `name` identifies the SVG in warnings.
+pub fn paths(raw: &[u8], name: &str) -> Result<Vec<OpacityPath>> {
+    let doc = parse(raw)?;
+    //println!("{:?}", doc.to_string(&Default::default()));
+    let mut children = doc.root().children();
+    let defs = children.next().context("expected children")?;
+    if defs.has_children() {
+        log::warn!(
+            "ignoring defs, will probably output incorrect icon ({})",
+            name
+        );
+    }
+
+    let mut paths = vec![];
+    let mut transform = vec![];
+    for child in children {
+        handle_child(child, &mut transform, 1., &mut paths)?;
+    }
+    // Coordinates are written out as literals, which can't be infinite or NaN.
+    for path in &paths {
+        ensure!(
+            path.opacity.is_finite() && path.path.iter().all(is_finite),
+            "the paths don't have finite coordinates"
+        );
+    }
+    // Keep the paths of each opacity level together, so `IconPaths::groups` finds one group
+    // per level. Every path is filled with the same color, so this doesn't change the result.
+    let mut levels = vec![];
+    for path in &paths {
+        if !levels.contains(&path.opacity) {
+            levels.push(path.opacity);
+        }
+    }
+    paths.sort_by_key(|path| levels.iter().position(|&level| level == path.opacity));
+    Ok(paths)
+}
+
+#[derive(Debug)]
+pub struct OpacityPath {
+    pub path: kurbo::BezPath,
+    pub opacity: f64,
+}
+
+/// Function to be called recursively to extract paths from svg.
+fn handle_child(
+    node: usvg::Node,
+    transform: &mut Vec<kurbo::Affine>,
+    mut opacity: f64,
+    paths: &mut Vec<OpacityPath>,
+) -> Result {
+    match &*node.borrow() {
+        usvg::NodeKind::Path(path) => {
+            if let Some(OpacityPath {
+                mut path,
+                opacity: fill_opacity,
+            }) = handle_path(path)
+            {
+                for aff in transform.iter().rev() {
+                    path = *aff * path;
+                }
+                paths.push(OpacityPath {
+                    path,
+                    opacity: opacity * fill_opacity,
+                });
+            }
+        }
+        usvg::NodeKind::Group(group) => {
+            let (aff, opacity_change) = handle_group(group)?;
+            if let Some(aff) = aff {
+                transform.push(aff);
+            }
+            if let Some(op) = opacity_change {
+                opacity *= op
+            }
+            for child in node.children() {
+                handle_child(child, transform, opacity, paths)?;
+            }
+            if aff.is_some() {
+                transform.pop();
+            }
+        }
+        other => log::warn!("unexpected node type {:?}", other),
+    }
+    Ok(())
+}
+
+/// Check that the group makes no difference
+fn handle_group(input: &usvg::Group) -> Result<(Option<kurbo::Affine>, Option<f64>)> {
+    ensure!(input.id.is_empty(), "unexpected group id {:?}", input.id);
+    // transform below
+    let transform = if input.transform != usvg::Transform::new(1., 0., 0., 1., 0., 0.) {
+        let t = input.transform;
+        Some(kurbo::Affine::new([t.a, t.b, t.c, t.d, t.e, t.f]))
+    } else {
+        None
+    };
+    let opacity = if input.opacity.value() != 1. {
+        Some(input.opacity.value())
+    } else {
+        None
+    };
+    if input.clip_path.is_some() {
+        log::warn!("unhandled clip path");
+    }
+    ensure!(input.mask.is_none(), "masks aren't supported");
+    ensure!(
+        input.filter.is_empty() && input.filter_fill.is_none() && input.filter_stroke.is_none(),
+        "filters aren't supported"
+    );
+    // ignore enable_background
+
+    Ok((transform, opacity))
+}
+
+/// Convert a path, applying its own transform and fill opacity (but not those of its ancestors).
+fn handle_path(input: &usvg::Path) -> Option<OpacityPath> {
+    if matches!(input.visibility, Visibility::Hidden) {
+        return None;
+    }
+    let fill = input.fill.as_ref()?;
+    let mut bez_path = kurbo::BezPath::new();
+    for segment in input.data.0.iter().cloned() {
+        match segment {
+            usvg::PathSegment::MoveTo { x, y } => bez_path.move_to((x, y)),
+            usvg::PathSegment::LineTo { x, y } => bez_path.line_to((x, y)),
+            usvg::PathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => bez_path.curve_to((x1, y1), (x2, y2), (x, y)),
+            usvg::PathSegment::ClosePath => bez_path.close_path(),
+        }
+    }
+    let t = input.transform;
+    if t != usvg::Transform::default() {
+        bez_path = kurbo::Affine::new([t.a, t.b, t.c, t.d, t.e, t.f]) * bez_path;
+    }
+    // We fill everything with the non-zero rule, under which even-odd paths fill their holes in.
+    if fill.rule == usvg::FillRule::EvenOdd {
+        bez_path = even_odd_to_non_zero(&bez_path);
+    }
+    Some(OpacityPath {
+        path: bez_path,
+        opacity: fill.opacity.value(),
+    })
+}
+
+/// A path that fills the same area with the non-zero rule as `path` does with the even-odd rule.
+///
+/// Each contour is turned to wind the opposite way to the contour directly around it, so that
+/// nested contours take turns filling and cutting holes. This relies on contours not crossing,
+/// which holds for the upstream icons.
+fn even_odd_to_non_zero(path: &kurbo::BezPath) -> kurbo::BezPath {
+    use kurbo::{ParamCurve, PathEl, PathSeg, Shape, Vec2};
+    // Test points are moved off the exact coordinates of the path, where kurbo can count the
+    // winding wrong.
+    const NUDGE: Vec2 = Vec2::new(1.3e-6, 1.7e-6);
+
+    let mut contours = vec![];
+    for el in path.iter() {
+        if let PathEl::MoveTo(_) = el {
+            contours.push(kurbo::BezPath::new());
+        }
+        if let Some(contour) = contours.last_mut() {
+            contour.push(el);
+        }
+    }
+    let mut out = kurbo::BezPath::new();
+    for (idx, contour) in contours.iter().enumerate() {
+        let depth = contours
+            .iter()
+            .enumerate()
+            .filter(|&(outer_idx, outer)| {
+                outer_idx != idx
+                    && contour
+                        .segments()
+                        .all(|segment| outer.winding(segment.start() + NUDGE) != 0)
+            })
+            .count();
+        // Outermost contours wind positively, the contours in them negatively, and so on.
+        let positive = depth % 2 == 0;
+        if (contour.area() >= 0.) == positive {
+            out.extend(contour.iter());
+            continue;
+        }
+        let segments: Vec<_> = contour.segments().collect();
+        let start = match segments.last() {
+            Some(last) => last.end(),
+            None => continue,
+        };
+        out.move_to(start);
+        for segment in segments.iter().rev() {
+            match segment.reverse() {
+                PathSeg::Line(line) => out.line_to(line.p1),
+                PathSeg::Quad(quad) => out.quad_to(quad.p1, quad.p2),
+                PathSeg::Cubic(cubic) => out.curve_to(cubic.p1, cubic.p2, cubic.p3),
+            }
+        }
+        out.close_path();
+    }
+    out
+}
+
+/// Whether every point of `el` has finite coordinates.
+fn is_finite(el: kurbo::PathEl) -> bool {
+    use kurbo::PathEl;
+    let points = match el {
+        PathEl::MoveTo(p0) | PathEl::LineTo(p0) => vec![p0],
+        PathEl::QuadTo(p0, p1) => vec![p0, p1],
+        PathEl::CurveTo(p0, p1, p2) => vec![p0, p1, p2],
+        PathEl::ClosePath => vec![],
+    };
+    points
+        .iter()
+        .all(|point| point.x.is_finite() && point.y.is_finite())
+}
@@ -0,0 +1,151 @@
+//! Writing icons as macOS template images, for menu bar and tray icons that the system recolors
+//! to match its appearance.
+//!
+//! Template images are black with an alpha channel, and their file names end in `Template`.
+//! AppKit picks up the `@2x`/`@3x` versions automatically when loading the 1x name.
+
+use crate::{pdf, raster, Icon, Icons};
+use kurbo::PathEl;
+use qu::ick_use::*;
+use std::{collections::BTreeMap, fmt::Write, fs, path::PathBuf};
+use tiny_skia::Pixmap;
+
+/// Only the alpha channel of a template image is used, so the glyph is painted in black.
+const BLACK: [u8; 3] = [0, 0, 0];
+
+#[derive(Parser)]
+pub struct Template {
+    /// The directory to write the images to.
+    #[clap(long, parse(from_os_str))]
+    out: PathBuf,
+    /// The size of the icons in points. Menu bar icons are usually 16 to 18pt.
+    #[clap(long, default_value = "16")]
+    size: u32,
+    /// The scale factors to rasterize at.
+    #[clap(long, multiple_values = true, default_values = &["1", "2", "3"])]
+    scales: Vec<u32>,
+    /// Also write each icon as a single-page vector PDF, which scales to any resolution.
+    #[clap(long)]
+    pdf: bool,
+    /// The upstream names of the icons to include. Includes every icon if empty.
+    #[clap(long, multiple_values = true)]
+    icons: Vec<String>,
+}
+
+impl Template {
+    pub fn run(&self, icons: &Icons) -> Result {
+        ensure!(self.size > 0, "the icon size must be positive");
+        let selected = icons.select(&self.icons)?;
+        fs::create_dir_all(&self.out).context(format!("creating {}", self.out.display()))?;
+        for icon in &selected {
+            for &scale in &self.scales {
+                ensure!(scale > 0, "scale factors must be positive");
+                let size = self.size * scale;
+                let mut pixmap = Pixmap::new(size, size).context("zero-sized icon")?;
+                raster::render_into(icon, size, BLACK, &mut pixmap.as_mut(), 0, 0)?;
+                let path = self.out.join(file_name(icon, scale, "png"));
+                pixmap
+                    .save_png(&path)
+                    .context(format!("writing {}", path.display()))?;
+            }
+            if self.pdf {
+                let path = self.out.join(file_name(icon, 1, "pdf"));
+                fs::write(&path, pdf(icon, self.size as f64))
+                    .context(format!("writing {}", path.display()))?;
+            }
+        }
+        log::info!(
+            "wrote {} template images to {}",
+            selected.len(),
+            self.out.display()
+        );
+        Ok(())
+    }
+}
+
+/// `add`, 2 -> `addTemplate@2x.png`
+fn file_name(icon: &Icon, scale: u32, extension: &str) -> String {
+    match scale {
+        1 => format!("{}Template.{}", icon.name, extension),
+        scale => format!("{}Template@{}x.{}", icon.name, scale, extension),
+    }
+}
+
+/// A one-page PDF `size` points square containing the icon in black.
+fn pdf(icon: &Icon, size: f64) -> Vec<u8> {
+    // Each distinct opacity needs its own graphics state.
+    let mut states = BTreeMap::new();
+    for path in &icon.paths {
+        let next = states.len();
+        states.entry(opacity_key(path.opacity)).or_insert(next);
+    }
+
+    // Writing to a string can't fail.
+    let mut content = String::new();
+    // PDF's y axis points up, so flip the icon's coordinates.
+    let scale = size / icon.size;
+    let _ = writeln!(content, "0 g {0} 0 0 {1} 0 {2} cm", scale, -scale, size);
+    for path in &icon.paths {
+        let _ = writeln!(content, "/GS{} gs", states[&opacity_key(path.opacity)]);
+        pdf::fill_path(
+            &mut content,
+            path.path.iter().map(|el| match el {
+                PathEl::MoveTo(p) => pdf::El::MoveTo((p.x, p.y)),
+                PathEl::LineTo(p) => pdf::El::LineTo((p.x, p.y)),
+                PathEl::QuadTo(p1, p2) => pdf::El::QuadTo((p1.x, p1.y), (p2.x, p2.y)),
+                PathEl::CurveTo(p1, p2, p3) => {
+                    pdf::El::CurveTo((p1.x, p1.y), (p2.x, p2.y), (p3.x, p3.y))
+                }
+                PathEl::ClosePath => pdf::El::ClosePath,
+            }),
+        );
+    }
+
+    let mut ext_g_states = String::new();
+    for (opacity, idx) in &states {
+        let _ = write!(
+            ext_g_states,
+            "/GS{} << /ca {} >> ",
+            idx,
+            *opacity as f64 / 100.
+        );
+    }
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_owned(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_owned(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {0} {0}] /Contents 4 0 R \
+            /Resources << /ExtGState << {1}>> >> >>",
+            size, ext_g_states
+        ),
+        format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ),
+    ];
+
+    let mut out = String::from("%PDF-1.4\n");
+    let mut offsets = vec![];
+    for (idx, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        let _ = writeln!(out, "{} 0 obj\n{}\nendobj", idx + 1, object);
+    }
+    let xref = out.len();
+    let _ = writeln!(out, "xref\n0 {}\n0000000000 65535 f ", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(out, "{:010} 00000 n ", offset);
+    }
+    let _ = write!(
+        out,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+    out.into_bytes()
+}
+
+/// Opacities as whole percentages, so they can be compared and used as map keys.
+fn opacity_key(opacity: f64) -> u32 {
+    (opacity.clamp(0., 1.) * 100.).round() as u32
+}
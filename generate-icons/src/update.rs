@@ -0,0 +1,98 @@
+//! Regenerating some icons in place, leaving the rest of the generated code alone.
+//!
+//! Only icons that are already in the generated code can be updated this way, since adding or
+//! removing icons changes the tables and re-exports as well.
+
+use crate::{diff, format, is_generated, Icons, USE};
+use qu::ick_use::*;
+use std::{collections::BTreeMap, fmt::Write, fs, path::PathBuf};
+
+#[derive(Parser)]
+pub struct Update {
+    /// The category of the icons to update.
+    #[clap(long)]
+    category: String,
+    /// The upstream names of the icons to update. Updates the whole category if empty.
+    #[clap(long, multiple_values = true)]
+    icon: Vec<String>,
+    /// The generated code to patch.
+    #[clap(long, parse(from_os_str), default_value = "../src/icons.rs.in")]
+    file: PathBuf,
+}
+
+impl Update {
+    pub fn run(&self, icons: &Icons) -> Result {
+        // Generate the selected icons inside their modules, so they are formatted exactly as
+        // they would be by a full regeneration.
+        let mut generated = String::new();
+        for (variant, categories) in icons.0.iter().filter(|(v, _)| is_generated(v)) {
+            let category = categories
+                .get(&*self.category)
+                .context(format!("no category called {}", self.category))?;
+            writeln!(
+                generated,
+                "pub mod {} {{\npub mod {} {{\n{}\n",
+                variant, self.category, USE
+            )?;
+            if self.icon.is_empty() {
+                for icon in category.values() {
+                    writeln!(generated, "{}", icon.implement())?;
+                }
+            } else {
+                for name in &self.icon {
+                    let icon = category
+                        .get(&**name)
+                        .context(format!("no icon called {} in {}", name, self.category))?;
+                    writeln!(generated, "{}", icon.implement())?;
+                }
+            }
+            writeln!(generated, "}}\n}}")?;
+        }
+        let generated = format::rustfmt(&generated)?;
+        let mut replacements = diff::chunks(&generated)
+            .into_iter()
+            .filter_map(|chunk| match chunk {
+                diff::Chunk::Icon(key, item) => Some((key, item)),
+                diff::Chunk::Line(_) => None,
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let existing =
+            fs::read_to_string(&self.file).context(format!("reading {}", self.file.display()))?;
+        let mut patched = String::with_capacity(existing.len());
+        let mut changed = 0;
+        for chunk in diff::chunks(&existing) {
+            match chunk {
+                diff::Chunk::Icon(key, item) => match replacements.remove(&key) {
+                    Some(replacement) => {
+                        if replacement != item {
+                            log::info!("updating {}::{}::{}", key.0, key.1, key.2);
+                            changed += 1;
+                        }
+                        patched.push_str(&replacement);
+                    }
+                    None => patched.push_str(&item),
+                },
+                diff::Chunk::Line(line) => {
+                    patched.push_str(line);
+                    patched.push('\n');
+                }
+            }
+        }
+        if let Some((variant, category, name)) = replacements.keys().next() {
+            bail!(
+                "{}::{}::{} isn't in {}, regenerate everything to add new icons",
+                variant,
+                category,
+                name,
+                self.file.display()
+            );
+        }
+
+        if changed > 0 {
+            fs::write(&self.file, patched).context(format!("writing {}", self.file.display()))?;
+        }
+        log::info!("updated {} icons in {}", changed, self.file.display());
+        Ok(())
+    }
+}
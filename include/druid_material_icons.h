@@ -0,0 +1,45 @@
+/* C interface to druid-material-icons. See `src/capi.rs` for documentation. */
+#ifndef DRUID_MATERIAL_ICONS_H
+#define DRUID_MATERIAL_ICONS_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define MATERIAL_ICON_MOVE_TO 0
+#define MATERIAL_ICON_LINE_TO 1
+#define MATERIAL_ICON_QUAD_TO 2
+#define MATERIAL_ICON_CURVE_TO 3
+#define MATERIAL_ICON_CLOSE_PATH 4
+
+typedef struct IconPath IconPath;
+
+typedef struct IconPathsC {
+    const IconPath *paths;
+    size_t path_count;
+    double width;
+    double height;
+} IconPathsC;
+
+typedef struct PointC {
+    double x;
+    double y;
+} PointC;
+
+typedef struct PathElC {
+    int kind;
+    PointC points[3];
+} PathElC;
+
+IconPathsC material_icon_lookup(const char *name);
+double material_icon_path_opacity(IconPathsC icon, size_t path);
+size_t material_icon_path_element_count(IconPathsC icon, size_t path);
+int material_icon_path_element(IconPathsC icon, size_t path, size_t el, PathElC *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
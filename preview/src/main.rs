@@ -0,0 +1,104 @@
+//! A window showing every generated icon, for checking the generator's output by eye after an
+//! upstream update.
+//!
+//! Run it with `cargo run -p preview`. Type in the search box to filter icons by name or
+//! category, and use the buttons at the top to switch between style variants.
+
+use druid::{
+    widget::{CrossAxisAlignment, Flex, Label, RadioGroup, Scroll, TextBox, ViewSwitcher},
+    AppLauncher, Color, Data, Lens, Widget, WidgetExt, WindowDesc,
+};
+use druid_material_icons::{Icon, IconPaths, VARIANTS};
+
+/// How many icons to show on each row of the grid.
+const COLUMNS: usize = 8;
+/// The size icons are drawn at, a multiple of their design size so that stray points stand out.
+const ICON_SIZE: f64 = 48.;
+/// The width of each cell in the grid, which fits all but the longest names.
+const CELL_WIDTH: f64 = 128.;
+
+#[derive(Clone, Data, Lens)]
+struct AppState {
+    /// Only show icons whose name or category contains this.
+    query: String,
+    /// The index of the variant being shown in `VARIANTS`.
+    variant: usize,
+}
+
+fn main() {
+    let window = WindowDesc::new(ui())
+        .title("Material icons preview")
+        .window_size((CELL_WIDTH * COLUMNS as f64 + 48., 800.));
+    AppLauncher::with_window(window)
+        .launch(AppState {
+            query: String::new(),
+            variant: 0,
+        })
+        .expect("launching the preview window");
+}
+
+fn ui() -> impl Widget<AppState> {
+    let variants = VARIANTS
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, _))| (*name, idx));
+    let controls = Flex::row()
+        .with_flex_child(
+            TextBox::new()
+                .with_placeholder("Search by name or category")
+                .expand_width()
+                .lens(AppState::query),
+            1.,
+        )
+        .with_spacer(16.)
+        .with_child(RadioGroup::row(variants).lens(AppState::variant));
+    // Rebuild the grid whenever the filter changes, rather than hiding cells, so that the
+    // thousands of icons we aren't showing cost nothing.
+    let grid = ViewSwitcher::new(
+        |data: &AppState, _| (data.query.clone(), data.variant),
+        |(query, variant), _, _| Box::new(grid(filter(*variant, query))),
+    );
+    Flex::column()
+        .with_child(controls.padding(8.))
+        .with_flex_child(Scroll::new(grid).vertical().expand_width(), 1.)
+}
+
+/// The icons of the variant at `variant` in `VARIANTS` whose name or category contains `query`.
+fn filter(variant: usize, query: &str) -> Vec<IconPaths> {
+    VARIANTS[variant]
+        .1
+        .iter()
+        .map(|(_, icon)| *icon)
+        .filter(|icon| icon.name.contains(query) || icon.category.contains(query))
+        .collect()
+}
+
+/// `icons` laid out in rows of `COLUMNS`, with a count at the top.
+fn grid(icons: Vec<IconPaths>) -> impl Widget<AppState> {
+    let mut grid = Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(format!("{} icons", icons.len())).padding(8.));
+    for row in icons.chunks(COLUMNS) {
+        let mut cells = Flex::row();
+        for icon in row {
+            cells.add_child(cell(*icon));
+        }
+        grid.add_child(cells);
+    }
+    grid
+}
+
+/// An icon with its name and category underneath.
+fn cell(icon: IconPaths) -> impl Widget<AppState> {
+    Flex::column()
+        .with_child(Icon::new(icon, Color::WHITE).fix_size(ICON_SIZE, ICON_SIZE))
+        .with_spacer(4.)
+        .with_child(Label::new(icon.name).with_text_size(12.))
+        .with_child(
+            Label::new(icon.category)
+                .with_text_size(10.)
+                .with_text_color(Color::grey(0.6)),
+        )
+        .fix_width(CELL_WIDTH)
+        .padding(4.)
+}
@@ -0,0 +1,108 @@
+use druid::{
+    kurbo::{Affine, Size},
+    piet::ImageFormat,
+    Color, Env, ImageBuf, RenderContext,
+};
+
+use crate::{image, Error, Icon, IconPaths};
+
+/// The default space around the glyph, as a fraction of the image size.
+const DEFAULT_PADDING: f64 = 0.125;
+
+/// An icon drawn for use as a window or taskbar icon, optionally on a colored background.
+///
+/// druid doesn't set window icons itself, so this renders into the formats windowing libraries
+/// accept: [`to_rgba`](AppIcon::to_rgba) gives the straight RGBA bytes that e.g. winit's
+/// `Icon::from_rgba` expects, and [`to_image`](AppIcon::to_image) gives an [`ImageBuf`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use druid::Color;
+/// use druid_material_icons::{AppIcon, EDIT_NOTE};
+/// let icon = AppIcon::new(EDIT_NOTE, Color::WHITE)
+///     .with_background(Color::rgb8(0x67, 0x50, 0xa4))
+///     .with_corner_radius(0.25);
+/// // Window icons are usually wanted at a few sizes.
+/// for size in [16, 32, 48, 256] {
+///     let rgba = icon.to_rgba(size).unwrap();
+///     assert_eq!(rgba.len(), size * size * 4);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AppIcon {
+    paths: IconPaths,
+    color: Color,
+    background: Option<Color>,
+    corner_radius: f64,
+    padding: f64,
+}
+
+impl AppIcon {
+    /// Draw `paths` in `color`, with no background.
+    pub fn new(paths: IconPaths, color: Color) -> Self {
+        AppIcon {
+            paths,
+            color,
+            background: None,
+            corner_radius: 0.,
+            padding: DEFAULT_PADDING,
+        }
+    }
+
+    /// Builder-style method to draw the glyph on a background of `color`.
+    #[inline]
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Builder-style method to round the corners of the background, as a fraction of the image
+    /// size. `0.5` makes the background a circle.
+    #[inline]
+    pub fn with_corner_radius(mut self, radius: f64) -> Self {
+        self.corner_radius = radius.clamp(0., 0.5);
+        self
+    }
+
+    /// Builder-style method to set the space around the glyph, as a fraction of the image size.
+    ///
+    /// Defaults to 1/8, which suits most platforms' icon guidelines.
+    #[inline]
+    pub fn with_padding(mut self, padding: f64) -> Self {
+        self.padding = padding.clamp(0., 0.5);
+        self
+    }
+
+    /// Render the icon `size` pixels square.
+    pub fn to_image(&self, size: usize) -> Result<ImageBuf, Error> {
+        let side = size as f64;
+        let bounds = Size::new(side, side).to_rect();
+        let glyph = bounds.inset(-self.padding * side);
+        let icon = Icon::new(self.paths, self.color);
+        image::render(size, size, |rc| {
+            if let Some(background) = &self.background {
+                let radius = self.corner_radius * side;
+                rc.fill(bounds.to_rounded_rect(radius), background);
+            }
+            rc.transform(Affine::translate(glyph.origin().to_vec2()));
+            icon.paint_into(rc, glyph.size(), &Env::empty());
+        })
+    }
+
+    /// Render the icon `size` pixels square, as rows of straight (not premultiplied) RGBA bytes.
+    pub fn to_rgba(&self, size: usize) -> Result<Vec<u8>, Error> {
+        let image = self.to_image(size)?;
+        let mut pixels = image.raw_pixels().to_vec();
+        debug_assert_eq!(image.format(), ImageFormat::RgbaPremul);
+        for pixel in pixels.chunks_exact_mut(4) {
+            let alpha = pixel[3];
+            if alpha != 0 && alpha != 255 {
+                for channel in &mut pixel[..3] {
+                    *channel = ((*channel as u32 * 255 + alpha as u32 / 2) / alpha as u32) as u8;
+                }
+            }
+        }
+        Ok(pixels)
+    }
+}
@@ -0,0 +1,74 @@
+use druid::{
+    kurbo::{Affine, BezPath, Rect},
+    Color, RenderContext,
+};
+
+use crate::IconPaths;
+
+/// Paint many icons at once, each stretched to fill its rect.
+///
+/// This is much cheaper than an [`Icon`](crate::Icon) widget per icon in views like file
+/// browsers that show thousands of icons: icons are grouped by color, so each brush is only
+/// created once, and no transforms are pushed or popped. Because of the grouping, icons are
+/// painted in color order rather than the order given, which only matters if they overlap.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{kurbo::Rect, Color, PaintCtx};
+/// use druid_material_icons::{paint_icons, FOLDER, INSERT_DRIVE_FILE};
+/// fn paint_grid(ctx: &mut PaintCtx, entries: &[bool]) {
+///     let icons = entries
+///         .iter()
+///         .enumerate()
+///         .map(|(idx, is_dir)| {
+///             let rect = Rect::from_origin_size((idx as f64 * 32., 0.), (24., 24.));
+///             match is_dir {
+///                 true => (FOLDER, rect, Color::rgb8(0xf9, 0xa8, 0x25)),
+///                 false => (INSERT_DRIVE_FILE, rect, Color::grey(0.5)),
+///             }
+///         })
+///         .collect::<Vec<_>>();
+///     paint_icons(ctx.render_ctx, &icons);
+/// }
+/// ```
+pub fn paint_icons<R: RenderContext>(rc: &mut R, icons: &[(IconPaths, Rect, Color)]) {
+    let mut order = (0..icons.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&idx| icons[idx].2.as_rgba_u32());
+
+    // Brushes for the current color, one per path opacity.
+    let mut brushes: Vec<(f64, R::Brush)> = vec![];
+    let mut current_color = None;
+    // Reused for each path, to avoid allocating.
+    let mut scratch = BezPath::new();
+    for idx in order {
+        let (paths, rect, color) = &icons[idx];
+        let rgba = color.as_rgba_u32();
+        if current_color != Some(rgba) {
+            current_color = Some(rgba);
+            brushes.clear();
+        }
+        let transform = Affine::translate(rect.origin().to_vec2())
+            * Affine::scale_non_uniform(
+                rect.width() / paths.size.width,
+                rect.height() / paths.size.height,
+            );
+        for path in paths.paths {
+            let brush = match brushes
+                .iter()
+                .position(|(opacity, _)| *opacity == path.opacity)
+            {
+                Some(brush_idx) => &brushes[brush_idx].1,
+                None => {
+                    let (.., alpha) = color.as_rgba();
+                    let brush = rc.solid_brush(color.with_alpha(alpha * path.opacity));
+                    brushes.push((path.opacity, brush));
+                    &brushes[brushes.len() - 1].1
+                }
+            };
+            scratch.truncate(0);
+            scratch.extend(path.els.iter().map(|el| transform * *el));
+            rc.fill(&scratch, brush);
+        }
+    }
+}
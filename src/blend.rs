@@ -0,0 +1,83 @@
+//! Interpolating between icons drawn as masters along a design axis (e.g. weight), as variable
+//! fonts do.
+
+use crate::{BezPath, IconPath, IconPaths, PathEl, Point, Size};
+
+/// The geometry to paint for an icon, after any interpolation.
+#[derive(Debug, Clone)]
+pub(crate) enum Glyph {
+    /// An icon painted as-is.
+    Static(IconPaths),
+    /// Paths interpolated between two masters, with their opacities, and the interpolated size.
+    Blended(Vec<(BezPath, f64)>, Size),
+}
+
+impl Glyph {
+    /// The size of the glyph's coordinate space.
+    pub fn size(&self) -> Size {
+        match self {
+            Glyph::Static(paths) => paths.size,
+            Glyph::Blended(_, size) => *size,
+        }
+    }
+}
+
+/// The glyph at `value` along an axis with masters at the given positions.
+///
+/// `masters` must be sorted by position. Between two masters we interpolate their paths if they
+/// have the same structure, and otherwise use whichever master is nearer.
+pub(crate) fn at(masters: &[(f64, IconPaths)], value: f64) -> Option<Glyph> {
+    let (first, last) = (masters.first()?, masters.last()?);
+    if value <= first.0 {
+        return Some(Glyph::Static(first.1));
+    }
+    if value >= last.0 {
+        return Some(Glyph::Static(last.1));
+    }
+    let idx = masters.iter().position(|(pos, _)| *pos > value)?;
+    let ((lo_pos, lo), (hi_pos, hi)) = (masters[idx - 1], masters[idx]);
+    let t = (value - lo_pos) / (hi_pos - lo_pos);
+    Some(lerp(&lo, &hi, t).unwrap_or(Glyph::Static(if t < 0.5 { lo } else { hi })))
+}
+
+/// Interpolate between two icons, or `None` if their paths don't have the same structure.
+pub(crate) fn lerp(from: &IconPaths, to: &IconPaths, t: f64) -> Option<Glyph> {
+    if from.paths.len() != to.paths.len() {
+        return None;
+    }
+    let paths = from
+        .paths
+        .iter()
+        .zip(to.paths)
+        .map(|(from, to)| {
+            let path = lerp_path(from, to, t)?;
+            Some((path, from.opacity + (to.opacity - from.opacity) * t))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let size = from.size.to_vec2().lerp(to.size.to_vec2(), t).to_size();
+    Some(Glyph::Blended(paths, size))
+}
+
+fn lerp_path(from: &IconPath, to: &IconPath, t: f64) -> Option<BezPath> {
+    if from.els.len() != to.els.len() {
+        return None;
+    }
+    let p = |a: Point, b: Point| a.lerp(b, t);
+    from.els
+        .iter()
+        .zip(to.els)
+        .map(|(from, to)| match (*from, *to) {
+            (PathEl::MoveTo(a), PathEl::MoveTo(b)) => Some(PathEl::MoveTo(p(a, b))),
+            (PathEl::LineTo(a), PathEl::LineTo(b)) => Some(PathEl::LineTo(p(a, b))),
+            (PathEl::QuadTo(a1, a2), PathEl::QuadTo(b1, b2)) => {
+                Some(PathEl::QuadTo(p(a1, b1), p(a2, b2)))
+            }
+            (PathEl::CurveTo(a1, a2, a3), PathEl::CurveTo(b1, b2, b3)) => {
+                Some(PathEl::CurveTo(p(a1, b1), p(a2, b2), p(a3, b3)))
+            }
+            (PathEl::ClosePath, PathEl::ClosePath) => Some(PathEl::ClosePath),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(BezPath::from_vec)
+}
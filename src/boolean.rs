@@ -0,0 +1,154 @@
+//! Boolean operations between icons, for cutting notches where badges sit and building masked
+//! composites. Build with the `boolean` feature.
+//!
+//! The operations work on the shape an icon covers, so every path of an icon counts as ink
+//! whatever its opacity, and the result is a single opaque path.
+
+use flo_curves::{
+    bezier::path::{
+        path_add, path_intersect, path_remove_interior_points, path_sub, SimpleBezierPath,
+    },
+    Coord2,
+};
+
+use crate::{BezPath, OwnedIconPath, OwnedIconPaths, PathEl, Point};
+
+/// How close the result follows the original outlines, in icon units. Generated coordinates are
+/// only precise to 0.01 anyway.
+const ACCURACY: f64 = 0.01;
+
+impl OwnedIconPaths {
+    /// The area covered by either icon.
+    ///
+    /// The result keeps this icon's name and size.
+    pub fn union(&self, other: impl Into<OwnedIconPaths>) -> OwnedIconPaths {
+        self.combine(other.into(), path_add)
+    }
+
+    /// The area covered by this icon but not `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::{OwnedIconPaths, CIRCLE, NOTIFICATIONS};
+    /// use kurbo::{Affine, Point, Shape, Vec2};
+    /// // Cut a notch out of the bell where a badge will sit in the top right corner.
+    /// let notch = CIRCLE.transformed(Affine::translate(Vec2::new(8., -8.)));
+    /// let bell = OwnedIconPaths::from(NOTIFICATIONS).subtract(notch);
+    /// let covered = |point| bell.paths.iter().any(|path| path.path.winding(point) != 0);
+    /// assert!(!covered(Point::new(16., 8.)));
+    /// assert!(covered(Point::new(8., 12.)));
+    /// ```
+    pub fn subtract(&self, other: impl Into<OwnedIconPaths>) -> OwnedIconPaths {
+        self.combine(other.into(), path_sub)
+    }
+
+    /// The area covered by both icons.
+    ///
+    /// The result keeps this icon's name and size.
+    pub fn intersect(&self, other: impl Into<OwnedIconPaths>) -> OwnedIconPaths {
+        self.combine(other.into(), path_intersect)
+    }
+
+    /// The result of `op` between the outlines of this icon and `other`.
+    fn combine(
+        &self,
+        other: OwnedIconPaths,
+        op: fn(&Vec<SimpleBezierPath>, &Vec<SimpleBezierPath>, f64) -> Vec<SimpleBezierPath>,
+    ) -> OwnedIconPaths {
+        self.with_outline(op(&self.outline(), &other.outline(), ACCURACY))
+    }
+
+    /// An icon like this one, made of `outline` rather than its own paths.
+    pub(crate) fn with_outline(&self, outline: Vec<SimpleBezierPath>) -> OwnedIconPaths {
+        let mut path = BezPath::new();
+        for (start, curves) in outline {
+            path.move_to(point(start));
+            for (c1, c2, end) in curves {
+                path.curve_to(point(c1), point(c2), point(end));
+            }
+            path.close_path();
+        }
+        OwnedIconPaths {
+            paths: vec![OwnedIconPath { path, opacity: 1. }],
+            ..self.clone()
+        }
+    }
+
+    /// The outline of everything the icon covers, without overlaps.
+    ///
+    /// flo_curves expects its operands not to overlap themselves and fills them with the
+    /// even-odd rule, but icons are drawn with the nonzero rule and often overlap, so resolve
+    /// that first.
+    pub(crate) fn outline(&self) -> Vec<SimpleBezierPath> {
+        let mut subpaths = vec![];
+        for path in &self.paths {
+            let mut current: Option<SimpleBezierPath> = None;
+            let mut last = Point::ZERO;
+            for el in path.path.elements() {
+                match *el {
+                    PathEl::MoveTo(p) => {
+                        subpaths.extend(current.take().map(close));
+                        current = Some((coord(p), vec![]));
+                        last = p;
+                    }
+                    PathEl::LineTo(p) => {
+                        if let Some((_, curves)) = &mut current {
+                            curves.push(line(last, p));
+                        }
+                        last = p;
+                    }
+                    PathEl::QuadTo(p1, p2) => {
+                        if let Some((_, curves)) = &mut current {
+                            // Raise the degree; the control points are 2/3 of the way to p1.
+                            let c1 = last + (p1 - last) * (2. / 3.);
+                            let c2 = p2 + (p1 - p2) * (2. / 3.);
+                            curves.push((coord(c1), coord(c2), coord(p2)));
+                        }
+                        last = p2;
+                    }
+                    PathEl::CurveTo(p1, p2, p3) => {
+                        if let Some((_, curves)) = &mut current {
+                            curves.push((coord(p1), coord(p2), coord(p3)));
+                        }
+                        last = p3;
+                    }
+                    PathEl::ClosePath => {
+                        if let Some(subpath) = current.take() {
+                            last = point(subpath.0);
+                            subpaths.push(close(subpath));
+                        }
+                    }
+                }
+            }
+            subpaths.extend(current.map(close));
+        }
+        path_remove_interior_points(&subpaths, ACCURACY)
+    }
+}
+
+/// `subpath` with a line back to its start, if it doesn't end there already.
+fn close((start, mut curves): SimpleBezierPath) -> SimpleBezierPath {
+    let end = curves.last().map_or(start, |&(_, _, end)| end);
+    if end != start {
+        curves.push(line(point(end), point(start)));
+    }
+    (start, curves)
+}
+
+/// A straight line from `from` to `to` as a cubic curve.
+fn line(from: Point, to: Point) -> (Coord2, Coord2, Coord2) {
+    (
+        coord(from.lerp(to, 1. / 3.)),
+        coord(from.lerp(to, 2. / 3.)),
+        coord(to),
+    )
+}
+
+fn coord(p: Point) -> Coord2 {
+    Coord2(p.x, p.y)
+}
+
+fn point(c: Coord2) -> Point {
+    Point::new(c.0, c.1)
+}
@@ -0,0 +1,116 @@
+//! Brand and logo icons, to show next to the material icons. Build with the `brands` feature.
+//!
+//! The logos are trademarks of their owners, so follow their brand guidelines when using them.
+//!
+//! Generated by `generate-icons pack`; don't edit by hand.
+
+use crate::{IconPath, IconPaths, PathEl, Point, Size};
+
+pub const MARKDOWN: IconPaths = IconPaths {
+    name: "markdown",
+    category: "brands",
+    variant: "brands",
+    paths: &[
+        IconPath {
+            els: &[
+                PathEl::MoveTo(Point { x: 1.73, y: 4.62 }),
+                PathEl::LineTo(Point { x: 22.27, y: 4.62 }),
+                PathEl::CurveTo(
+                    Point { x: 23.22, y: 4.62 },
+                    Point { x: 24.00, y: 5.39 },
+                    Point { x: 24.00, y: 6.35 },
+                ),
+                PathEl::LineTo(Point { x: 24.00, y: 17.65 }),
+                PathEl::CurveTo(
+                    Point { x: 24.00, y: 18.61 },
+                    Point { x: 23.22, y: 19.38 },
+                    Point { x: 22.27, y: 19.38 },
+                ),
+                PathEl::LineTo(Point { x: 1.73, y: 19.38 }),
+                PathEl::CurveTo(
+                    Point { x: 0.77, y: 19.38 },
+                    Point { x: 0.00, y: 18.61 },
+                    Point { x: 0.00, y: 17.65 },
+                ),
+                PathEl::LineTo(Point { x: 0.00, y: 6.35 }),
+                PathEl::CurveTo(
+                    Point { x: -0.00, y: 5.39 },
+                    Point { x: 0.77, y: 4.62 },
+                    Point { x: 1.73, y: 4.62 },
+                ),
+                PathEl::ClosePath,
+                PathEl::MoveTo(Point { x: 1.73, y: 5.77 }),
+                PathEl::CurveTo(
+                    Point { x: 1.41, y: 5.77 },
+                    Point { x: 1.15, y: 6.03 },
+                    Point { x: 1.15, y: 6.35 },
+                ),
+                PathEl::LineTo(Point { x: 1.15, y: 17.65 }),
+                PathEl::CurveTo(
+                    Point { x: 1.15, y: 17.97 },
+                    Point { x: 1.41, y: 18.23 },
+                    Point { x: 1.73, y: 18.23 },
+                ),
+                PathEl::LineTo(Point { x: 22.27, y: 18.23 }),
+                PathEl::CurveTo(
+                    Point { x: 22.59, y: 18.23 },
+                    Point { x: 22.85, y: 17.97 },
+                    Point { x: 22.85, y: 17.65 },
+                ),
+                PathEl::LineTo(Point { x: 22.85, y: 6.35 }),
+                PathEl::CurveTo(
+                    Point { x: 22.85, y: 6.03 },
+                    Point { x: 22.59, y: 5.77 },
+                    Point { x: 22.27, y: 5.77 },
+                ),
+                PathEl::ClosePath,
+            ],
+            opacity: 1.00,
+        },
+        IconPath {
+            els: &[
+                PathEl::MoveTo(Point { x: 3.46, y: 15.92 }),
+                PathEl::LineTo(Point { x: 3.46, y: 8.08 }),
+                PathEl::LineTo(Point { x: 5.77, y: 8.08 }),
+                PathEl::LineTo(Point { x: 8.08, y: 10.96 }),
+                PathEl::LineTo(Point { x: 10.38, y: 8.08 }),
+                PathEl::LineTo(Point { x: 12.69, y: 8.08 }),
+                PathEl::LineTo(Point { x: 12.69, y: 15.92 }),
+                PathEl::LineTo(Point { x: 10.38, y: 15.92 }),
+                PathEl::LineTo(Point { x: 10.38, y: 11.42 }),
+                PathEl::LineTo(Point { x: 8.08, y: 14.31 }),
+                PathEl::LineTo(Point { x: 5.77, y: 11.42 }),
+                PathEl::LineTo(Point { x: 5.77, y: 15.92 }),
+                PathEl::ClosePath,
+                PathEl::MoveTo(Point { x: 17.88, y: 15.92 }),
+                PathEl::LineTo(Point { x: 14.42, y: 12.12 }),
+                PathEl::LineTo(Point { x: 16.73, y: 12.12 }),
+                PathEl::LineTo(Point { x: 16.73, y: 8.08 }),
+                PathEl::LineTo(Point { x: 19.04, y: 8.08 }),
+                PathEl::LineTo(Point { x: 19.04, y: 12.12 }),
+                PathEl::LineTo(Point { x: 21.35, y: 12.12 }),
+                PathEl::ClosePath,
+            ],
+            opacity: 1.00,
+        },
+    ],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 58,
+};
+/// Every icon in the pack as `(name, icon)` pairs, sorted by name.
+pub const ALL: &[(&str, IconPaths)] = &[("markdown", MARKDOWN)];
+/// The number of icons in [`ALL`].
+pub const COUNT: usize = ALL.len();
+/// The names of the icons in [`ALL`], in the same order.
+pub const NAMES: &[&str] = &crate::names::<COUNT>(ALL);
+/// Where the pack's icons come from and the license they are under.
+pub const ATTRIBUTION: crate::Attribution = crate::Attribution {
+    license: "CC0-1.0",
+    copyright: "Dustin Curtis",
+    source: "https://github.com/dcurtis/markdown-mark",
+    release: None,
+    commit: None,
+};
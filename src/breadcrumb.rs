@@ -0,0 +1,93 @@
+use druid::{
+    kurbo::{Affine, Size},
+    widget::prelude::*,
+    Color, Data, Key,
+};
+
+use crate::{icon::IconAlignment, theme, Icon, CHEVRON_RIGHT};
+
+/// A [`CHEVRON_RIGHT`] for separating the items of a breadcrumb row.
+///
+/// The chevron is sized to match the text around it and lines up with its capital letters when
+/// the row uses [`CrossAxisAlignment::Baseline`](druid::widget::CrossAxisAlignment::Baseline).
+/// It points left when [`theme::RIGHT_TO_LEFT`] is set, and is drawn in
+/// [`theme::ON_SURFACE_VARIANT`].
+///
+/// # Examples
+///
+/// ```
+/// # use druid::Widget;
+/// use druid::widget::{CrossAxisAlignment, Flex, Label};
+/// use druid_material_icons::BreadcrumbSeparator;
+/// fn path() -> impl Widget<()> {
+///     Flex::row()
+///         .cross_axis_alignment(CrossAxisAlignment::Baseline)
+///         .with_child(Label::new("Home"))
+///         .with_child(BreadcrumbSeparator::new())
+///         .with_child(Label::new("Photos"))
+/// }
+/// ```
+pub struct BreadcrumbSeparator {
+    icon: Icon,
+}
+
+impl BreadcrumbSeparator {
+    /// A separator matching text of [`TEXT_SIZE_NORMAL`](druid::theme::TEXT_SIZE_NORMAL).
+    pub fn new() -> Self {
+        BreadcrumbSeparator {
+            // The color is set from the theme when painting.
+            icon: Icon::new(CHEVRON_RIGHT, Color::BLACK)
+                .match_font_size(druid::theme::TEXT_SIZE_NORMAL)
+                .with_alignment(IconAlignment::CapHeight),
+        }
+    }
+
+    /// Builder-style method to match text of the font size stored under `key` instead.
+    pub fn match_font_size(mut self, key: Key<f64>) -> Self {
+        self.icon = self.icon.match_font_size(key);
+        self
+    }
+}
+
+impl Default for BreadcrumbSeparator {
+    fn default() -> Self {
+        BreadcrumbSeparator::new()
+    }
+}
+
+impl<T: Data> Widget<T> for BreadcrumbSeparator {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.icon.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.icon.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if ctx.env_changed() {
+            ctx.request_paint();
+        }
+        self.icon.update(ctx, old_data, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.icon.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let defaults = &theme::MATERIAL3_SCHEME;
+        let content = theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant);
+        self.icon.set_color(content);
+        if theme::right_to_left(env) {
+            // The chevron is centered in its box, so mirroring it in place keeps it lined up.
+            let width = ctx.size().width;
+            ctx.with_save(|ctx| {
+                ctx.transform(Affine::new([-1., 0., 0., 1., width, 0.]));
+                self.icon.paint(ctx, data, env);
+            });
+        } else {
+            self.icon.paint(ctx, data, env);
+        }
+    }
+}
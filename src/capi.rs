@@ -0,0 +1,161 @@
+//! A C interface to the icon data, for sharing one icon set with C and C++ UI toolkits.
+//!
+//! Build a shared library with
+//!
+//! ```text
+//! cargo rustc --release --no-default-features --features capi --crate-type cdylib
+//! ```
+//!
+//! and use the declarations in `include/druid_material_icons.h`. All icon data is static, so
+//! nothing returned from these functions ever needs to be freed.
+
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_int},
+    ptr,
+};
+
+use crate::{lookup, IconPath, IconPaths, PathEl};
+
+/// An icon, as returned by [`material_icon_lookup`].
+///
+/// If the lookup failed, `paths` is null and `path_count` is zero.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IconPathsC {
+    /// Opaque pointer to the first path, for use with the accessor functions.
+    pub paths: *const IconPath,
+    pub path_count: usize,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// The kind of a [`PathElC`], matching [`PathEl`].
+pub const MATERIAL_ICON_MOVE_TO: c_int = 0;
+pub const MATERIAL_ICON_LINE_TO: c_int = 1;
+pub const MATERIAL_ICON_QUAD_TO: c_int = 2;
+pub const MATERIAL_ICON_CURVE_TO: c_int = 3;
+pub const MATERIAL_ICON_CLOSE_PATH: c_int = 4;
+
+/// A single path element.
+///
+/// `kind` is one of the `MATERIAL_ICON_*` constants, and determines how many of `points` are
+/// used: one for move/line, two for quad, three for curve and none for close.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PathElC {
+    pub kind: c_int,
+    pub points: [PointC; 3],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PointC {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl From<IconPaths> for IconPathsC {
+    fn from(icon: IconPaths) -> Self {
+        IconPathsC {
+            paths: icon.paths.as_ptr(),
+            path_count: icon.paths.len(),
+            width: icon.size.width,
+            height: icon.size.height,
+        }
+    }
+}
+
+impl From<PathEl> for PathElC {
+    fn from(el: PathEl) -> Self {
+        let p = |p: crate::Point| PointC { x: p.x, y: p.y };
+        let zero = PointC::default();
+        let (kind, points) = match el {
+            PathEl::MoveTo(p0) => (MATERIAL_ICON_MOVE_TO, [p(p0), zero, zero]),
+            PathEl::LineTo(p0) => (MATERIAL_ICON_LINE_TO, [p(p0), zero, zero]),
+            PathEl::QuadTo(p0, p1) => (MATERIAL_ICON_QUAD_TO, [p(p0), p(p1), zero]),
+            PathEl::CurveTo(p0, p1, p2) => (MATERIAL_ICON_CURVE_TO, [p(p0), p(p1), p(p2)]),
+            PathEl::ClosePath => (MATERIAL_ICON_CLOSE_PATH, [zero; 3]),
+        };
+        PathElC { kind, points }
+    }
+}
+
+/// Find an icon by its upstream name (e.g. `"add_circle"`).
+///
+/// # Safety
+///
+/// `name` must be null or a valid nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn material_icon_lookup(name: *const c_char) -> IconPathsC {
+    let icon = if name.is_null() {
+        None
+    } else {
+        CStr::from_ptr(name)
+            .to_str()
+            .ok()
+            .and_then(|name| lookup(name).ok())
+    };
+    icon.map(IconPathsC::from).unwrap_or(IconPathsC {
+        paths: ptr::null(),
+        path_count: 0,
+        width: 0.,
+        height: 0.,
+    })
+}
+
+/// The opacity of path `path` of `icon`, or zero if it is out of range.
+///
+/// # Safety
+///
+/// `icon` must have been returned by [`material_icon_lookup`].
+#[no_mangle]
+pub unsafe extern "C" fn material_icon_path_opacity(icon: IconPathsC, path: usize) -> f64 {
+    icon_path(icon, path).map(|path| path.opacity).unwrap_or(0.)
+}
+
+/// The number of elements in path `path` of `icon`, or zero if it is out of range.
+///
+/// # Safety
+///
+/// `icon` must have been returned by [`material_icon_lookup`].
+#[no_mangle]
+pub unsafe extern "C" fn material_icon_path_element_count(icon: IconPathsC, path: usize) -> usize {
+    icon_path(icon, path)
+        .map(|path| path.els.len())
+        .unwrap_or(0)
+}
+
+/// Write element `el` of path `path` of `icon` to `out`.
+///
+/// Returns 0 on success, or -1 (leaving `out` untouched) if either index is out of range.
+///
+/// # Safety
+///
+/// `icon` must have been returned by [`material_icon_lookup`], and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn material_icon_path_element(
+    icon: IconPathsC,
+    path: usize,
+    el: usize,
+    out: *mut PathElC,
+) -> c_int {
+    match icon_path(icon, path).and_then(|path| path.els.get(el)) {
+        Some(el) if !out.is_null() => {
+            out.write(PathElC::from(*el));
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Safety: `icon` must have been returned by [`material_icon_lookup`].
+unsafe fn icon_path(icon: IconPathsC, path: usize) -> Option<&'static IconPath> {
+    if icon.paths.is_null() {
+        return None;
+    }
+    // All icon data is static, so the slice lives forever.
+    let paths: &'static [IconPath] = std::slice::from_raw_parts(icon.paths, icon.path_count);
+    paths.get(path)
+}
@@ -0,0 +1,93 @@
+//! Shims over the parts of druid's API that differ between the versions we support.
+//!
+//! The widgets are written against the latest druid release. Anything that is spelled
+//! differently in druid 0.7 (the `druid-0_7` feature) or on druid's git master (the
+//! `druid-master` feature) goes through here, so the rest of the crate doesn't need to know which
+//! druid it is built with.
+//!
+//! To build against git master, enable `druid-master` and patch druid in your workspace:
+//!
+//! ```toml
+//! [patch.crates-io]
+//! druid = { git = "https://github.com/linebender/druid" }
+//! ```
+
+use druid::{piet::StrokeStyle, Env, EventCtx, PaintCtx, Point, Scale, WindowId};
+
+#[cfg(all(feature = "druid", feature = "druid-0_7"))]
+compile_error!("the `druid-0_7` feature needs the default `druid` feature to be disabled");
+
+/// The widget's origin in the window and the window's scale, for snapping to physical pixels.
+///
+/// Returns `None` if druid can't tell us where the widget is, in which case we don't snap.
+#[cfg(all(feature = "druid", not(feature = "druid-master")))]
+pub(crate) fn pixel_grid(ctx: &PaintCtx) -> Option<(Point, Scale)> {
+    Some((ctx.window_origin(), ctx.scale()))
+}
+
+/// The widget's origin in the window and the window's scale, for snapping to physical pixels.
+///
+/// Returns `None` if druid can't tell us where the widget is, in which case we don't snap.
+#[cfg(feature = "druid-master")]
+pub(crate) fn pixel_grid(ctx: &PaintCtx) -> Option<(Point, Scale)> {
+    // `window_origin` is on its way out in favour of `to_window`.
+    Some((ctx.to_window(Point::ORIGIN), ctx.scale()))
+}
+
+/// The widget's origin in the window and the window's scale, for snapping to physical pixels.
+///
+/// Returns `None` if druid can't tell us where the widget is, in which case we don't snap.
+#[cfg(all(feature = "druid-0_7", not(feature = "druid")))]
+pub(crate) fn pixel_grid(_ctx: &PaintCtx) -> Option<(Point, Scale)> {
+    // Paint contexts don't know their position in the window before druid 0.8.
+    None
+}
+
+/// Open a tooltip window showing `text` with its top left corner at `position` in the widget's
+/// window.
+#[cfg(feature = "druid")]
+pub(crate) fn show_tooltip(
+    ctx: &mut EventCtx,
+    text: &str,
+    position: Point,
+    env: &Env,
+) -> Option<WindowId> {
+    use druid::{widget::Label, WidgetExt, WindowConfig, WindowLevel, WindowSizePolicy};
+
+    let config = WindowConfig::default()
+        .show_titlebar(false)
+        .window_size_policy(WindowSizePolicy::Content)
+        .set_level(WindowLevel::Tooltip(ctx.window().clone()))
+        .set_position(position);
+    let label = Label::<()>::new(text.to_owned()).padding((8., 4.));
+    Some(ctx.new_sub_window(config, label, (), env.clone()))
+}
+
+/// Open a tooltip window showing `text` with its top left corner at `position` in the widget's
+/// window.
+#[cfg(all(feature = "druid-0_7", not(feature = "druid")))]
+pub(crate) fn show_tooltip(
+    _ctx: &mut EventCtx,
+    _text: &str,
+    _position: Point,
+    _env: &Env,
+) -> Option<WindowId> {
+    // Widgets can't open windows before druid 0.8.
+    None
+}
+
+/// A stroke style dashed with `pattern`, starting at the beginning of the pattern.
+#[cfg(feature = "druid")]
+pub(crate) fn dashed(pattern: Vec<f64>) -> StrokeStyle {
+    let mut style = StrokeStyle::new();
+    style.set_dash_pattern(pattern);
+    style
+}
+
+/// A stroke style dashed with `pattern`, starting at the beginning of the pattern.
+#[cfg(all(feature = "druid-0_7", not(feature = "druid")))]
+pub(crate) fn dashed(pattern: Vec<f64>) -> StrokeStyle {
+    let mut style = StrokeStyle::new();
+    style.set_dash(pattern, 0.);
+    style
+}
@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use druid::{widget::prelude::*, Color, Data};
+
+use crate::{icon, theme, Icon, IconPaths};
+
+/// A widget that displays the icon in its data.
+///
+/// Optionally, changes of icon can be animated by crossfading from the old icon to the new one.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use druid::{Color, Widget, WidgetExt};
+/// use druid_material_icons::{DynIcon, IconPaths};
+/// fn play_pause() -> impl Widget<IconPaths> {
+///     DynIcon::new(Color::WHITE).with_crossfade(Duration::from_millis(150))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynIcon {
+    icon: Icon,
+    crossfade: Option<Duration>,
+    fade: Option<Fade>,
+}
+
+/// A crossfade in progress.
+#[derive(Debug, Clone)]
+struct Fade {
+    from: IconPaths,
+    elapsed: Duration,
+}
+
+impl DynIcon {
+    /// Create a widget that paints its icon with the given color.
+    pub fn new(color: Color) -> Self {
+        DynIcon {
+            icon: Icon::new(crate::DEFAULT_ICON, color),
+            crossfade: None,
+            fade: None,
+        }
+    }
+
+    /// Builder-style method to crossfade between icons for `duration` when the icon changes.
+    #[inline]
+    pub fn with_crossfade(mut self, duration: Duration) -> Self {
+        self.crossfade = Some(duration);
+        self
+    }
+
+    /// Set how long to crossfade between icons for, or `None` to switch instantly.
+    #[inline]
+    pub fn set_crossfade(&mut self, duration: Option<Duration>) {
+        self.crossfade = duration;
+    }
+
+    /// Builder-style method to show the icon as loading, with a highlight sweeping across a faint
+    /// silhouette of the glyph, e.g. while the data that decides the icon is being fetched.
+    ///
+    /// Stop the shimmer with [`SET_LOADING`](crate::icon::SET_LOADING) or
+    /// [`set_loading`](DynIcon::set_loading) when the content arrives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{Color, Widget};
+    /// use druid_material_icons::{DynIcon, IconPaths};
+    /// fn sync_status() -> impl Widget<IconPaths> {
+    ///     DynIcon::new(Color::grey(0.6)).with_loading()
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_loading(mut self) -> Self {
+        self.set_loading(true);
+        self
+    }
+
+    /// Set whether the icon is shown as loading.
+    ///
+    /// The shimmer starts on the next update, so the caller is responsible for requesting one,
+    /// or should use [`SET_LOADING`](crate::icon::SET_LOADING) instead.
+    #[inline]
+    pub fn set_loading(&mut self, loading: bool) {
+        self.icon.set_loading(loading);
+    }
+
+    /// Whether the icon is shown as loading.
+    #[inline]
+    pub fn is_loading(&self) -> bool {
+        self.icon.is_loading()
+    }
+
+    /// The inner icon, for configuring its appearance.
+    #[inline]
+    pub fn icon_mut(&mut self) -> &mut Icon {
+        &mut self.icon
+    }
+
+    /// How far through the crossfade we are, from 0 to 1, eased like [`Icon`]'s animations.
+    fn progress(&self, fade: &Fade) -> f64 {
+        let duration = self.crossfade.unwrap_or_default();
+        icon::ease(icon::progress(fade.elapsed, duration))
+    }
+}
+
+impl Widget<IconPaths> for DynIcon {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut IconPaths, env: &Env) {
+        if let (Event::AnimFrame(interval), Some(fade)) = (event, &mut self.fade) {
+            fade.elapsed += Duration::from_nanos(*interval);
+            if fade.elapsed >= self.crossfade.unwrap_or_default() {
+                self.fade = None;
+            } else {
+                ctx.request_anim_frame();
+            }
+            ctx.request_paint();
+        }
+        self.icon.event(ctx, event, &mut (), env)
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &IconPaths,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.icon.set_icon(*data);
+        }
+        self.icon.lifecycle(ctx, event, &(), env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &IconPaths, data: &IconPaths, env: &Env) {
+        if !old_data.same(data) {
+            if self.crossfade.is_some() && !theme::high_contrast(env) {
+                // If we're interrupting a fade, start again from whatever was on screen.
+                self.fade = Some(Fade {
+                    from: self.icon.icon(),
+                    elapsed: Duration::ZERO,
+                });
+                ctx.request_anim_frame();
+            }
+            self.icon.set_icon(*data);
+            ctx.request_layout();
+        }
+        self.icon.update(ctx, &(), &(), env)
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &IconPaths,
+        env: &Env,
+    ) -> Size {
+        self.icon.layout(ctx, bc, &(), env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &IconPaths, env: &Env) {
+        let fade = match self.fade.clone() {
+            Some(fade) => fade,
+            None => return self.icon.paint(ctx, &(), env),
+        };
+        let t = self.progress(&fade);
+        let to = self.icon.icon();
+        self.icon.set_icon(fade.from);
+        ctx.with_save(|ctx| self.icon.paint_with_alpha(ctx, env, 1. - t));
+        self.icon.set_icon(to);
+        ctx.with_save(|ctx| self.icon.paint_with_alpha(ctx, env, t));
+    }
+}
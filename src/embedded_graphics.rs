@@ -0,0 +1,108 @@
+//! Icons rasterized for [embedded-graphics](https://github.com/embedded-graphics/embedded-graphics)
+//! displays, such as small monochrome OLEDs or RGB565 TFTs on microcontrollers. Build with the
+//! `embedded-graphics` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use druid_material_icons::{embedded_graphics::IconBitmap, ADD};
+//! use ::embedded_graphics::{
+//!     image::Image, mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*,
+//! };
+//!
+//! let bitmap = IconBitmap::new(&ADD, Size::new(16, 16), BinaryColor::On);
+//! let mut display = MockDisplay::new();
+//! Image::new(&bitmap, Point::zero()).draw(&mut display).unwrap();
+//! // The middle of the plus is set, and the corners aren't touched.
+//! assert_eq!(display.get_pixel(Point::new(8, 8)), Some(BinaryColor::On));
+//! assert_eq!(display.get_pixel(Point::new(0, 0)), None);
+//! ```
+
+use ::embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point as EgPoint, Size as EgSize},
+    image::ImageDrawable,
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+use crate::{IconPaths, Point, Shape};
+
+/// An icon rasterized to a 1-bit mask, drawn in a single color.
+///
+/// Pixels are either inside the icon or not, with no antialiasing, which suits the small
+/// displays embedded-graphics targets. Pixels outside the icon are left as they are, and paths
+/// that are less than half opaque are left out.
+#[derive(Debug, Clone)]
+pub struct IconBitmap<C> {
+    size: EgSize,
+    /// Whether each pixel is inside the icon, row by row.
+    mask: Vec<bool>,
+    color: C,
+}
+
+impl<C: PixelColor> IconBitmap<C> {
+    /// Rasterize `icon` stretched to `size` pixels, to be drawn in `color`.
+    pub fn new(icon: &IconPaths, size: EgSize, color: C) -> Self {
+        let scale_x = icon.size.width / f64::from(size.width);
+        let scale_y = icon.size.height / f64::from(size.height);
+        let mut mask = Vec::with_capacity((size.width * size.height) as usize);
+        for y in 0..size.height {
+            for x in 0..size.width {
+                // Sample the middle of the pixel, in icon coordinates.
+                let center = Point::new(
+                    (f64::from(x) + 0.5) * scale_x,
+                    (f64::from(y) + 0.5) * scale_y,
+                );
+                mask.push(
+                    icon.paths
+                        .iter()
+                        .any(|path| path.opacity >= 0.5 && path.winding(center) != 0),
+                );
+            }
+        }
+        IconBitmap { size, mask, color }
+    }
+
+    /// Change the color the icon is drawn in.
+    pub fn set_color(&mut self, color: C) {
+        self.color = color;
+    }
+
+    /// The pixels inside the icon that are also inside `area`, relative to `area`'s top left.
+    fn pixels(&self, area: Rectangle) -> impl Iterator<Item = Pixel<C>> + '_ {
+        let width = self.size.width as usize;
+        self.mask
+            .iter()
+            .enumerate()
+            .filter(|(_, &inside)| inside)
+            .map(move |(idx, _)| EgPoint::new((idx % width) as i32, (idx / width) as i32))
+            .filter(move |&point| area.contains(point))
+            .map(move |point| Pixel(point - area.top_left, self.color))
+    }
+}
+
+impl<C> OriginDimensions for IconBitmap<C> {
+    fn size(&self) -> EgSize {
+        self.size
+    }
+}
+
+impl<C: PixelColor> ImageDrawable for IconBitmap<C> {
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(self.pixels(Rectangle::new(EgPoint::zero(), self.size)))
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(self.pixels(*area))
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// Errors returned by the runtime APIs of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// There is no icon with the given name.
+    UnknownIcon {
+        name: String,
+        /// The most similar icon names, best match first.
+        suggestions: Vec<&'static str>,
+    },
+    /// There is no style variant with the given name.
+    UnknownVariant(String),
+    /// SVG path data couldn't be parsed.
+    ParseError(String),
+    /// Binary icon data couldn't be decoded.
+    DecodeError(String),
+    /// The graphics backend failed to render an icon to an image.
+    ///
+    /// Only the rendering APIs of the `druid` feature return this, but it exists either way so
+    /// that matching on errors doesn't depend on which features are enabled.
+    Render(String),
+}
+
+impl Error {
+    /// An [`Error::UnknownIcon`], suggesting the closest of `candidates`.
+    pub(crate) fn unknown_icon<'a>(
+        name: &str,
+        candidates: impl Iterator<Item = &'a (&'static str, crate::IconPaths)>,
+    ) -> Self {
+        Error::UnknownIcon {
+            name: name.to_owned(),
+            suggestions: suggestions(name, candidates.map(|(name, _)| *name)),
+        }
+    }
+
+    /// An [`Error::Render`] from a backend error.
+    #[cfg(feature = "druid")]
+    pub(crate) fn render(error: druid::piet::Error) -> Self {
+        Error::Render(error.to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownIcon { name, suggestions } => {
+                write!(f, "no icon named `{}`", name)?;
+                if let Some((first, rest)) = suggestions.split_first() {
+                    write!(f, " (did you mean `{}`", first)?;
+                    for suggestion in rest {
+                        write!(f, ", `{}`", suggestion)?;
+                    }
+                    f.write_str("?)")?;
+                }
+                Ok(())
+            }
+            Error::UnknownVariant(name) => write!(f, "no icon variant named `{}`", name),
+            Error::ParseError(error) => write!(f, "could not parse icon path data: {}", error),
+            Error::DecodeError(error) => write!(f, "could not decode icon data: {}", error),
+            Error::Render(error) => write!(f, "could not render icon: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The maximum number of suggestions to offer for a misspelled name.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The closest few `candidates` to `name`, ignoring any that are too different to be useful.
+fn suggestions(name: &str, candidates: impl Iterator<Item = &'static str>) -> Vec<&'static str> {
+    // Allow roughly one edit for every three characters.
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut matches = candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect::<Vec<_>>();
+    matches.sort_unstable();
+    matches
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let next = (diag + usize::from(a != *b))
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            diag = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
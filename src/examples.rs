@@ -0,0 +1,303 @@
+//! A few icons of each category in small widget trees, to show how they are used.
+//!
+//! Generated by `generate-icons` along with the icons, so that the doctests here check
+//! every category against the widgets. Categories behind a feature are only checked with
+//! it.
+//!
+//! # action
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::action, IconButton};
+//!
+//! fn action_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(action::ABC))
+//!         .with_child(action::ACCESSIBILITY.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(action::ACCESSIBILITY_NEW.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = action_toolbar();
+//! ```
+//!
+//! # alert
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::alert, IconButton};
+//!
+//! fn alert_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(alert::ADD_ALERT))
+//!         .with_child(alert::AUTO_DELETE.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(alert::ERROR.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = alert_toolbar();
+//! ```
+//!
+//! # av
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::av, IconButton};
+//!
+//! fn av_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(av::ADD_TO_QUEUE))
+//!         .with_child(av::AIRPLAY.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(av::ALBUM.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = av_toolbar();
+//! ```
+//!
+//! # communication
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::communication, IconButton};
+//!
+//! fn communication_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(communication::ADD_IC_CALL))
+//!         .with_child(communication::ALTERNATE_EMAIL.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(communication::APP_REGISTRATION.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = communication_toolbar();
+//! ```
+//!
+//! # content
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::content, IconButton};
+//!
+//! fn content_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(content::ADD))
+//!         .with_child(content::ADD_BOX.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(content::ADD_CIRCLE.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = content_toolbar();
+//! ```
+//!
+//! # device
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::device, IconButton};
+//!
+//! fn device_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(device::ACCESS_ALARM))
+//!         .with_child(device::ACCESS_ALARMS.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(device::ACCESS_TIME.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = device_toolbar();
+//! ```
+//!
+//! # editor
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::editor, IconButton};
+//!
+//! fn editor_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(editor::ADD_CHART))
+//!         .with_child(editor::ADD_COMMENT.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(editor::ALIGN_HORIZONTAL_CENTER.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = editor_toolbar();
+//! ```
+//!
+//! # file
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::file, IconButton};
+//!
+//! fn file_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(file::APPROVAL))
+//!         .with_child(file::ATTACH_EMAIL.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(file::ATTACHMENT.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = file_toolbar();
+//! ```
+//!
+//! # hardware
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::hardware, IconButton};
+//!
+//! fn hardware_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(hardware::ADF_SCANNER))
+//!         .with_child(hardware::BROWSER_NOT_SUPPORTED.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(hardware::BROWSER_UPDATED.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = hardware_toolbar();
+//! ```
+//!
+//! # home
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::home, IconButton};
+//!
+//! fn home_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(home::CLOUDY_SNOWING))
+//!         .with_child(home::FOGGY.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(home::SENSOR_DOOR.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = home_toolbar();
+//! ```
+//!
+//! # image
+//!
+//! Needs the `category-image` feature.
+//!
+//! ```
+//! # #[cfg(feature = "category-image")]
+//! # {
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::image, IconButton};
+//!
+//! fn image_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(image::ADD_A_PHOTO))
+//!         .with_child(image::ADD_PHOTO_ALTERNATE.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(image::ADD_TO_PHOTOS.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = image_toolbar();
+//! # }
+//! ```
+//!
+//! # maps
+//!
+//! Needs the `category-maps` feature.
+//!
+//! ```
+//! # #[cfg(feature = "category-maps")]
+//! # {
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::maps, IconButton};
+//!
+//! fn maps_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(maps::ADD_BUSINESS))
+//!         .with_child(maps::ADD_LOCATION.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(maps::ADD_LOCATION_ALT.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = maps_toolbar();
+//! # }
+//! ```
+//!
+//! # navigation
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::navigation, IconButton};
+//!
+//! fn navigation_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(navigation::APP_SETTINGS_ALT))
+//!         .with_child(navigation::APPS.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(navigation::APPS_OUTAGE.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = navigation_toolbar();
+//! ```
+//!
+//! # notification
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::notification, IconButton};
+//!
+//! fn notification_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(notification::ACCOUNT_TREE))
+//!         .with_child(notification::ADB.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(notification::ADD_CALL.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = notification_toolbar();
+//! ```
+//!
+//! # places
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::places, IconButton};
+//!
+//! fn places_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(places::AC_UNIT))
+//!         .with_child(places::AIRPORT_SHUTTLE.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(places::ALL_INCLUSIVE.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = places_toolbar();
+//! ```
+//!
+//! # search
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::search, IconButton};
+//!
+//! fn search_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(search::BATHROOM))
+//!         .with_child(search::BED.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(search::BEDROOM_BABY.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = search_toolbar();
+//! ```
+//!
+//! # social
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::social, IconButton};
+//!
+//! fn social_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(social::ADD_MODERATOR))
+//!         .with_child(social::ADD_REACTION.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(social::ARCHITECTURE.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = social_toolbar();
+//! ```
+//!
+//! # toggle
+//!
+//! ```
+//! use druid::widget::Flex;
+//! use druid::{Color, Widget, WidgetExt};
+//! use druid_material_icons::{normal::toggle, IconButton};
+//!
+//! fn toggle_toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(IconButton::new(toggle::CHECK_BOX))
+//!         .with_child(toggle::CHECK_BOX_OUTLINE_BLANK.new(Color::BLACK).fix_size(24., 24.))
+//!         .with_child(toggle::INDETERMINATE_CHECK_BOX.new(Color::BLACK).fix_size(24., 24.))
+//! }
+//! let _ = toggle_toolbar();
+//! ```
@@ -0,0 +1,60 @@
+use druid::Color;
+
+/// A transformation applied to an icon's color before it is painted.
+///
+/// This is useful for deriving disabled or "ghost" states from an existing color.
+///
+/// # Examples
+///
+/// ```
+/// use druid::Color;
+/// use druid_material_icons::ColorFilter;
+/// assert_eq!(ColorFilter::Invert.apply(Color::BLACK), Color::WHITE);
+/// assert_eq!(ColorFilter::Grayscale.apply(Color::WHITE), Color::WHITE);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColorFilter {
+    /// Replace the color with a gray of the same luminance.
+    Grayscale,
+    /// Invert the red, green and blue channels.
+    Invert,
+    /// Multiply the red, green and blue channels by the given factor.
+    Brightness(f64),
+    /// A 4x5 row-major color matrix, as used by Android and SVG's `feColorMatrix`.
+    ///
+    /// Each output channel (red, green, blue, alpha) is the dot product of its row with the input
+    /// `[r, g, b, a, 1]`, where all channels are in the range `0..=1`.
+    Matrix([f64; 20]),
+}
+
+impl ColorFilter {
+    /// Apply this filter to a color.
+    pub fn apply(&self, color: Color) -> Color {
+        let (r, g, b, a) = color.as_rgba();
+        let (r, g, b, a) = match self {
+            ColorFilter::Grayscale => {
+                // Rec. 709 luma coefficients.
+                let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+                (luma, luma, luma, a)
+            }
+            ColorFilter::Invert => (1. - r, 1. - g, 1. - b, a),
+            ColorFilter::Brightness(factor) => (r * factor, g * factor, b * factor, a),
+            ColorFilter::Matrix(m) => {
+                let row = |i: usize| {
+                    m[i * 5] * r
+                        + m[i * 5 + 1] * g
+                        + m[i * 5 + 2] * b
+                        + m[i * 5 + 3] * a
+                        + m[i * 5 + 4]
+                };
+                (row(0), row(1), row(2), row(3))
+            }
+        };
+        Color::rgba(
+            r.clamp(0., 1.),
+            g.clamp(0., 1.),
+            b.clamp(0., 1.),
+            a.clamp(0., 1.),
+        )
+    }
+}
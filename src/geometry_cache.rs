@@ -0,0 +1,69 @@
+//! A global cache of icon paths flattened to line segments, shared by every [`Icon`](crate::Icon).
+//!
+//! Flattening curves is most of the cost of filling an icon, and icon-dense UIs fill the same few
+//! icons at the same few sizes over and over. Entries are keyed by the icon's path data and its
+//! scale, rounded to a quarter octave so that animated sizes still mostly hit the cache.
+
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use druid::kurbo::{flatten, BezPath};
+use lru::LruCache;
+
+use crate::IconPaths;
+
+/// The number of flattened icons to keep.
+const CAPACITY: usize = 1024;
+
+/// How far the flattened paths may stray from the curves, in pixels.
+///
+/// This is much finer than backends flatten curves themselves, so that edges land where they
+/// would without the cache: a tenth of a pixel already changes antialiased edges visibly.
+const TOLERANCE: f64 = 0.01;
+
+/// How finely scales are distinguished, in steps per doubling.
+const STEPS_PER_OCTAVE: f64 = 4.;
+
+/// The address and length of an icon's path data, and its quantized scale.
+type Key = (usize, usize, i32);
+
+static CACHE: OnceLock<Mutex<LruCache<Key, Arc<[BezPath]>>>> = OnceLock::new();
+
+/// The paths of `icon` flattened for drawing at `scale` pixels per icon unit, in icon units.
+pub(crate) fn flattened(icon: &IconPaths, scale: f64) -> Arc<[BezPath]> {
+    let step = (scale.max(f64::MIN_POSITIVE).log2() * STEPS_PER_OCTAVE).round() as i32;
+    let key = (icon.paths.as_ptr() as usize, icon.paths.len(), step);
+    let cache = CACHE.get_or_init(|| {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(CAPACITY).expect("capacity is nonzero"),
+        ))
+    });
+    // Nothing can panic while the lock is held, but a poisoned cache is still usable anyway.
+    if let Some(hit) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&key)
+    {
+        return hit.clone();
+    }
+
+    // Flatten for the largest scale that rounds to this step, so we're never too coarse.
+    let max_scale = ((f64::from(step) + 0.5) / STEPS_PER_OCTAVE).exp2();
+    let tolerance = TOLERANCE / max_scale;
+    let paths = icon
+        .paths
+        .iter()
+        .map(|path| {
+            let mut flat = BezPath::new();
+            flatten(path.els.iter().copied(), tolerance, |el| flat.push(el));
+            flat
+        })
+        .collect::<Arc<[_]>>();
+    cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .put(key, paths.clone());
+    paths
+}
@@ -0,0 +1,106 @@
+//! Icons triangulated into vertex and index buffers, so that GPU renderers (e.g. wgpu) can
+//! upload them directly. Build with the `gpu` feature.
+
+use bytemuck::{Pod, Zeroable};
+use lyon_tessellation::{
+    math::point, path::Path, BuffersBuilder, FillOptions, FillRule, FillTessellator, FillVertex,
+    VertexBuffers,
+};
+
+use crate::{IconPaths, PathEl, Point};
+
+/// A vertex of a triangulated icon.
+///
+/// It is `#[repr(C)]` and [`Pod`](bytemuck::Pod), so a slice of them can be cast to bytes with
+/// `bytemuck::cast_slice` for a vertex buffer: two `f32`s of position then an `f32` of opacity.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default, Pod, Zeroable)]
+pub struct Vertex {
+    /// The position in the icon's coordinates, from `(0, 0)` to its size, with `y` down.
+    pub position: [f32; 2],
+    /// The opacity of the path the vertex belongs to, to multiply the icon color's alpha by.
+    pub opacity: f32,
+}
+
+impl IconPaths {
+    /// The icon as triangles: a vertex buffer and a triangle list of indices into it.
+    ///
+    /// Curves are flattened so that the triangles stray at most `tolerance` from them, in the
+    /// icon's units. Paths are filled with the non-zero rule, like the widgets fill them, and
+    /// come in order, so drawing the triangles in order with alpha blending matches the widgets.
+    /// A path lyon can't triangulate is left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (vertices, indices) = druid_material_icons::ADD.as_gpu_vertices(0.01);
+    /// assert_eq!(indices.len() % 3, 0);
+    /// assert!(indices.iter().all(|&idx| (idx as usize) < vertices.len()));
+    /// let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    /// assert_eq!(bytes.len(), vertices.len() * 12);
+    /// ```
+    pub fn as_gpu_vertices(&self, tolerance: f64) -> (Vec<Vertex>, Vec<u32>) {
+        let mut buffers: VertexBuffers<Vertex, u32> = VertexBuffers::new();
+        let mut tessellator = FillTessellator::new();
+        let options = FillOptions::tolerance(tolerance as f32).with_fill_rule(FillRule::NonZero);
+        for path in self.paths {
+            let opacity = path.opacity as f32;
+            let vertices = buffers.vertices.len();
+            let indices = buffers.indices.len();
+            let result = tessellator.tessellate_path(
+                &to_lyon_path(path.els),
+                &options,
+                &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+                    let position = vertex.position();
+                    Vertex {
+                        position: [position.x, position.y],
+                        opacity,
+                    }
+                }),
+            );
+            if result.is_err() {
+                // Drop whatever the failed path added, so it doesn't leave stray triangles.
+                buffers.vertices.truncate(vertices);
+                buffers.indices.truncate(indices);
+            }
+        }
+        (buffers.vertices, buffers.indices)
+    }
+}
+
+/// `els` as a lyon path.
+fn to_lyon_path(els: &[PathEl]) -> Path {
+    let to_lyon = |p: Point| point(p.x as f32, p.y as f32);
+    let mut builder = Path::builder();
+    let mut open = false;
+    for el in els {
+        match *el {
+            PathEl::MoveTo(p) => {
+                if open {
+                    builder.end(false);
+                }
+                builder.begin(to_lyon(p));
+                open = true;
+            }
+            PathEl::LineTo(p) if open => {
+                builder.line_to(to_lyon(p));
+            }
+            PathEl::QuadTo(p1, p2) if open => {
+                builder.quadratic_bezier_to(to_lyon(p1), to_lyon(p2));
+            }
+            PathEl::CurveTo(p1, p2, p3) if open => {
+                builder.cubic_bezier_to(to_lyon(p1), to_lyon(p2), to_lyon(p3));
+            }
+            PathEl::ClosePath if open => {
+                builder.end(true);
+                open = false;
+            }
+            // The generated paths start each subpath with a move.
+            _ => (),
+        }
+    }
+    if open {
+        builder.end(false);
+    }
+    builder.build()
+}
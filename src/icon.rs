@@ -0,0 +1,1251 @@
+//! The [`Icon`] widget, and the commands it responds to.
+
+use std::time::Duration;
+
+use druid::{
+    commands::CLOSE_WINDOW,
+    kurbo::{Affine, Circle, Point, Rect, Shape, Size, Vec2},
+    piet::{FixedLinearGradient, GradientStop},
+    widget::prelude::*,
+    Color, Data, FontDescriptor, Key, Scalable, Scale, Selector, TimerToken, WindowId,
+};
+
+use crate::{
+    blend::{self, Glyph},
+    compat, theme, ColorFilter, IconPaths,
+};
+
+/// Replace the icon an [`Icon`] displays, keeping its color.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{EventCtx, WidgetId};
+/// use druid_material_icons::{icon, DELETE};
+/// fn mark_for_deletion(ctx: &mut EventCtx, icon_id: WidgetId) {
+///     ctx.submit_command(icon::SET_ICON.with(DELETE).to(icon_id));
+/// }
+/// ```
+pub const SET_ICON: Selector<IconPaths> = Selector::new("druid-material-icons.icon.set-icon");
+
+/// Change the color an [`Icon`] is painted with.
+pub const SET_COLOR: Selector<Color> = Selector::new("druid-material-icons.icon.set-color");
+
+/// Change the weight of an [`Icon`] with weight masters, animating if it has a weight
+/// animation.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{EventCtx, WidgetId};
+/// use druid_material_icons::icon;
+/// fn embolden_on_hover(ctx: &mut EventCtx, icon_id: WidgetId, hot: bool) {
+///     let weight = if hot { 600. } else { 400. };
+///     ctx.submit_command(icon::SET_WEIGHT.with(weight).to(icon_id));
+/// }
+/// ```
+pub const SET_WEIGHT: Selector<f64> = Selector::new("druid-material-icons.icon.set-weight");
+
+/// Change how far between its outlined (0) and filled (1) versions an [`Icon`] is drawn,
+/// animating if it has a fill animation.
+pub const SET_FILL_AMOUNT: Selector<f64> =
+    Selector::new("druid-material-icons.icon.set-fill-amount");
+
+/// Start (`true`) or stop (`false`) the loading shimmer of an [`Icon`] or
+/// [`DynIcon`](crate::DynIcon).
+pub const SET_LOADING: Selector<bool> = Selector::new("druid-material-icons.icon.set-loading");
+
+/// Select or deselect an [`Icon`], animating if it has a selection animation.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{EventCtx, WidgetId};
+/// use druid_material_icons::icon;
+/// fn toggle_in_picker(ctx: &mut EventCtx, icon_id: WidgetId, picked: bool) {
+///     ctx.submit_command(icon::SET_SELECTED.with(picked).to(icon_id));
+/// }
+/// ```
+pub const SET_SELECTED: Selector<bool> = Selector::new("druid-material-icons.icon.set-selected");
+
+/// How long the loading shimmer takes to sweep across the icon.
+const SHIMMER_PERIOD: Duration = Duration::from_millis(1200);
+
+/// The opacity selected icons are drawn at, under their checkmark badge.
+const SELECTED_OPACITY: f64 = 0.38;
+/// The size of the checkmark badge of selected icons, as a fraction of the icon's size.
+const BADGE_SIZE: f64 = 0.5;
+
+/// How long the mouse has to rest on an icon before its tooltip shows.
+const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+/// Where the tooltip goes relative to the mouse, to keep it clear of the cursor.
+const TOOLTIP_OFFSET: Vec2 = Vec2::new(15., 15.);
+
+/// Where an [`Icon`] puts its baseline, for lining up with text in a
+/// [`Flex`](druid::widget::Flex) using [`CrossAxisAlignment::Baseline`](druid::widget::CrossAxisAlignment::Baseline).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum IconAlignment {
+    /// The bottom of the icon sits on the baseline, as for any widget.
+    #[default]
+    Bottom,
+    /// The visual center of the icon lines up with the middle of the text's capital letters.
+    ///
+    /// The text size comes from [`match_font_size`](Icon::match_font_size) if it is set, and
+    /// otherwise is taken to be the icon's height.
+    CapHeight,
+}
+
+/// A widget that displays a material icon. Use constraints, [`theme::ICON_SIZE`] or
+/// [`match_font_size`](Icon::match_font_size) to set the preferred size.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{Widget, Data, Color, WidgetExt};
+/// # #[derive(Data, Clone)]
+/// # struct MyData;
+/// use druid_material_icons as icons;
+/// fn build_ui() -> impl Widget<MyData> {
+///     icons::ADD.new(Color::BLACK).fix_width(12.0).center()
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Icon {
+    paths: IconPaths,
+    color: Color,
+    filter: Option<ColorFilter>,
+    halo: Option<Halo>,
+    size_animation: Option<Duration>,
+    resize: Option<Resize>,
+    /// Our size after the last layout, used to spot when it changes.
+    last_size: Option<Size>,
+    /// A text style whose size we follow instead of [`theme::ICON_SIZE`].
+    text_size: Option<TextSize>,
+    alignment: IconAlignment,
+    /// Versions of the icon drawn at different weights, sorted by weight.
+    weight_masters: &'static [(f64, IconPaths)],
+    weight: Axis,
+    /// Versions of the icon drawn for different display sizes, sorted by size.
+    optical_size_masters: &'static [(f64, IconPaths)],
+    /// How far between the outlined (0) and filled (1) versions of the icon to draw, or `None` to
+    /// draw `paths` as given.
+    fill: Option<Axis>,
+    /// Whether to clip painting to the widget's bounds.
+    clip: bool,
+    /// A dashed stroke to draw the glyph's outline with instead of filling it.
+    dashes: Option<Dashes>,
+    /// How far through its sweep the loading shimmer is, or `None` if the icon isn't loading.
+    shimmer: Option<Duration>,
+    /// How selected the icon is, from 0 to 1, which dims it and shows a checkmark badge.
+    selection: Axis,
+    tooltip: Tooltip,
+}
+
+/// Where to find the size of the text an icon is matched to.
+#[derive(Debug, Clone)]
+enum TextSize {
+    FontSize(Key<f64>),
+    Font(Key<FontDescriptor>),
+}
+
+impl TextSize {
+    /// The size of the text, if it's in `env`.
+    fn get(&self, env: &Env) -> Option<f64> {
+        match self {
+            TextSize::FontSize(key) => env.try_get(key).ok(),
+            TextSize::Font(key) => env.try_get(key).ok().map(|font| font.size),
+        }
+    }
+
+    fn changed(&self, ctx: &UpdateCtx) -> bool {
+        match self {
+            TextSize::FontSize(key) => ctx.env_key_changed(key),
+            TextSize::Font(key) => ctx.env_key_changed(key),
+        }
+    }
+}
+
+/// A value that can animate smoothly towards new targets.
+#[derive(Debug, Clone)]
+struct Axis {
+    target: f64,
+    animation: Option<Duration>,
+    transition: Option<Transition>,
+}
+
+/// An animation of an [`Axis`] towards its target.
+#[derive(Debug, Clone)]
+struct Transition {
+    from: f64,
+    elapsed: Duration,
+}
+
+impl Axis {
+    fn new(value: f64) -> Self {
+        Axis {
+            target: value,
+            animation: None,
+            transition: None,
+        }
+    }
+
+    /// The value right now, part way through any animation.
+    fn current(&self) -> f64 {
+        match (&self.transition, self.animation) {
+            (Some(transition), Some(duration)) => {
+                let t = ease(progress(transition.elapsed, duration));
+                transition.from + (self.target - transition.from) * t
+            }
+            _ => self.target,
+        }
+    }
+
+    /// Jump straight to `value`.
+    fn set(&mut self, value: f64) {
+        self.target = value;
+        self.transition = None;
+    }
+
+    /// Move to `value`, animating if we have an animation. Returns whether we need animation
+    /// frames.
+    fn animate_to(&mut self, value: f64) -> bool {
+        if self.animation.is_none() {
+            self.set(value);
+            return false;
+        }
+        // If we're interrupting an animation, start again from the current value.
+        self.transition = Some(Transition {
+            from: self.current(),
+            elapsed: Duration::ZERO,
+        });
+        self.target = value;
+        true
+    }
+
+    /// Advance any animation. Returns whether we were animating.
+    fn tick(&mut self, interval: Duration) -> bool {
+        let transition = match &mut self.transition {
+            Some(transition) => transition,
+            None => return false,
+        };
+        transition.elapsed += interval;
+        if transition.elapsed >= self.animation.unwrap_or_default() {
+            self.transition = None;
+        }
+        true
+    }
+
+    fn is_animating(&self) -> bool {
+        self.transition.is_some()
+    }
+}
+
+/// An animation of the painted size towards the layout size.
+#[derive(Debug, Clone)]
+struct Resize {
+    from: Size,
+    elapsed: Duration,
+}
+
+/// An outline drawn around the glyph, behind its fill.
+#[derive(Debug, Clone)]
+struct Halo {
+    /// How far the halo extends beyond the glyph, in display points.
+    width: f64,
+    color: Color,
+}
+
+/// Text to show in a small window when the mouse rests on the icon.
+#[derive(Debug, Clone)]
+struct Tooltip {
+    /// The text, or `None` for no tooltip.
+    text: Option<String>,
+    state: TooltipState,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TooltipState {
+    Hidden,
+    /// Waiting for the mouse to rest, at `position` in the window.
+    Waiting {
+        timer: TimerToken,
+        position: Point,
+    },
+    Showing(WindowId),
+}
+
+/// A dashed stroke along the glyph's outline.
+#[derive(Debug, Clone)]
+struct Dashes {
+    /// The width of the stroke, in display points.
+    width: f64,
+    /// Alternating lengths of dashes and gaps, in display points.
+    pattern: Vec<f64>,
+}
+
+impl Icon {
+    #[inline]
+    pub fn new(paths: IconPaths, color: Color) -> Self {
+        Self {
+            paths,
+            color,
+            filter: None,
+            halo: None,
+            size_animation: None,
+            resize: None,
+            last_size: None,
+            text_size: None,
+            alignment: IconAlignment::Bottom,
+            weight_masters: &[],
+            weight: Axis::new(400.),
+            optical_size_masters: &[],
+            fill: None,
+            clip: false,
+            dashes: None,
+            shimmer: None,
+            selection: Axis::new(0.),
+            tooltip: Tooltip {
+                text: None,
+                state: TooltipState::Hidden,
+            },
+        }
+    }
+
+    /// The icon being displayed.
+    #[inline]
+    pub fn icon(&self) -> IconPaths {
+        self.paths
+    }
+
+    /// Change the icon being displayed.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_icon(&mut self, paths: IconPaths) {
+        self.paths = paths;
+    }
+
+    /// Change the color the icon is painted with.
+    ///
+    /// The caller is responsible for requesting paint.
+    #[inline]
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    /// Builder-style method to transform the icon's color before painting.
+    #[inline]
+    pub fn with_filter(mut self, filter: ColorFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Set or clear the filter applied to the icon's color.
+    #[inline]
+    pub fn set_filter(&mut self, filter: Option<ColorFilter>) {
+        self.filter = filter;
+    }
+
+    /// Builder-style method to draw an outline of the given width and color around the glyph.
+    ///
+    /// This keeps icons legible when they are drawn over busy backgrounds like photos or maps.
+    /// The halo is drawn outside the widget's layout rect, so leave room for it.
+    #[inline]
+    pub fn with_halo(mut self, width: f64, color: Color) -> Self {
+        self.set_halo(width, color);
+        self
+    }
+
+    /// Set the width and color of the outline drawn around the glyph.
+    #[inline]
+    pub fn set_halo(&mut self, width: f64, color: Color) {
+        self.halo = Some(Halo { width, color });
+    }
+
+    /// Stop drawing an outline around the glyph.
+    #[inline]
+    pub fn clear_halo(&mut self) {
+        self.halo = None;
+    }
+
+    /// Builder-style method to animate the painted size over `duration` when the layout size
+    /// changes, rather than jumping to the new size.
+    ///
+    /// During the animation the glyph stays centered in the widget, and may be painted outside
+    /// of it.
+    #[inline]
+    pub fn with_size_animation(mut self, duration: Duration) -> Self {
+        self.size_animation = Some(duration);
+        self
+    }
+
+    /// Set how long to animate size changes for, or `None` to jump to the new size.
+    #[inline]
+    pub fn set_size_animation(&mut self, duration: Option<Duration>) {
+        self.size_animation = duration;
+        if duration.is_none() {
+            self.resize = None;
+        }
+    }
+
+    /// Builder-style method to clip painting to the widget's bounds.
+    ///
+    /// Halos and size animations can paint outside of the widget, which is usually what you
+    /// want, but can draw over neighbours in tightly packed layouts.
+    #[inline]
+    pub fn clip_to_bounds(mut self) -> Self {
+        self.clip = true;
+        self
+    }
+
+    /// Set whether to clip painting to the widget's bounds.
+    ///
+    /// The caller is responsible for requesting paint.
+    #[inline]
+    pub fn set_clip_to_bounds(&mut self, clip: bool) {
+        self.clip = clip;
+    }
+
+    /// Builder-style method to draw the glyph's outline as a dashed stroke instead of filling it.
+    ///
+    /// `pattern` holds alternating lengths of dashes and gaps, and like `width` is measured in
+    /// display points, so the dashes stay the same size whatever size the icon is. This is
+    /// useful for drop targets and placeholders that reuse an icon's shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{Color, Widget};
+    /// use druid_material_icons::UPLOAD_FILE;
+    /// fn drop_target() -> impl Widget<()> {
+    ///     UPLOAD_FILE
+    ///         .new(Color::grey(0.6))
+    ///         .with_dashed_outline(1.5, &[4., 3.])
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_dashed_outline(mut self, width: f64, pattern: &[f64]) -> Self {
+        self.set_dashed_outline(width, pattern);
+        self
+    }
+
+    /// Draw the glyph's outline as a dashed stroke of `width` with the dash `pattern`, instead
+    /// of filling it.
+    ///
+    /// The caller is responsible for requesting paint.
+    #[inline]
+    pub fn set_dashed_outline(&mut self, width: f64, pattern: &[f64]) {
+        self.dashes = Some(Dashes {
+            width,
+            pattern: pattern.to_vec(),
+        });
+    }
+
+    /// Go back to filling the glyph rather than drawing a dashed outline.
+    ///
+    /// The caller is responsible for requesting paint.
+    #[inline]
+    pub fn clear_dashed_outline(&mut self) {
+        self.dashes = None;
+    }
+
+    /// Builder-style method to show the icon as loading, with a highlight sweeping across a faint
+    /// silhouette of the glyph.
+    ///
+    /// Stop the shimmer with [`SET_LOADING`] or [`set_loading`](Icon::set_loading) when the
+    /// content arrives.
+    #[inline]
+    pub fn with_loading(mut self) -> Self {
+        self.set_loading(true);
+        self
+    }
+
+    /// Set whether the icon is shown as loading.
+    ///
+    /// The shimmer starts on the next update, so the caller is responsible for requesting one,
+    /// or should use [`SET_LOADING`] instead.
+    #[inline]
+    pub fn set_loading(&mut self, loading: bool) {
+        match (loading, self.shimmer) {
+            (true, None) => self.shimmer = Some(Duration::ZERO),
+            (false, _) => self.shimmer = None,
+            (true, Some(_)) => (),
+        }
+    }
+
+    /// Whether the icon is shown as loading.
+    #[inline]
+    pub fn is_loading(&self) -> bool {
+        self.shimmer.is_some()
+    }
+
+    /// Builder-style method to show the icon as selected or not.
+    ///
+    /// Selected icons are dimmed, with a [`CHECK_CIRCLE`](crate::CHECK_CIRCLE) badge in
+    /// [`theme::PRIMARY`] in their bottom right corner, as in Material's multi-select grids like
+    /// photo pickers. Change the selection with [`SET_SELECTED`] or
+    /// [`set_selected`](Icon::set_selected).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use druid::Color;
+    /// use druid_material_icons::{Icon, FOLDER};
+    /// let folder = Icon::new(FOLDER, Color::grey(0.4))
+    ///     .selected(true)
+    ///     .with_selection_animation(Duration::from_millis(150));
+    /// assert!(folder.is_selected());
+    /// ```
+    #[inline]
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.set_selected(selected);
+        self
+    }
+
+    /// Set whether the icon is shown as selected, without animating.
+    ///
+    /// The caller is responsible for requesting paint. Use [`SET_SELECTED`] to animate.
+    #[inline]
+    pub fn set_selected(&mut self, selected: bool) {
+        self.selection.set(if selected { 1. } else { 0. });
+    }
+
+    /// Whether the icon is shown as selected, or is animating towards being selected.
+    #[inline]
+    pub fn is_selected(&self) -> bool {
+        self.selection.target > 0.
+    }
+
+    /// Builder-style method to animate selection changes made with [`SET_SELECTED`] over
+    /// `duration`, fading the icon and growing the badge.
+    #[inline]
+    pub fn with_selection_animation(mut self, duration: Duration) -> Self {
+        self.selection.animation = Some(duration);
+        self
+    }
+
+    /// Builder-style method to show `text` in a tooltip when the mouse rests on the icon.
+    ///
+    /// Icon-only buttons and toolbars need tooltips to say what they do. The tooltip opens in a
+    /// small window after a short delay, and closes when the mouse leaves or clicks. Druid 0.7
+    /// can't open windows from widgets, so there the text is kept but never shown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{Color, Widget};
+    /// use druid_material_icons::DELETE;
+    /// fn delete_icon() -> impl Widget<()> {
+    ///     DELETE.new(Color::WHITE).with_tooltip("Delete")
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_tooltip(mut self, text: impl Into<String>) -> Self {
+        self.set_tooltip(Some(text.into()));
+        self
+    }
+
+    /// Set or remove the tooltip text.
+    ///
+    /// A tooltip that is already showing keeps its old text until the mouse leaves.
+    #[inline]
+    pub fn set_tooltip(&mut self, text: Option<String>) {
+        self.tooltip.text = text;
+    }
+
+    /// Builder-style method to make the icon prefer the same height as text of the font size
+    /// stored under `key`, instead of [`theme::ICON_SIZE`].
+    ///
+    /// This keeps a row of icons and labels consistent when the user scales their fonts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{widget::{Flex, Label}, theme, Color, Widget};
+    /// use druid_material_icons::SETTINGS;
+    /// fn settings_row() -> impl Widget<()> {
+    ///     Flex::row()
+    ///         .with_child(SETTINGS.new(Color::WHITE).match_font_size(theme::TEXT_SIZE_NORMAL))
+    ///         .with_child(Label::new("Settings"))
+    /// }
+    /// ```
+    #[inline]
+    pub fn match_font_size(mut self, key: Key<f64>) -> Self {
+        self.text_size = Some(TextSize::FontSize(key));
+        self
+    }
+
+    /// Builder-style method to make the icon prefer the same height as text in the font stored
+    /// under `key`, like [`match_font_size`](Icon::match_font_size).
+    #[inline]
+    pub fn match_font(mut self, key: Key<FontDescriptor>) -> Self {
+        self.text_size = Some(TextSize::Font(key));
+        self
+    }
+
+    /// Builder-style method to set where the icon puts its baseline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{widget::{CrossAxisAlignment, Flex, Label}, theme, Color, Widget};
+    /// use druid_material_icons::{icon::IconAlignment, WARNING};
+    /// fn warning_row() -> impl Widget<()> {
+    ///     let icon = WARNING
+    ///         .new(Color::rgb8(0xff, 0xa0, 0))
+    ///         .match_font_size(theme::TEXT_SIZE_NORMAL)
+    ///         .with_alignment(IconAlignment::CapHeight);
+    ///     Flex::row()
+    ///         .cross_axis_alignment(CrossAxisAlignment::Baseline)
+    ///         .with_child(icon)
+    ///         .with_child(Label::new("Unsaved changes"))
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_alignment(mut self, alignment: IconAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set where the icon puts its baseline.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_alignment(&mut self, alignment: IconAlignment) {
+        self.alignment = alignment;
+    }
+
+    /// Builder-style method to draw the icon at any weight, by interpolating between versions of
+    /// it drawn at different weights (e.g. the weight axis of Material Symbols).
+    ///
+    /// `masters` are `(weight, icon)` pairs sorted by weight, and replace the icon set with
+    /// [`new`](Icon::new) or [`set_icon`](Icon::set_icon) while they are set. Adjacent masters
+    /// whose paths have the same structure are interpolated smoothly, and otherwise the nearest
+    /// is drawn. The weight starts at 400, the regular weight.
+    ///
+    /// `generate-icons masters` generates the masters of Material Symbols icons from upstream,
+    /// as a module with a `{ICON}_WEIGHTS` table for each icon.
+    #[inline]
+    pub fn with_weight_masters(mut self, masters: &'static [(f64, IconPaths)]) -> Self {
+        self.set_weight_masters(masters);
+        self
+    }
+
+    /// Set the masters to interpolate between, or `&[]` to draw the icon as-is.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_weight_masters(&mut self, masters: &'static [(f64, IconPaths)]) {
+        self.weight_masters = masters;
+    }
+
+    /// Builder-style method to pick the version of the icon drawn for the size it is displayed
+    /// at, by interpolating between versions drawn for different sizes (e.g. the optical size
+    /// axis of Material Symbols, with masters at 20, 24, 40 and 48).
+    ///
+    /// Icons drawn for small sizes have thicker strokes and simpler shapes, so this keeps the
+    /// stroke weight consistent as the icon is resized. `masters` are `(size, icon)` pairs sorted
+    /// by size, and replace the icon set with [`new`](Icon::new) or [`set_icon`](Icon::set_icon)
+    /// while they are set. Weight masters take precedence if both are set.
+    ///
+    /// `generate-icons masters` generates these too, as `{ICON}_OPTICAL_SIZES` tables.
+    #[inline]
+    pub fn with_optical_size_masters(mut self, masters: &'static [(f64, IconPaths)]) -> Self {
+        self.set_optical_size_masters(masters);
+        self
+    }
+
+    /// Set the optical size masters to choose between, or `&[]` to draw the icon as-is.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_optical_size_masters(&mut self, masters: &'static [(f64, IconPaths)]) {
+        self.optical_size_masters = masters;
+    }
+
+    /// Set the weight to draw at, without animating.
+    ///
+    /// The caller is responsible for requesting paint. Use [`SET_WEIGHT`] to animate.
+    #[inline]
+    pub fn set_weight(&mut self, weight: f64) {
+        self.weight.set(weight);
+    }
+
+    /// Builder-style method to animate weight changes made with [`SET_WEIGHT`] over `duration`.
+    #[inline]
+    pub fn with_weight_animation(mut self, duration: Duration) -> Self {
+        self.weight.animation = Some(duration);
+        self
+    }
+
+    /// Builder-style method to draw the icon part way between its outlined and filled versions,
+    /// from 0 (outlined) to 1 (filled).
+    ///
+    /// This only affects icons that have both versions (see
+    /// [`IconPaths::fill_pair`](crate::IconPaths::fill_pair)), and either version can be the
+    /// icon set with [`new`](Icon::new). The versions are morphed into each other if their
+    /// paths have the same structure, and crossfaded otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use druid::Color;
+    /// use druid_material_icons::{Icon, FAVORITE_BORDER};
+    /// // Animate to filled when the tab is selected, with `icon::SET_FILL_AMOUNT`.
+    /// let tab_icon = Icon::new(FAVORITE_BORDER, Color::WHITE)
+    ///     .with_fill_amount(0.)
+    ///     .with_fill_animation(Duration::from_millis(200));
+    /// ```
+    #[inline]
+    pub fn with_fill_amount(mut self, amount: f64) -> Self {
+        self.set_fill_amount(amount);
+        self
+    }
+
+    /// Set how far between the outlined and filled versions to draw, without animating.
+    ///
+    /// The caller is responsible for requesting paint. Use [`SET_FILL_AMOUNT`] to animate.
+    #[inline]
+    pub fn set_fill_amount(&mut self, amount: f64) {
+        self.fill_axis().set(amount.clamp(0., 1.));
+    }
+
+    /// Builder-style method to animate fill changes made with [`SET_FILL_AMOUNT`] over
+    /// `duration`.
+    #[inline]
+    pub fn with_fill_animation(mut self, duration: Duration) -> Self {
+        self.fill_axis().animation = Some(duration);
+        self
+    }
+
+    /// The fill axis, starting from whichever version of the icon we were given.
+    fn fill_axis(&mut self) -> &mut Axis {
+        let paths = self.paths;
+        self.fill.get_or_insert_with(|| {
+            let filled = paths
+                .fill_pair()
+                .is_some_and(|(_, filled)| filled.name == paths.name);
+            Axis::new(if filled { 1. } else { 0. })
+        })
+    }
+
+    /// The geometry to paint when displayed at `size`, with the opacity to paint each at.
+    fn glyphs(&self, size: Size) -> Vec<(Glyph, f64)> {
+        if let Some(glyph) = blend::at(self.weight_masters, self.weight.current()) {
+            return vec![(glyph, 1.)];
+        }
+        let optical_size = size.width.min(size.height);
+        if let Some(glyph) = blend::at(self.optical_size_masters, optical_size) {
+            return vec![(glyph, 1.)];
+        }
+        let (outlined, filled) = match self.paths.fill_pair() {
+            Some(pair) => pair,
+            None => return vec![(Glyph::Static(self.paths), 1.)],
+        };
+        let amount = match &self.fill {
+            Some(fill) => fill.current(),
+            None => return vec![(Glyph::Static(self.paths), 1.)],
+        };
+        match amount {
+            t if t <= 0. => vec![(Glyph::Static(outlined), 1.)],
+            t if t >= 1. => vec![(Glyph::Static(filled), 1.)],
+            t => match blend::lerp(&outlined, &filled, t) {
+                Some(morphed) => vec![(morphed, 1.)],
+                None => vec![
+                    (Glyph::Static(outlined), 1. - t),
+                    (Glyph::Static(filled), t),
+                ],
+            },
+        }
+    }
+
+    /// The size to paint at, given our layout size.
+    fn painted_size(&self, size: Size) -> Size {
+        let (resize, duration) = match (&self.resize, self.size_animation) {
+            (Some(resize), Some(duration)) if !duration.is_zero() => (resize, duration),
+            _ => return size,
+        };
+        let t = ease(progress(resize.elapsed, duration));
+        resize.from.to_vec2().lerp(size.to_vec2(), t).to_size()
+    }
+
+    /// The color to paint with, after applying any filter.
+    fn filtered_color(&self) -> Color {
+        match &self.filter {
+            Some(filter) => filter.apply(self.color.clone()),
+            None => self.color.clone(),
+        }
+    }
+
+    /// Paint the icon into any piet render context, as if the widget were laid out at `size`.
+    ///
+    /// This paints exactly what the widget would, apart from animations and pixel snapping, so
+    /// it is useful for rendering icons to images outside of a window.
+    pub fn paint_into(&self, rc: &mut impl RenderContext, size: Size, env: &Env) {
+        // The closure can't fail, so neither can saving and restoring around it.
+        let _ = rc.with_save(|rc| {
+            if self.clip {
+                rc.clip(size.to_rect());
+            }
+            self.paint_rect(rc, size.to_rect(), env, 1.);
+            Ok(())
+        });
+    }
+
+    /// Paint the icon with its opacity scaled by `alpha`.
+    ///
+    /// This doesn't save and restore the render context's transform or clip.
+    pub(crate) fn paint_with_alpha(&self, ctx: &mut PaintCtx, env: &Env, alpha: f64) {
+        let layout_size = ctx.size();
+        if self.clip {
+            ctx.clip(layout_size.to_rect());
+        }
+        let painted_size = self.painted_size(layout_size);
+        let rect = Rect::from_center_size(layout_size.to_rect().center(), painted_size);
+        let rect = match compat::pixel_grid(ctx) {
+            Some((window_origin, scale)) => snap_to_pixels(rect, window_origin, scale),
+            None => rect,
+        };
+        self.paint_rect(ctx.render_ctx, rect, env, alpha);
+    }
+
+    /// Paint the icon scaled to fill `rect`, with its opacity scaled by `alpha`, and its
+    /// selection badge if it has one.
+    fn paint_rect(&self, rc: &mut impl RenderContext, rect: Rect, env: &Env, alpha: f64) {
+        let high_contrast = theme::high_contrast(env);
+        let selection = self.selection.current();
+        let glyph_alpha = alpha * (1. - (1. - SELECTED_OPACITY) * selection);
+        // The closure can't fail, so neither can saving and restoring around it.
+        let _ = rc.with_save(|rc| {
+            self.paint_glyphs(rc, rect, high_contrast, glyph_alpha);
+            Ok(())
+        });
+        if selection > 0. {
+            paint_badge(rc, rect, env, selection, alpha);
+        }
+    }
+
+    /// Paint the glyphs scaled to fill `rect`, with their opacity scaled by `alpha`.
+    ///
+    /// This doesn't save and restore the render context's transform.
+    fn paint_glyphs(
+        &self,
+        rc: &mut impl RenderContext,
+        rect: Rect,
+        high_contrast: bool,
+        alpha: f64,
+    ) {
+        let glyphs = self.glyphs(rect.size());
+        let Size { width, height } = rect.size();
+        let Size {
+            width: icon_width,
+            height: icon_height,
+        } = glyphs[0].0.size();
+        if rect.origin() != Point::ZERO {
+            rc.transform(Affine::translate(rect.origin().to_vec2()));
+        }
+        rc.transform(Affine::scale_non_uniform(
+            width * icon_width.recip(),
+            height * icon_height.recip(),
+        ));
+        let scale = 0.5 * (width * icon_width.recip() + height * icon_height.recip());
+        for (glyph, glyph_alpha) in &glyphs {
+            let alpha = alpha * glyph_alpha;
+            match glyph {
+                #[cfg(feature = "geometry-cache")]
+                Glyph::Static(paths) => {
+                    // Flatten finely enough for the more stretched axis.
+                    let max_scale = (width / icon_width).max(height / icon_height);
+                    let flattened = crate::geometry_cache::flattened(paths, max_scale);
+                    self.paint_shapes(
+                        rc,
+                        flattened
+                            .iter()
+                            .zip(paths.paths)
+                            .map(|(shape, path)| (shape, path.opacity)),
+                        scale,
+                        high_contrast,
+                        alpha,
+                    )
+                }
+                #[cfg(not(feature = "geometry-cache"))]
+                Glyph::Static(paths) => self.paint_shapes(
+                    rc,
+                    paths.paths.iter().map(|path| (path, path.opacity)),
+                    scale,
+                    high_contrast,
+                    alpha,
+                ),
+                Glyph::Blended(paths, _) => self.paint_shapes(
+                    rc,
+                    paths.iter().map(|(path, opacity)| (path, *opacity)),
+                    scale,
+                    high_contrast,
+                    alpha,
+                ),
+            }
+        }
+    }
+
+    /// Paint `(shape, opacity)` pairs in icon coordinates, which are `scale` times smaller than
+    /// display points.
+    fn paint_shapes<'a, S: Shape + 'a>(
+        &self,
+        rc: &mut impl RenderContext,
+        shapes: impl Iterator<Item = (&'a S, f64)> + Clone,
+        scale: f64,
+        high_contrast: bool,
+        alpha: f64,
+    ) {
+        if let Some(halo) = &self.halo {
+            // The stroke is centered on the outline and the fill covers the inner half, so double
+            // the width. Convert it from display points to icon units while we're at it.
+            let stroke_width = 2. * halo.width / scale;
+            let (_, _, _, halo_alpha) = halo.color.as_rgba();
+            let brush = rc.solid_brush(halo.color.clone().with_alpha(halo_alpha * alpha));
+            for (shape, _) in shapes.clone() {
+                rc.stroke(shape, &brush, stroke_width);
+            }
+        }
+        let base_color = self.filtered_color();
+        // Dashes are measured in display points, so convert them to icon units.
+        let dashes = self.dashes.as_ref().map(|dashes| {
+            let pattern = dashes.pattern.iter().map(|length| length / scale).collect();
+            (dashes.width / scale, compat::dashed(pattern))
+        });
+        // TODO This makes slightly more brushes than it needs to. Probably not an issue.
+        for (shape, opacity) in shapes {
+            let color = base_color.clone();
+            let color = if high_contrast {
+                // Translucency reduces contrast, so draw everything opaque, apart from fades.
+                color.with_alpha(alpha)
+            } else {
+                let (_, _, _, color_alpha) = color.as_rgba();
+                color.with_alpha(color_alpha * opacity * alpha)
+            };
+            let brush = match self.shimmer {
+                Some(elapsed) => self.shimmer_brush(rc, color.clone(), elapsed, high_contrast),
+                None => None,
+            };
+            let brush = brush.unwrap_or_else(|| rc.solid_brush(color));
+            match &dashes {
+                Some((width, style)) => rc.stroke_styled(shape, &brush, *width, style),
+                None => rc.fill(shape, &brush),
+            }
+        }
+    }
+
+    /// A brush for the loading shimmer, `elapsed` into its sweep, in icon coordinates.
+    ///
+    /// This is a faint version of `color`, which a path would otherwise be filled with, with a
+    /// brighter band moving from left to right. In high contrast the silhouette is less faint.
+    fn shimmer_brush<R: RenderContext>(
+        &self,
+        rc: &mut R,
+        color: Color,
+        elapsed: Duration,
+        high_contrast: bool,
+    ) -> Option<R::Brush> {
+        let Size { width, height } = self.design_size();
+        let progress = elapsed.as_secs_f64() / SHIMMER_PERIOD.as_secs_f64();
+        // Start with the band just off the left edge, and finish just off the right.
+        let x = -width + 2. * width * progress;
+        let (_, _, _, color_alpha) = color.as_rgba();
+        let shade = |amount: f64| color.clone().with_alpha(color_alpha * amount);
+        let (faint, bright) = if high_contrast { (0.6, 1.) } else { (0.2, 0.5) };
+        let gradient = FixedLinearGradient {
+            start: Point::new(x, 0.),
+            end: Point::new(x + width, height),
+            stops: vec![
+                GradientStop {
+                    pos: 0.,
+                    color: shade(faint),
+                },
+                GradientStop {
+                    pos: 0.5,
+                    color: shade(bright),
+                },
+                GradientStop {
+                    pos: 1.,
+                    color: shade(faint),
+                },
+            ],
+        };
+        rc.gradient(gradient).ok()
+    }
+
+    /// The size the icon was designed at, which sets its aspect ratio.
+    fn design_size(&self) -> Size {
+        match (
+            self.weight_masters.first(),
+            self.optical_size_masters.first(),
+        ) {
+            (Some((_, master)), _) | (None, Some((_, master))) => master.size,
+            (None, None) => self.paths.size,
+        }
+    }
+}
+
+/// Paint the checkmark badge of an icon filling `rect`, grown to `amount` of its full size.
+fn paint_badge(rc: &mut impl RenderContext, rect: Rect, env: &Env, amount: f64, alpha: f64) {
+    let defaults = &theme::MATERIAL3_SCHEME;
+    let badge = crate::normal::action::CHECK_CIRCLE;
+    let full_size = BADGE_SIZE * rect.width().min(rect.height());
+    let size = full_size * ease(amount);
+    // Keep the badge inside the icon, so clipping to bounds doesn't cut it off.
+    let center = Point::new(rect.x1 - 0.5 * full_size, rect.y1 - 0.5 * full_size);
+    let color = |key, default: Color| {
+        let color = theme::color_or(env, key, default);
+        let (_, _, _, color_alpha) = color.as_rgba();
+        color.with_alpha(color_alpha * alpha)
+    };
+    // Fill in behind the check, which is a hole in the circle.
+    let backing = rc.solid_brush(color(&theme::ON_PRIMARY, defaults.on_primary.clone()));
+    rc.fill(Circle::new(center, 0.4 * size), &backing);
+    let brush = rc.solid_brush(color(&theme::PRIMARY, defaults.primary.clone()));
+    let _ = rc.with_save(|rc| {
+        rc.transform(
+            Affine::translate(center.to_vec2() - 0.5 * Vec2::new(size, size))
+                * Affine::scale_non_uniform(size / badge.size.width, size / badge.size.height),
+        );
+        for path in badge.paths {
+            rc.fill(path, &brush);
+        }
+        Ok(())
+    });
+}
+
+/// How far through an animation of length `duration` we are, from 0 to 1.
+pub(crate) fn progress(elapsed: Duration, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        return 1.;
+    }
+    (elapsed.as_secs_f64() / duration.as_secs_f64()).min(1.)
+}
+
+/// Smoothstep, so animations ease in and out.
+pub(crate) fn ease(t: f64) -> f64 {
+    t * t * (3. - 2. * t)
+}
+
+/// Round `rect` (in widget coordinates) so its edges fall on physical pixels, so icons don't
+/// blur or shimmer depending on where they are in the window and the display's scale factor.
+fn snap_to_pixels(rect: Rect, window_origin: Point, scale: Scale) -> Rect {
+    let offset = window_origin.to_vec2();
+    let device = (rect + offset).to_px(scale);
+    let snapped = Rect::new(
+        device.x0.round(),
+        device.y0.round(),
+        device.x1.round(),
+        device.y1.round(),
+    );
+    if snapped.width() < 1. || snapped.height() < 1. {
+        // Too small to snap without disappearing.
+        return rect;
+    }
+    snapped.to_dp(scale) - offset
+}
+
+impl Tooltip {
+    fn handle_event(&mut self, ctx: &mut EventCtx, event: &Event, env: &Env) {
+        let text = match &self.text {
+            Some(text) => text,
+            // A tooltip that is already showing is closed when the mouse leaves.
+            None => return,
+        };
+        match (event, self.state) {
+            // Wait for the mouse to stop moving.
+            (Event::MouseMove(mouse), TooltipState::Hidden | TooltipState::Waiting { .. })
+                if ctx.is_hot() =>
+            {
+                self.state = TooltipState::Waiting {
+                    timer: ctx.request_timer(TOOLTIP_DELAY),
+                    position: mouse.window_pos,
+                };
+            }
+            (Event::Timer(token), TooltipState::Waiting { timer, position }) if *token == timer => {
+                self.state = match compat::show_tooltip(ctx, text, position + TOOLTIP_OFFSET, env) {
+                    Some(window) => TooltipState::Showing(window),
+                    None => TooltipState::Hidden,
+                };
+                ctx.set_handled();
+            }
+            (Event::MouseDown(_), _) => {
+                if let Some(window) = self.hide() {
+                    ctx.submit_command(CLOSE_WINDOW.to(window));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Stop waiting or showing, returning the window to close if the tooltip was showing.
+    fn hide(&mut self) -> Option<WindowId> {
+        match std::mem::replace(&mut self.state, TooltipState::Hidden) {
+            TooltipState::Showing(window) => Some(window),
+            _ => None,
+        }
+    }
+}
+
+// The widget methods don't look at the data, so they live here rather than in the `Widget` impl,
+// where they would be compiled again for every data type an `Icon` is used with.
+impl Icon {
+    fn handle_event(&mut self, ctx: &mut EventCtx, event: &Event, env: &Env) {
+        self.tooltip.handle_event(ctx, event, env);
+        if let Event::AnimFrame(interval) = event {
+            let interval = Duration::from_nanos(*interval);
+            let mut animating = false;
+            if let Some(resize) = &mut self.resize {
+                resize.elapsed += interval;
+                if resize.elapsed >= self.size_animation.unwrap_or_default() {
+                    self.resize = None;
+                }
+                animating = true;
+            }
+            animating |= self.weight.tick(interval);
+            animating |= self.fill.as_mut().is_some_and(|fill| fill.tick(interval));
+            animating |= self.selection.tick(interval);
+            if let Some(shimmer) = &mut self.shimmer {
+                let nanos = (*shimmer + interval).as_nanos() % SHIMMER_PERIOD.as_nanos();
+                *shimmer = Duration::from_nanos(nanos as u64);
+                animating = true;
+            }
+            if animating {
+                if self.resize.is_some()
+                    || self.weight.is_animating()
+                    || self.fill.as_ref().is_some_and(Axis::is_animating)
+                    || self.selection.is_animating()
+                    || self.shimmer.is_some()
+                {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+            }
+        }
+        if let Event::Command(cmd) = event {
+            if let Some(paths) = cmd.get(SET_ICON) {
+                self.paths = *paths;
+                ctx.request_layout();
+                ctx.set_handled();
+            } else if let Some(color) = cmd.get(SET_COLOR) {
+                self.color = color.clone();
+                ctx.request_paint();
+                ctx.set_handled();
+            } else if let Some(weight) = cmd.get(SET_WEIGHT) {
+                if self.weight.animate_to(*weight) {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+                ctx.set_handled();
+            } else if let Some(loading) = cmd.get(SET_LOADING) {
+                self.set_loading(*loading);
+                if *loading {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+                ctx.set_handled();
+            } else if let Some(selected) = cmd.get(SET_SELECTED) {
+                if self.selection.animate_to(if *selected { 1. } else { 0. }) {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+                ctx.set_handled();
+            } else if let Some(amount) = cmd.get(SET_FILL_AMOUNT) {
+                if self.fill_axis().animate_to(amount.clamp(0., 1.)) {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn handle_lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle) {
+        if let LifeCycle::HotChanged(false) = event {
+            if let Some(window) = self.tooltip.hide() {
+                ctx.submit_command(CLOSE_WINDOW.to(window));
+            }
+        }
+        if let (LifeCycle::WidgetAdded, Some(_)) = (event, self.shimmer) {
+            ctx.request_anim_frame();
+        }
+        if let LifeCycle::Size(size) = event {
+            // Layout can't request animation frames, so we start size animations here.
+            if let (Some(_), Some(last_size)) = (self.size_animation, self.last_size) {
+                self.resize = Some(Resize {
+                    from: self.painted_size(last_size),
+                    elapsed: Duration::ZERO,
+                });
+                ctx.request_anim_frame();
+            }
+            self.last_size = Some(*size);
+        }
+    }
+
+    fn handle_update(&mut self, ctx: &mut UpdateCtx) {
+        // Keep the shimmer going, or start it if `set_loading` was called.
+        if self.shimmer.is_some() {
+            ctx.request_anim_frame();
+        }
+        if ctx.env_key_changed(&theme::ICON_SIZE)
+            || ctx.env_key_changed(&theme::HIGH_CONTRAST)
+            || ctx.env_key_changed(&theme::HIGH_CONTRAST_MIN_SIZE)
+            || self
+                .text_size
+                .as_ref()
+                .is_some_and(|size| size.changed(ctx))
+        {
+            ctx.request_layout();
+        }
+    }
+
+    fn handle_layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, env: &Env) -> Size {
+        let Size { width, height } = self.design_size();
+        let text_height = self.text_size.as_ref().and_then(|size| size.get(env));
+        let mut preferred_width = match text_height {
+            Some(text_height) => text_height * width / height,
+            None => env.try_get(theme::ICON_SIZE).unwrap_or(width),
+        };
+        if theme::high_contrast(env) {
+            let min_size = env
+                .try_get(theme::HIGH_CONTRAST_MIN_SIZE)
+                .unwrap_or(theme::DEFAULT_HIGH_CONTRAST_MIN_SIZE);
+            preferred_width = preferred_width.max(min_size);
+        }
+        let size = bc.constrain_aspect_ratio(height / width, preferred_width);
+        if self.alignment == IconAlignment::CapHeight {
+            let ink_center = self.paths.ink_bounds().center().y * size.height / height;
+            // We can't measure the font without laying out text, and Roboto's cap height is close
+            // enough for most UI fonts.
+            let cap_height = crate::CAP_HEIGHT * text_height.unwrap_or(size.height);
+            ctx.set_baseline_offset(size.height - ink_center - 0.5 * cap_height);
+        }
+        size
+    }
+}
+
+impl<T: Data> Widget<T> for Icon {
+    #[inline]
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut T, env: &Env) {
+        self.handle_event(ctx, event, env);
+    }
+    #[inline]
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, _env: &Env) {
+        self.handle_lifecycle(ctx, event);
+    }
+    #[inline]
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {
+        self.handle_update(ctx);
+    }
+    #[inline]
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        self.handle_layout(ctx, bc, env)
+    }
+    #[inline]
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
+        ctx.with_save(|ctx| self.paint_with_alpha(ctx, env, 1.));
+    }
+}
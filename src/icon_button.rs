@@ -0,0 +1,404 @@
+use druid::{
+    kurbo::{Circle, Size},
+    widget::prelude::*,
+    ArcStr, Color, Data, HotKey, KbKey, KeyEvent, Point, Rect, Selector, TextLayout, WidgetPod,
+};
+
+use crate::{state_layer, theme, Icon, IconPaths};
+
+/// The size of an icon button's container, from the Material 3 spec.
+const CONTAINER_SIZE: f64 = 40.;
+/// The size of the icon inside the container.
+const ICON_SIZE: f64 = 24.;
+/// The smallest touch target recommended by Material, for [`IconButton::min_tap_target`].
+pub const MIN_TOUCH_TARGET: Size = Size::new(48., 48.);
+/// The width of the border of an outlined button.
+const OUTLINE_WIDTH: f64 = 1.;
+
+/// The size of the text of a hotkey hint.
+const HINT_TEXT_SIZE: f64 = 10.;
+
+/// Tell [`IconButton`]s about a key press, so that buttons with a matching
+/// [hotkey](IconButton::with_hotkey) fire.
+///
+/// Druid only sends key events to the focused widget and its ancestors, so forward key presses
+/// from your [`AppDelegate`](druid::AppDelegate) with this to make hotkeys work wherever the
+/// focus is.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{AppDelegate, DelegateCtx, Env, Event, WindowId};
+/// use druid_material_icons::HOTKEY_PRESSED;
+/// struct Delegate;
+///
+/// impl<T: druid::Data> AppDelegate<T> for Delegate {
+///     fn event(
+///         &mut self,
+///         ctx: &mut DelegateCtx,
+///         window_id: WindowId,
+///         event: Event,
+///         _data: &mut T,
+///         _env: &Env,
+///     ) -> Option<Event> {
+///         if let Event::KeyDown(key) = &event {
+///             ctx.submit_command(HOTKEY_PRESSED.with(key.clone()).to(window_id));
+///         }
+///         Some(event)
+///     }
+/// }
+/// ```
+pub const HOTKEY_PRESSED: Selector<KeyEvent> =
+    Selector::new("druid-material-icons.icon-button.hotkey-pressed");
+
+/// Disabled content and container opacities, from the Material 3 spec.
+const DISABLED_CONTENT_OPACITY: f64 = 0.38;
+const DISABLED_CONTAINER_OPACITY: f64 = 0.12;
+
+/// The Material 3 styles of [`IconButton`], in increasing order of emphasis.
+///
+/// Container and icon colors come from the color keys in [`theme`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Data)]
+pub enum IconButtonStyle {
+    /// Just the icon, for low-emphasis actions like toolbar buttons.
+    Standard,
+    /// The icon inside a border.
+    Outlined,
+    /// The icon on a secondary-colored container, between filled and outlined in emphasis.
+    FilledTonal,
+    /// The icon on a primary-colored container, for the most important action on screen.
+    Filled,
+}
+
+/// A circular button containing an icon, styled after the Material 3 icon buttons.
+///
+/// The button shows state layers when it is hovered, focused or pressed, and dims itself when
+/// disabled. While it is focused, Space and Enter click it.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::Widget;
+/// use druid_material_icons::{IconButton, IconButtonStyle, ADD};
+/// fn increment_button() -> impl Widget<u32> {
+///     IconButton::new(ADD)
+///         .with_style(IconButtonStyle::FilledTonal)
+///         .on_click(|_ctx, count: &mut u32, _env| *count += 1)
+/// }
+/// ```
+pub struct IconButton<T> {
+    icon: WidgetPod<(), Icon>,
+    style: IconButtonStyle,
+    #[allow(clippy::type_complexity)]
+    action: Option<Box<dyn Fn(&mut EventCtx, &mut T, &Env)>>,
+    /// The smallest area that responds to the pointer, which may be bigger than the container.
+    tap_target: Size,
+    /// Where the container is drawn, from the last layout.
+    container: Rect,
+    hotkey: Option<HotKey>,
+    /// Text describing the hotkey, drawn over the corner of the container.
+    hint: Option<TextLayout<ArcStr>>,
+}
+
+impl<T: Data> IconButton<T> {
+    /// Create a [standard](IconButtonStyle::Standard) button showing `paths`.
+    pub fn new(paths: IconPaths) -> Self {
+        IconButton {
+            // The color is set from the style when painting.
+            icon: WidgetPod::new(Icon::new(paths, Color::BLACK)),
+            style: IconButtonStyle::Standard,
+            action: None,
+            tap_target: Size::ZERO,
+            container: Rect::ZERO,
+            hotkey: None,
+            hint: None,
+        }
+    }
+
+    /// Builder-style method to set the button's style.
+    #[inline]
+    pub fn with_style(mut self, style: IconButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the button's style.
+    ///
+    /// The caller is responsible for requesting paint.
+    #[inline]
+    pub fn set_style(&mut self, style: IconButtonStyle) {
+        self.style = style;
+    }
+
+    /// Builder-style method to make the button respond to the pointer over at least `size`,
+    /// without making the container or icon any bigger.
+    ///
+    /// Material recommends touch targets of at least [`MIN_TOUCH_TARGET`], which is bigger than
+    /// the 40dp container. The button takes up the extra space in the layout, with the container
+    /// centered in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::Widget;
+    /// use druid_material_icons::{IconButton, CLOSE, MIN_TOUCH_TARGET};
+    /// fn close_button() -> impl Widget<()> {
+    ///     IconButton::new(CLOSE).min_tap_target(MIN_TOUCH_TARGET)
+    /// }
+    /// ```
+    #[inline]
+    pub fn min_tap_target(mut self, size: Size) -> Self {
+        self.tap_target = size;
+        self
+    }
+
+    /// Set the smallest area the button responds to the pointer over.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_min_tap_target(&mut self, size: Size) {
+        self.tap_target = size;
+    }
+
+    /// Builder-style method to run `action` when the button is clicked.
+    pub fn on_click(mut self, action: impl Fn(&mut EventCtx, &mut T, &Env) + 'static) -> Self {
+        self.action = Some(Box::new(action));
+        self
+    }
+
+    /// Builder-style method to also run the click action when `hotkey` is pressed.
+    ///
+    /// The button sees key presses while it or a child has focus, and any sent with
+    /// [`HOTKEY_PRESSED`] while it doesn't, so forwarding every key press fires it once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::Widget;
+    /// use druid::{HotKey, SysMods};
+    /// use druid_material_icons::{IconButton, SAVE};
+    /// fn save_button() -> impl Widget<u32> {
+    ///     IconButton::new(SAVE)
+    ///         .on_click(|_ctx, saves: &mut u32, _env| *saves += 1)
+    ///         .with_hotkey(HotKey::new(SysMods::Cmd, "s"))
+    ///         .with_hotkey_hint("Ctrl+S")
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_hotkey(mut self, hotkey: HotKey) -> Self {
+        self.hotkey = Some(hotkey);
+        self
+    }
+
+    /// Builder-style method to show `text`, e.g. the keys of the
+    /// [hotkey](IconButton::with_hotkey), in small type over the bottom right corner of the
+    /// button.
+    pub fn with_hotkey_hint(mut self, text: impl Into<ArcStr>) -> Self {
+        let mut hint = TextLayout::from_text(text);
+        hint.set_text_size(HINT_TEXT_SIZE);
+        self.hint = Some(hint);
+        self
+    }
+
+    /// The inner icon, for configuring its appearance.
+    #[inline]
+    pub fn icon_mut(&mut self) -> &mut Icon {
+        self.icon.widget_mut()
+    }
+
+    /// Whether `key` is a fresh press of our hotkey.
+    fn is_hotkey(&self, key: &KeyEvent) -> bool {
+        !key.repeat
+            && self
+                .hotkey
+                .as_ref()
+                .is_some_and(|hotkey| hotkey.matches(key))
+    }
+
+    /// Whether `key` is a fresh press of Space or Enter, which click a focused button.
+    fn is_activation(key: &KeyEvent) -> bool {
+        !key.repeat
+            && match &key.key {
+                KbKey::Enter => true,
+                KbKey::Character(c) => c == " ",
+                _ => false,
+            }
+    }
+
+    /// Draw the hotkey hint, if we have one, over the bottom right corner of the container.
+    fn paint_hint(&self, ctx: &mut PaintCtx) {
+        if let Some(hint) = &self.hint {
+            let size = hint.size();
+            let origin = Point::new(
+                self.container.x1 - size.width,
+                self.container.y1 - size.height,
+            );
+            hint.draw(ctx, origin);
+        }
+    }
+
+    /// The colors of the container and icon, before any state layer is applied.
+    fn colors(&self, env: &Env) -> (Option<Color>, Color) {
+        let defaults = &theme::MATERIAL3_SCHEME;
+        match self.style {
+            IconButtonStyle::Standard | IconButtonStyle::Outlined => (
+                None,
+                theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant),
+            ),
+            IconButtonStyle::FilledTonal => (
+                Some(theme::color_or(
+                    env,
+                    &theme::SECONDARY_CONTAINER,
+                    defaults.secondary_container,
+                )),
+                theme::color_or(
+                    env,
+                    &theme::ON_SECONDARY_CONTAINER,
+                    defaults.on_secondary_container,
+                ),
+            ),
+            IconButtonStyle::Filled => (
+                Some(theme::color_or(env, &theme::PRIMARY, defaults.primary)),
+                theme::color_or(env, &theme::ON_PRIMARY, defaults.on_primary),
+            ),
+        }
+    }
+}
+
+impl<T: Data> Widget<T> for IconButton<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(_) if !ctx.is_disabled() => {
+                ctx.set_active(true);
+                ctx.request_paint();
+            }
+            Event::MouseUp(_) if ctx.is_active() => {
+                ctx.set_active(false);
+                ctx.request_paint();
+                if ctx.is_hot() && !ctx.is_disabled() {
+                    if let Some(action) = &self.action {
+                        action(ctx, data, env);
+                    }
+                }
+            }
+            Event::KeyDown(key)
+                if (self.is_hotkey(key) || ctx.is_focused() && Self::is_activation(key))
+                    && !ctx.is_disabled() =>
+            {
+                if let Some(action) = &self.action {
+                    action(ctx, data, env);
+                }
+                ctx.set_handled();
+            }
+            // Not handled, so that every button with this hotkey sees it. While the focus is on
+            // the button or a child, the key press itself has already reached us.
+            Event::Command(cmd) if !ctx.is_disabled() && !ctx.has_focus() => {
+                if let Some(key) = cmd.get(HOTKEY_PRESSED) {
+                    if let (true, Some(action)) = (self.is_hotkey(key), &self.action) {
+                        action(ctx, data, env);
+                    }
+                }
+            }
+            _ => (),
+        }
+        self.icon.event(ctx, event, &mut (), env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, env: &Env) {
+        match event {
+            LifeCycle::WidgetAdded => ctx.register_for_focus(),
+            LifeCycle::HotChanged(_)
+            | LifeCycle::FocusChanged(_)
+            | LifeCycle::DisabledChanged(_) => ctx.request_paint(),
+            _ => (),
+        }
+        self.icon.lifecycle(ctx, event, &(), env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, env: &Env) {
+        if ctx.env_changed() {
+            ctx.request_paint();
+        }
+        if let Some(hint) = &mut self.hint {
+            if hint.needs_rebuild_after_update(ctx) || ctx.env_changed() {
+                ctx.request_layout();
+            }
+        }
+        self.icon.update(ctx, &(), env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        let container = bc.constrain(Size::new(CONTAINER_SIZE, CONTAINER_SIZE));
+        // Grow to the tap target, but keep the container its usual size in the middle.
+        let size = bc.constrain(Size::new(
+            container.width.max(self.tap_target.width),
+            container.height.max(self.tap_target.height),
+        ));
+        let center = size.to_rect().center();
+        self.container = Rect::from_center_size(center, container);
+
+        let icon_size = ICON_SIZE.min(container.width).min(container.height);
+        let icon_bc = BoxConstraints::new(Size::ZERO, Size::new(icon_size, icon_size));
+        let icon_size = self.icon.layout(ctx, &icon_bc, &(), env);
+        let origin = Point::new(
+            center.x - 0.5 * icon_size.width,
+            center.y - 0.5 * icon_size.height,
+        );
+        self.icon.set_origin(ctx, origin);
+
+        if let Some(hint) = &mut self.hint {
+            let defaults = &theme::MATERIAL3_SCHEME;
+            let color =
+                theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant);
+            hint.set_text_color(color);
+            hint.rebuild_if_needed(ctx.text(), env);
+        }
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
+        let container = self.container;
+        let circle = Circle::new(
+            container.center(),
+            0.5 * container.width().min(container.height()),
+        );
+        let (container, content) = self.colors(env);
+
+        if ctx.is_disabled() {
+            let defaults = &theme::MATERIAL3_SCHEME;
+            let on_surface = theme::color_or(env, &theme::ON_SURFACE, defaults.on_surface);
+            if container.is_some() {
+                ctx.fill(circle, &on_surface.with_alpha(DISABLED_CONTAINER_OPACITY));
+            }
+            if self.style == IconButtonStyle::Outlined {
+                ctx.stroke(
+                    Circle::new(circle.center, circle.radius - 0.5 * OUTLINE_WIDTH),
+                    &on_surface.with_alpha(DISABLED_CONTAINER_OPACITY),
+                    OUTLINE_WIDTH,
+                );
+            }
+            self.icon
+                .widget_mut()
+                .set_color(on_surface.with_alpha(DISABLED_CONTENT_OPACITY));
+            self.icon.paint(ctx, &(), env);
+            return self.paint_hint(ctx);
+        }
+
+        if let Some(container) = container {
+            ctx.fill(circle, &container);
+        }
+        if self.style == IconButtonStyle::Outlined {
+            let defaults = &theme::MATERIAL3_SCHEME;
+            let outline = theme::color_or(env, &theme::OUTLINE, defaults.outline);
+            ctx.stroke(
+                Circle::new(circle.center, circle.radius - 0.5 * OUTLINE_WIDTH),
+                &outline,
+                OUTLINE_WIDTH,
+            );
+        }
+        state_layer::paint(ctx, circle, content, env);
+        self.icon.widget_mut().set_color(content);
+        self.icon.paint(ctx, &(), env);
+        self.paint_hint(ctx);
+    }
+}
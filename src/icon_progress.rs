@@ -0,0 +1,205 @@
+use std::{f64::consts::PI, time::Duration};
+
+use druid::{
+    kurbo::{Arc, Circle, Size, Vec2},
+    piet::{LineCap, StrokeStyle},
+    widget::prelude::*,
+    Color, Point, WidgetPod,
+};
+
+use crate::{
+    icon::{ease, progress},
+    theme, Icon, IconPaths,
+};
+
+/// The size of the ring, from the Material 3 circular progress indicator.
+const RING_SIZE: f64 = 40.;
+/// The size of the icon inside the ring.
+const ICON_SIZE: f64 = 24.;
+/// The width of the ring.
+const RING_WIDTH: f64 = 4.;
+/// How long the ring takes to catch up when the progress changes.
+const CATCH_UP: Duration = Duration::from_millis(250);
+/// How long an indeterminate ring takes to spin once.
+const SPIN_PERIOD: Duration = Duration::from_millis(1400);
+/// How long an indeterminate ring takes to grow and shrink once.
+const BREATHE_PERIOD: Duration = Duration::from_millis(2800);
+/// The shortest and longest arcs of an indeterminate ring, in turns.
+const MIN_ARC: f64 = 0.05;
+const MAX_ARC: f64 = 0.75;
+
+/// An icon inside a circular progress ring, like a download button that fills as the download
+/// goes.
+///
+/// The data is the progress from 0 to 1, and the ring animates smoothly towards it. An
+/// [`indeterminate`](IconProgress::indeterminate) ring ignores the data and spins instead.
+/// The ring is drawn in [`theme::PRIMARY`] on a track of [`theme::SECONDARY_CONTAINER`], and
+/// the icon in [`theme::ON_SURFACE_VARIANT`].
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{Widget, WidgetExt};
+/// use druid_material_icons::{IconProgress, DOWNLOAD};
+/// fn download_status() -> impl Widget<f64> {
+///     IconProgress::new(DOWNLOAD)
+/// }
+/// ```
+pub struct IconProgress {
+    icon: WidgetPod<(), Icon>,
+    indeterminate: bool,
+    /// The progress the ring is showing, which lags behind the data while it catches up.
+    shown: f64,
+    /// An animation of `shown` towards the data, from the given progress.
+    catch_up: Option<(f64, Duration)>,
+    /// How long an indeterminate ring has been spinning, for working out where it is.
+    spinning: Duration,
+}
+
+impl IconProgress {
+    /// A determinate ring around `paths`.
+    pub fn new(paths: IconPaths) -> Self {
+        IconProgress {
+            // The color is set from the theme when painting.
+            icon: WidgetPod::new(Icon::new(paths, Color::BLACK)),
+            indeterminate: false,
+            shown: 0.,
+            catch_up: None,
+            spinning: Duration::ZERO,
+        }
+    }
+
+    /// An indeterminate ring around `paths`, for when there's no telling how long it will take.
+    pub fn indeterminate(paths: IconPaths) -> Self {
+        let mut this = IconProgress::new(paths);
+        this.indeterminate = true;
+        this
+    }
+
+    /// Set whether the ring is indeterminate.
+    ///
+    /// The caller is responsible for requesting an animation frame.
+    #[inline]
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        self.indeterminate = indeterminate;
+    }
+
+    /// The inner icon, for configuring its appearance.
+    #[inline]
+    pub fn icon_mut(&mut self) -> &mut Icon {
+        self.icon.widget_mut()
+    }
+
+    /// The start angle and sweep of the arc to draw, in radians clockwise from 3 o'clock.
+    fn arc(&self, data: f64) -> (f64, f64) {
+        // Start at 12 o'clock.
+        let top = -0.5 * PI;
+        if !self.indeterminate {
+            let shown = match self.catch_up {
+                Some(_) => self.shown,
+                None => data.clamp(0., 1.),
+            };
+            return (top, 2. * PI * shown);
+        }
+        let turns = |period: Duration| self.spinning.as_secs_f64() / period.as_secs_f64();
+        // The arc grows and shrinks while the whole thing spins.
+        let breath = 0.5 - 0.5 * (2. * PI * turns(BREATHE_PERIOD)).cos();
+        let sweep = MIN_ARC + (MAX_ARC - MIN_ARC) * breath;
+        (top + 2. * PI * turns(SPIN_PERIOD), 2. * PI * sweep)
+    }
+}
+
+impl Widget<f64> for IconProgress {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut f64, env: &Env) {
+        if let Event::AnimFrame(interval) = event {
+            let interval = Duration::from_nanos(*interval);
+            if self.indeterminate {
+                self.spinning += interval;
+                ctx.request_anim_frame();
+                ctx.request_paint();
+            } else if let Some((from, elapsed)) = &mut self.catch_up {
+                *elapsed += interval;
+                let t = progress(*elapsed, CATCH_UP);
+                self.shown = *from + (data.clamp(0., 1.) - *from) * ease(t);
+                if t < 1. {
+                    ctx.request_anim_frame();
+                } else {
+                    self.catch_up = None;
+                }
+                ctx.request_paint();
+            }
+        }
+        self.icon.event(ctx, event, &mut (), env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &f64, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.shown = data.clamp(0., 1.);
+            if self.indeterminate {
+                ctx.request_anim_frame();
+            }
+        }
+        self.icon.lifecycle(ctx, event, &(), env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &f64, data: &f64, env: &Env) {
+        if self.indeterminate {
+            // In case we've just been made indeterminate.
+            ctx.request_anim_frame();
+        } else if old_data != data {
+            // Catch up from wherever the ring is now, even part way through catching up.
+            self.catch_up = Some((self.shown, Duration::ZERO));
+            ctx.request_anim_frame();
+        }
+        if ctx.env_changed() {
+            ctx.request_paint();
+        }
+        self.icon.update(ctx, &(), env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &f64, env: &Env) -> Size {
+        let size = bc.constrain(Size::new(RING_SIZE, RING_SIZE));
+        let icon_size = ICON_SIZE.min(size.width).min(size.height);
+        let icon_bc = BoxConstraints::new(Size::ZERO, Size::new(icon_size, icon_size));
+        let icon_size = self.icon.layout(ctx, &icon_bc, &(), env);
+        let origin = Point::new(
+            0.5 * (size.width - icon_size.width),
+            0.5 * (size.height - icon_size.height),
+        );
+        self.icon.set_origin(ctx, origin);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &f64, env: &Env) {
+        let defaults = &theme::MATERIAL3_SCHEME;
+        let size = ctx.size();
+        // Keep the stroke inside our bounds.
+        let radius = 0.5 * (size.width.min(size.height) - RING_WIDTH);
+        let center = size.to_rect().center();
+
+        let track = theme::color_or(
+            env,
+            &theme::SECONDARY_CONTAINER,
+            defaults.secondary_container,
+        );
+        ctx.stroke(Circle::new(center, radius), &track, RING_WIDTH);
+
+        let (start_angle, sweep_angle) = self.arc(*data);
+        if sweep_angle > 0. {
+            let arc = Arc {
+                center,
+                radii: Vec2::new(radius, radius),
+                start_angle,
+                sweep_angle,
+                x_rotation: 0.,
+            };
+            let indicator = theme::color_or(env, &theme::PRIMARY, defaults.primary);
+            let style = StrokeStyle::new().line_cap(LineCap::Round);
+            ctx.stroke_styled(arc, &indicator, RING_WIDTH, &style);
+        }
+
+        let content = theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant);
+        self.icon.widget_mut().set_color(content);
+        self.icon.paint(ctx, &(), env);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{cell::RefCell, rc::Rc};
+
+use druid::{kurbo::Size, widget::prelude::*, Data, Menu, MenuItem, Point, WidgetPod};
+
+use crate::{IconButton, IconPaths, MenuIcon};
+
+/// The default space between buttons.
+const DEFAULT_SPACING: f64 = 4.;
+
+/// A toolbar action, shared between its button and the overflow menu.
+type Action<T> = Rc<dyn Fn(&mut T, &Env)>;
+
+struct Item<T> {
+    button: WidgetPod<T, IconButton<T>>,
+    label: String,
+    action: Action<T>,
+}
+
+/// The labels and actions of the items that didn't fit, shared with the overflow menu.
+type Overflow<T> = Rc<RefCell<Vec<(String, Action<T>)>>>;
+
+/// A row of [`IconButton`]s, for the actions of an app bar or panel.
+///
+/// Buttons that don't fit are collapsed, from the end, into a "more" menu opened from a
+/// [`MenuIcon`], where they are listed by label.
+///
+/// Because of how druid shows context menus, the overflow menu only works if `T` is the app's
+/// root data type.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{Data, Widget};
+/// use druid_material_icons::{IconToolbar, DELETE, EDIT, SHARE};
+/// #[derive(Clone, Data)]
+/// struct Document {
+///     edits: u32,
+///     deleted: bool,
+/// }
+///
+/// fn document_actions() -> impl Widget<Document> {
+///     IconToolbar::new()
+///         .with_action(EDIT, "Edit", |doc: &mut Document, _env| doc.edits += 1)
+///         .with_action(SHARE, "Share", |_doc, _env| {})
+///         .with_action(DELETE, "Delete", |doc, _env| doc.deleted = true)
+/// }
+/// ```
+pub struct IconToolbar<T> {
+    items: Vec<Item<T>>,
+    overflow_button: WidgetPod<T, MenuIcon<T>>,
+    overflow: Overflow<T>,
+    spacing: f64,
+    /// How many items fit in the toolbar after the last layout.
+    visible: usize,
+}
+
+impl<T: Data> IconToolbar<T> {
+    /// Create an empty toolbar.
+    pub fn new() -> Self {
+        let overflow = Overflow::default();
+        let menu_items = overflow.clone();
+        IconToolbar {
+            items: Vec::new(),
+            overflow_button: WidgetPod::new(MenuIcon::new(move |_data, _env| {
+                overflow_menu(&menu_items.borrow())
+            })),
+            overflow,
+            spacing: DEFAULT_SPACING,
+            visible: 0,
+        }
+    }
+
+    /// Builder-style method to add a button showing `icon`, which runs `action` when clicked.
+    ///
+    /// `label` is shown in the overflow menu if the button doesn't fit.
+    pub fn with_action(
+        mut self,
+        icon: IconPaths,
+        label: impl Into<String>,
+        action: impl Fn(&mut T, &Env) + 'static,
+    ) -> Self {
+        self.add_action(icon, label, action);
+        self
+    }
+
+    /// Add a button showing `icon`, which runs `action` when clicked.
+    ///
+    /// The caller is responsible for requesting layout.
+    pub fn add_action(
+        &mut self,
+        icon: IconPaths,
+        label: impl Into<String>,
+        action: impl Fn(&mut T, &Env) + 'static,
+    ) {
+        let action: Action<T> = Rc::new(action);
+        let on_click = action.clone();
+        self.items.push(Item {
+            button: WidgetPod::new(
+                IconButton::new(icon).on_click(move |_ctx, data, env| on_click(data, env)),
+            ),
+            label: label.into(),
+            action,
+        });
+    }
+
+    /// Builder-style method to set the space between buttons.
+    #[inline]
+    pub fn with_spacing(mut self, spacing: f64) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the space between buttons.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_spacing(&mut self, spacing: f64) {
+        self.spacing = spacing;
+    }
+
+    /// Whether some items are collapsed into the overflow menu.
+    fn overflowing(&self) -> bool {
+        self.visible < self.items.len()
+    }
+}
+
+/// The menu listing the items that didn't fit.
+fn overflow_menu<T: Data>(items: &[(String, Action<T>)]) -> Menu<T> {
+    items.iter().fold(Menu::empty(), |menu, (label, action)| {
+        let action = action.clone();
+        menu.entry(
+            MenuItem::new(label.clone()).on_activate(move |_ctx, data, env| action(data, env)),
+        )
+    })
+}
+
+impl<T: Data> Default for IconToolbar<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Data> Widget<T> for IconToolbar<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        // Collapsed buttons are laid out with no size, so they never get pointer events.
+        for item in &mut self.items {
+            item.button.event(ctx, event, data, env);
+        }
+        if self.overflowing() {
+            self.overflow_button.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        for item in &mut self.items {
+            item.button.lifecycle(ctx, event, data, env);
+        }
+        self.overflow_button.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        for item in &mut self.items {
+            item.button.update(ctx, data, env);
+        }
+        self.overflow_button.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let button_bc = bc.loosen();
+        let button_size = self.overflow_button.layout(ctx, &button_bc, data, env);
+        let stride = button_size.width + self.spacing;
+        let count = self.items.len();
+        let fits = |n: usize| n as f64 * stride - self.spacing <= bc.max().width;
+        self.visible = if fits(count) {
+            count
+        } else {
+            // Leave room for the overflow button.
+            (0..count).rev().find(|&n| fits(n + 1)).unwrap_or(0)
+        };
+        *self.overflow.borrow_mut() = self.items[self.visible..]
+            .iter()
+            .map(|item| (item.label.clone(), item.action.clone()))
+            .collect();
+
+        let mut x = 0.;
+        for (idx, item) in self.items.iter_mut().enumerate() {
+            if idx < self.visible {
+                item.button.layout(ctx, &button_bc, data, env);
+                item.button.set_origin(ctx, Point::new(x, 0.));
+                x += stride;
+            } else {
+                item.button
+                    .layout(ctx, &BoxConstraints::tight(Size::ZERO), data, env);
+                item.button.set_origin(ctx, Point::ZERO);
+            }
+        }
+        if self.overflowing() {
+            self.overflow_button.set_origin(ctx, Point::new(x, 0.));
+            x += stride;
+        } else {
+            self.overflow_button.set_origin(ctx, Point::ZERO);
+        }
+        bc.constrain(Size::new((x - self.spacing).max(0.), button_size.height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        for item in &mut self.items[..self.visible] {
+            item.button.paint(ctx, data, env);
+        }
+        if self.overflowing() {
+            self.overflow_button.paint(ctx, data, env);
+        }
+    }
+}
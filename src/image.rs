@@ -0,0 +1,28 @@
+//! Rendering icons to images on the CPU, for uses outside of a window's widget tree.
+
+use druid::{
+    piet::{Device, ImageFormat, Piet},
+    ImageBuf, RenderContext,
+};
+
+use crate::Error;
+
+/// Render an image of `width` by `height` pixels, painted by `paint`.
+pub(crate) fn render(
+    width: usize,
+    height: usize,
+    paint: impl FnOnce(&mut Piet),
+) -> Result<ImageBuf, Error> {
+    let mut device = Device::new().map_err(Error::render)?;
+    let mut target = device
+        .bitmap_target(width, height, 1.)
+        .map_err(Error::render)?;
+    {
+        let mut rc = target.render_context();
+        paint(&mut rc);
+        rc.finish().map_err(Error::render)?;
+    }
+    target
+        .to_image_buf(ImageFormat::RgbaPremul)
+        .map_err(Error::render)
+}
@@ -1,116 +1,788 @@
 //! This library includes icons from Google's [material design icons
 //! repository](https://github.com/google/material-design-icons).
+//!
+//! Icons live in a module per category (e.g. `normal::content::ADD`), and are also re-exported
+//! at the crate root (e.g. `ADD`). If two categories contain an icon with the same name, the
+//! root re-export from the alphabetically later category is suffixed with its category name.
+//!
+//! The `image` and `maps` categories are much bigger than the rest. Every category is built by
+//! default; apps that don't use those two can keep compile times down by turning off default
+//! features and enabling just `druid` (`--no-default-features --features druid`), adding either
+//! back with `category-image` or `category-maps`. [`DEFAULT_ICON`] and the [`prelude`] icons are
+//! always built.
+//! [`Category::code_size`] shows how big each category is.
+//!
+//! To compile only the icons an app uses, set the `DRUID_MATERIAL_ICONS` environment variable to
+//! a comma separated list of their upstream names when building, e.g.
+//! `DRUID_MATERIAL_ICONS="add,delete,search"`. The icons the crate's own widgets and [`prelude`]
+//! use are always kept, and naming an icon that doesn't exist fails the build. Running
+//! `generate-icons audit <binary>` from this repository reports which icons a compiled binary
+//! kept, to show whether it's worth it.
+//!
+//! When upstream renames an icon, its old name stays available at the crate root as a deprecated
+//! constant.
+//!
+//! Other crates can publish icon packs that work with the widgets here by defining their icons
+//! with [`IconPaths::from_static`] and [`icon_path!`]. The `brands` feature adds a small pack of
+//! logos, generated with `generate-icons pack` from SVGs, and the `symbols_extra` feature a
+//! community-maintained pack of weather, currency and arrow symbols missing from Material. Each
+//! pack credits its icons' authors and license in its `ATTRIBUTION`.
+//!
+//! The [`prelude`] re-exports the most commonly used types and icons.
 
+// `Color` is only `Copy` from druid 0.8, so it's cloned for the `druid-0_7` build.
+#![allow(clippy::clone_on_copy)]
+
+use std::{fmt, str::FromStr};
+
+// Everything refers to druid by its usual name, whichever version it is.
+#[cfg(all(feature = "druid-0_7", not(feature = "druid")))]
+extern crate druid_0_7 as druid;
+
+#[cfg(feature = "druid")]
+mod app_icon;
+#[cfg(feature = "druid")]
+mod batch;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+mod blend;
+#[cfg(feature = "boolean")]
+mod boolean;
+#[cfg(feature = "brands")]
+pub mod brands;
+#[cfg(feature = "druid")]
+mod breadcrumb;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+mod compat;
+#[cfg(feature = "druid")]
+mod dyn_icon;
+#[cfg(feature = "embedded-graphics")]
+pub mod embedded_graphics;
+mod error;
+// The examples use icons that an allowlist may leave out.
+#[cfg(all(feature = "druid", not(icon_allowlist)))]
+pub mod examples;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+mod filter;
+#[cfg(feature = "geometry-cache")]
+mod geometry_cache;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+pub mod icon;
+#[cfg(feature = "druid")]
+mod icon_button;
+#[cfg(feature = "druid")]
+mod icon_progress;
+#[cfg(feature = "druid")]
+mod icon_toolbar;
+#[cfg(feature = "druid")]
+mod image;
+mod macros;
+#[cfg(feature = "druid")]
+mod maybe_icon;
+#[cfg(feature = "druid")]
+mod menu_icon;
+#[cfg(feature = "offset")]
+mod offset;
+mod owned;
+pub mod palette;
+mod pdf;
+#[cfg(feature = "plotters")]
+pub mod plotters;
+pub mod prelude;
+#[cfg(feature = "raqote")]
+pub mod raqote;
+#[cfg(feature = "raw")]
+pub mod raw;
+#[cfg(feature = "sdf")]
+mod sdf;
+#[cfg(feature = "druid")]
+mod search_field;
+#[cfg(feature = "druid")]
+mod state_layer;
 #[cfg(feature = "druid")]
+mod stepper;
+#[cfg(feature = "symbols_extra")]
+pub mod symbols_extra;
+#[cfg(feature = "druid")]
+mod tab_item;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+pub mod theme;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "xilem")]
+pub mod xilem;
+
+#[cfg(feature = "druid")]
+pub use app_icon::AppIcon;
+#[cfg(feature = "druid")]
+pub use batch::paint_icons;
+#[cfg(feature = "druid")]
+pub use breadcrumb::BreadcrumbSeparator;
+#[cfg(feature = "druid")]
+pub use dyn_icon::DynIcon;
+pub use error::Error;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+pub use filter::ColorFilter;
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+pub use icon::Icon;
+#[cfg(feature = "druid")]
+pub use icon_button::{IconButton, IconButtonStyle, HOTKEY_PRESSED, MIN_TOUCH_TARGET};
+#[cfg(feature = "druid")]
+pub use icon_progress::IconProgress;
+#[cfg(feature = "druid")]
+pub use icon_toolbar::IconToolbar;
+#[cfg(feature = "druid")]
+pub use maybe_icon::{MaybeIcon, Placeholder};
+#[cfg(feature = "druid")]
+pub use menu_icon::MenuIcon;
+pub use owned::{OwnedIconPath, OwnedIconPaths};
+#[cfg(feature = "druid")]
+pub use search_field::SearchField;
+#[cfg(feature = "druid")]
+pub use stepper::{Stepper, StepperValue};
+#[cfg(feature = "druid")]
+pub use tab_item::TabItem;
+
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
 use druid::{
-    kurbo::{Affine, PathEl, Point, Rect, Shape, Size},
-    widget::prelude::*,
+    kurbo::{Affine, BezPath, PathEl, Point, Rect, Shape, Size},
     Color, Data,
 };
-#[cfg(not(feature = "druid"))]
-use kurbo::{PathEl, Point, Rect, Shape, Size};
+#[cfg(feature = "druid")]
+use druid::{widget::Painter, Cursor, CursorDesc, Env, ImageBuf, RenderContext, WindowHandle};
+#[cfg(not(any(feature = "druid", feature = "druid-0_7")))]
+use kurbo::{Affine, BezPath, PathEl, Point, Rect, Shape, Size};
+// For `icon_path!`, so that it builds elements of whichever kurbo the crate uses.
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+#[doc(hidden)]
+pub use druid::kurbo as __kurbo;
+#[cfg(not(any(feature = "druid", feature = "druid-0_7")))]
+#[doc(hidden)]
+pub use kurbo as __kurbo;
 
-/// A widget that displays a material icon. Use constraints to set the preferred size.
+/// Factories for creating material icons for druid.
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "druid")]
+/// # mod example {
 /// # use druid::{Widget, Data, Color, WidgetExt};
 /// # #[derive(Data, Clone)]
 /// # struct MyData;
 /// use druid_material_icons as icons;
 /// fn build_ui() -> impl Widget<MyData> {
-///     icons::normal::content::ADD.new(Color::BLACK).fix_width(12.0).center()
+///     icons::ADD.new(Color::BLACK).fix_width(12.0).center()
 /// }
+/// # }
 /// ```
-#[derive(Debug, Clone)]
-#[cfg(feature = "druid")]
-pub struct Icon {
-    paths: IconPaths,
-    color: Color,
+///
+/// `IconPaths` and [`IconPath`] are `Copy`, `Send` and `Sync`, which is checked when the crate
+/// is compiled, so they can be shared freely between render threads and kept in statics. This
+/// won't change without a breaking release.
+#[derive(Copy, Clone)]
+pub struct IconPaths {
+    /// The upstream name of the icon, e.g. `"add_circle"`.
+    pub name: &'static str,
+    /// The category the icon belongs to, e.g. `"content"`.
+    pub category: &'static str,
+    /// The style variant of the icon, e.g. `"normal"`.
+    pub variant: &'static str,
+    pub paths: &'static [IconPath],
+    pub size: Size,
+    /// A rough measure of how expensive the icon is to paint, computed when the crate is
+    /// generated.
+    ///
+    /// Each path counts one, plus one for every point in it. Use this to decide whether to
+    /// cache a rasterized copy, or to skip costly effects on heavy glyphs.
+    pub complexity: u32,
 }
 
-#[cfg(feature = "druid")]
-impl Icon {
-    #[inline]
-    pub fn new(paths: IconPaths, color: Color) -> Self {
-        Self { paths, color }
-    }
-}
-
-#[cfg(feature = "druid")]
-impl<T: Data> Widget<T> for Icon {
-    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut T, _env: &Env) {
-        // no events
-    }
-    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {
-        // no lifecycle
-    }
-    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {
-        // no update
-    }
-    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
-        let Size { width, height } = self.paths.size;
-        bc.constrain_aspect_ratio(height / width, width)
-    }
-    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, _env: &Env) {
-        let Size { width, height } = ctx.size();
-        let Size {
-            width: icon_width,
-            height: icon_height,
-        } = self.paths.size;
-        ctx.transform(Affine::scale_non_uniform(
-            width * icon_width.recip(),
-            height * icon_height.recip(),
-        ));
-        // TODO This makes slightly more brushes than it needs to. Probably not an issue.
-        for shape in self.paths.paths {
-            let color = self.color.clone();
-            let (_, _, _, alpha) = color.as_rgba();
-            let color = color.with_alpha(alpha * shape.opacity);
-            let brush = ctx.solid_brush(color);
-            ctx.fill(shape, &brush);
-        }
+impl fmt::Debug for IconPaths {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The path data is far too long to be useful in logs.
+        f.debug_struct("IconPaths")
+            .field("name", &self.name)
+            .field("category", &self.category)
+            .field("variant", &self.variant)
+            .field("size", &self.size)
+            .field("complexity", &self.complexity)
+            .finish_non_exhaustive()
     }
 }
 
-/// Factories for creating material icons for druid.
+/// A cheap identity for an icon, for keying maps and diffing lists of icons without comparing
+/// their path data. Get one from [`IconPaths::id`].
+///
+/// Icons are the same if they share path data, so two custom icons with the same name but
+/// different paths have different ids. Ids order by variant, category and then name, for stable
+/// listings. The compiler usually keeps one copy of each icon's path data, but isn't required to,
+/// so an icon used from more than one crate may have more than one id; [`IconName`] is a
+/// smaller alternative that doesn't have that problem, for the crate's own `normal` icons.
 ///
 /// # Examples
 ///
 /// ```
-/// # use druid::{Widget, Data, Color, WidgetExt};
-/// # #[derive(Data, Clone)]
-/// # struct MyData;
-/// use druid_material_icons as icons;
-/// fn build_ui() -> impl Widget<MyData> {
-///     icons::normal::content::ADD.new(Color::BLACK).fix_width(12.0).center()
+/// use druid_material_icons::{IconPath, IconPaths, ADD, DELETE, REMOVE};
+/// use kurbo::Size;
+/// use std::collections::HashMap;
+///
+/// let mut uses = HashMap::new();
+/// for icon in [ADD, DELETE, ADD] {
+///     *uses.entry(icon.id()).or_insert(0) += 1;
 /// }
+/// assert_eq!(uses[&ADD.id()], 2);
+/// // `delete` is in the `action` category, which comes before `add`'s `content`.
+/// assert!(DELETE.id() < ADD.id());
+///
+/// // The name alone doesn't make two icons the same.
+/// let plus = IconPaths::custom("sign", ADD.paths, Size::new(24., 24.));
+/// let minus = IconPaths::custom("sign", REMOVE.paths, Size::new(24., 24.));
+/// assert_ne!(plus.id(), minus.id());
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct IconPaths {
-    pub paths: &'static [IconPath],
-    pub size: Size,
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IconId {
+    // The names come first for the ordering, and the path data tells icons apart.
+    variant: &'static str,
+    category: &'static str,
+    name: &'static str,
+    /// The address and length of the icon's path data.
+    paths: (usize, usize),
 }
 
-#[cfg(feature = "druid")]
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
 impl IconPaths {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(self, color: Color) -> Icon {
         Icon::new(self, color)
     }
 }
 
+#[cfg(feature = "druid")]
+impl IconPaths {
+    /// A painter that draws the icon in `color`, as large as fits and centered, so it can be used
+    /// as the background or foreground of another widget.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{widget::Label, Color, Widget, WidgetExt};
+    /// use druid_material_icons::LOCK;
+    /// fn private_panel() -> impl Widget<()> {
+    ///     Label::new("Only you can see this")
+    ///         .padding(20.)
+    ///         .background(LOCK.painter(Color::grey(0.9)))
+    /// }
+    /// ```
+    pub fn painter<T>(self, color: Color) -> Painter<T> {
+        let icon = Icon::new(self, color);
+        Painter::new(move |ctx, _, env| {
+            let size = ctx.size();
+            let scale = (size.width / self.size.width).min(size.height / self.size.height);
+            let fitted = self.size * scale;
+            let offset = (size.to_vec2() - fitted.to_vec2()) * 0.5;
+            ctx.with_save(|ctx| {
+                ctx.transform(Affine::translate(offset));
+                icon.paint_into(ctx.render_ctx, fitted, env);
+            });
+        })
+    }
+
+    /// Render the icon in `color`, stretched to `width` by `height` pixels.
+    ///
+    /// This uses the platform's piet backend, so it can fail if the backend isn't available.
+    pub fn to_image(&self, width: usize, height: usize, color: Color) -> Result<ImageBuf, Error> {
+        let icon = Icon::new(*self, color);
+        let size = Size::new(width as f64, height as f64);
+        image::render(width, height, |rc| icon.paint_into(rc, size, &Env::empty()))
+    }
+
+    /// A mouse cursor showing the icon, `size` pixels square, with its hot spot at `hot_spot` in
+    /// pixels from the top left.
+    ///
+    /// The icon is drawn in black with a white outline, so it shows up on any background. Returns
+    /// `None` if the platform doesn't support custom cursors or the icon can't be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use druid::{EventCtx, Point};
+    /// use druid_material_icons::PAN_TOOL;
+    /// fn start_panning(ctx: &mut EventCtx) {
+    ///     if let Some(cursor) = PAN_TOOL.to_cursor(ctx.window(), 32, Point::new(16., 16.)) {
+    ///         ctx.set_cursor(&cursor);
+    ///     }
+    /// }
+    /// ```
+    pub fn to_cursor(
+        &self,
+        window: &WindowHandle,
+        size: usize,
+        hot_spot: impl Into<Point>,
+    ) -> Option<Cursor> {
+        // Leave room for the outline, which is drawn outside the icon.
+        const OUTLINE: f64 = 1.;
+        let icon = Icon::new(*self, Color::BLACK).with_halo(OUTLINE, Color::WHITE);
+        let inset = OUTLINE * size as f64 / self.size.width.max(self.size.height);
+        let rect = Size::new(size as f64, size as f64).to_rect().inset(-inset);
+        let image = image::render(size, size, |rc| {
+            rc.transform(Affine::translate(rect.origin().to_vec2()));
+            icon.paint_into(rc, rect.size(), &Env::empty());
+        })
+        .ok()?;
+        window.make_cursor(&CursorDesc::new(image, hot_spot))
+    }
+}
+
+/// Icons are the same if they share path data, so this is cheap but may report icons that are
+/// copies of each other as different.
+#[cfg(any(feature = "druid", feature = "druid-0_7"))]
+impl Data for IconPaths {
+    fn same(&self, other: &Self) -> bool {
+        std::ptr::eq(self.paths, other.paths) && self.size == other.size
+    }
+}
+
+impl IconPaths {
+    /// An icon drawn with `paths` in a box of `size`, with an empty name, category and variant.
+    ///
+    /// This is how other crates publish icon packs that work with this crate's widgets, caches
+    /// and helpers: define each icon as a constant, filling in its metadata with struct update
+    /// syntax, and the widgets treat it like any other icon. Give the pack's icons a category
+    /// and variant of their own so that they can be told apart from this crate's. The
+    /// [`icon_path!`] macro makes the paths shorter to write. The complexity is worked out from
+    /// the paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::{icon_path, IconPaths};
+    /// use kurbo::Size;
+    ///
+    /// const TRIANGLE: IconPaths = IconPaths {
+    ///     name: "triangle",
+    ///     category: "shapes",
+    ///     variant: "acme",
+    ///     ..IconPaths::from_static(
+    ///         &[icon_path![M(12., 3.), L(22., 21.), L(2., 21.), Z]],
+    ///         Size::new(24., 24.),
+    ///     )
+    /// };
+    /// assert_eq!(TRIANGLE.complexity, 5);
+    /// assert_eq!(TRIANGLE.paths[0].els.len(), 4);
+    /// ```
+    pub const fn from_static(paths: &'static [IconPath], size: Size) -> Self {
+        // Iterators aren't usable in const fns, so count the same way the generator does by hand.
+        let mut complexity = 0;
+        let mut i = 0;
+        while i < paths.len() {
+            complexity += 1;
+            let els = paths[i].els;
+            let mut j = 0;
+            while j < els.len() {
+                complexity += match els[j] {
+                    PathEl::MoveTo(_) | PathEl::LineTo(_) | PathEl::ClosePath => 1,
+                    PathEl::QuadTo(..) => 2,
+                    PathEl::CurveTo(..) => 3,
+                };
+                j += 1;
+            }
+            i += 1;
+        }
+        IconPaths {
+            name: "",
+            category: "",
+            variant: "",
+            paths,
+            size,
+            complexity,
+        }
+    }
+
+    /// An icon of your own, drawn with `paths` in a box of `size`.
+    ///
+    /// This is a `const fn`, so custom icons can be defined alongside the crate's own and used
+    /// anywhere they are. The icon's category and variant are both `"custom"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::{IconPath, IconPaths};
+    /// use kurbo::{PathEl, Point, Size};
+    ///
+    /// const DIAMOND: IconPaths = IconPaths::custom(
+    ///     "diamond",
+    ///     &[IconPath::new(
+    ///         &[
+    ///             PathEl::MoveTo(Point::new(12., 2.)),
+    ///             PathEl::LineTo(Point::new(22., 12.)),
+    ///             PathEl::LineTo(Point::new(12., 22.)),
+    ///             PathEl::LineTo(Point::new(2., 12.)),
+    ///             PathEl::ClosePath,
+    ///         ],
+    ///         1.,
+    ///     )],
+    ///     Size::new(24., 24.),
+    /// );
+    /// assert_eq!(DIAMOND.complexity, 6);
+    /// assert!(DIAMOND.fill_pair().is_none());
+    /// ```
+    pub const fn custom(name: &'static str, paths: &'static [IconPath], size: Size) -> Self {
+        IconPaths {
+            name,
+            category: "custom",
+            variant: "custom",
+            ..IconPaths::from_static(paths, size)
+        }
+    }
+
+    /// The icon's identity; see [`IconId`].
+    #[inline]
+    pub fn id(&self) -> IconId {
+        IconId {
+            variant: self.variant,
+            category: self.category,
+            name: self.name,
+            paths: (self.paths.as_ptr() as usize, self.paths.len()),
+        }
+    }
+
+    /// The outlined and filled versions of this icon, if it has both.
+    ///
+    /// See [`FILL_PAIRS`] for the full list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons as icons;
+    /// let (outlined, filled) = icons::FAVORITE.fill_pair().unwrap();
+    /// assert_eq!((outlined.name, filled.name), ("favorite_border", "favorite"));
+    /// assert!(icons::ADD.fill_pair().is_none());
+    /// ```
+    pub fn fill_pair(&self) -> Option<(IconPaths, IconPaths)> {
+        let name = self.icon_name()?;
+        let (outlined, filled) = match name.filled() {
+            Some(filled) => (name, filled),
+            None => (name.outlined()?, name),
+        };
+        Some((outlined.paths(), filled.paths()))
+    }
+
+    /// The name of this icon, if it is one of the crate's icons.
+    fn icon_name(&self) -> Option<IconName> {
+        if self.variant != "normal" {
+            return None;
+        }
+        find_index(ALL, self.name).map(|idx| IconName::ALL[idx])
+    }
+
+    /// Whether the icon should be mirrored in right-to-left layouts, e.g. because it points
+    /// "forward" or "back".
+    ///
+    /// See [`MIRRORABLE`] for the full list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons as icons;
+    /// assert!(icons::ARROW_FORWARD.is_mirrorable());
+    /// assert!(!icons::ADD.is_mirrorable());
+    /// ```
+    pub fn is_mirrorable(&self) -> bool {
+        self.variant == "normal"
+            && MIRRORABLE
+                .binary_search_by(|probe| probe.as_str().cmp(self.name))
+                .is_ok()
+    }
+
+    /// The point of the icon that should be placed on the position it marks, in the icon's
+    /// coordinates.
+    ///
+    /// This is the tip of the pin for map markers like [`PLACE`], and the center of the icon's
+    /// box for everything else. Use it to position map overlays and cursors by what the icon
+    /// points at rather than by its bounds. See [`ANCHORS`] for the icons that aren't centered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons as icons;
+    /// use kurbo::Point;
+    /// assert_eq!(icons::PLACE.anchor(), Point::new(12., 22.));
+    /// assert_eq!(icons::ADD.anchor(), Point::new(12., 12.));
+    /// ```
+    pub fn anchor(&self) -> Point {
+        let listed = ANCHORS
+            .binary_search_by(|(probe, _)| probe.as_str().cmp(self.name))
+            .ok()
+            .filter(|_| self.variant == "normal");
+        match listed {
+            Some(idx) => ANCHORS[idx].1,
+            None => Point::new(self.size.width / 2., self.size.height / 2.),
+        }
+    }
+
+    /// The smallest rectangle containing all of the icon's paths, in the icon's coordinates.
+    ///
+    /// Icons are drawn with some padding inside their [`size`](IconPaths::size), so this is
+    /// useful for aligning what is actually visible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let bounds = druid_material_icons::ADD.ink_bounds();
+    /// assert!(bounds.x0 > 0. && bounds.x1 < 24.);
+    /// ```
+    pub fn ink_bounds(&self) -> Rect {
+        self.paths
+            .iter()
+            .map(Shape::bounding_box)
+            .reduce(|bounds, path| bounds.union(path))
+            .unwrap_or_else(|| self.size.to_rect())
+    }
+
+    /// The paths of the icon grouped by opacity, so that a renderer can composite each opacity
+    /// level once, e.g. by drawing its paths into one layer, rather than once per path.
+    ///
+    /// The generator orders each icon's paths so that every opacity level is one group, in the
+    /// order the levels first appear upstream. This is also the split needed to color the
+    /// levels of two-tone icons separately. For custom icons, each run of paths with the same
+    /// opacity is a group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let groups: Vec<_> = druid_material_icons::ADD.groups().collect();
+    /// assert_eq!(groups.len(), 1);
+    /// assert_eq!(groups[0].opacity, 1.);
+    /// assert_eq!(groups[0].paths.len(), druid_material_icons::ADD.paths.len());
+    /// ```
+    pub fn groups(&self) -> impl Iterator<Item = OpacityGroup> {
+        let mut rest = self.paths;
+        std::iter::from_fn(move || {
+            let opacity = rest.first()?.opacity;
+            let len = rest
+                .iter()
+                .position(|path| path.opacity != opacity)
+                .unwrap_or(rest.len());
+            let (paths, tail) = rest.split_at(len);
+            rest = tail;
+            Some(OpacityGroup { opacity, paths })
+        })
+    }
+
+    /// This icon as a standalone SVG document, filled with `currentColor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let svg = druid_material_icons::ADD.to_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn to_svg(&self) -> String {
+        let Size { width, height } = self.size;
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" fill="currentColor">"#,
+            width, height
+        );
+        for path in self.paths {
+            if path.opacity < 1. {
+                svg.push_str(&format!(
+                    r#"<path d="{}" fill-opacity="{}"/>"#,
+                    path.to_svg_path_data(),
+                    path.opacity
+                ));
+            } else {
+                svg.push_str(&format!(r#"<path d="{}"/>"#, path.to_svg_path_data()));
+            }
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Where this icon comes from and the license it is under, or `None` if it isn't one of the
+    /// crate's icons or from a companion pack that is built.
+    ///
+    /// Use this to credit the icons an app uses on an "open source licenses" screen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons as icons;
+    /// let attribution = icons::ADD.attribution().unwrap();
+    /// assert_eq!(attribution.license, "Apache-2.0");
+    /// let custom = icons::IconPaths::custom("blank", &[], kurbo::Size::new(24., 24.));
+    /// assert!(custom.attribution().is_none());
+    /// // Other paths under a crate icon's name aren't credited to upstream either.
+    /// let renamed = icons::IconPaths {
+    ///     paths: icons::REMOVE.paths,
+    ///     ..icons::ADD
+    /// };
+    /// assert!(renamed.attribution().is_none());
+    /// // Icons from companion packs are credited to whoever drew them.
+    /// # #[cfg(feature = "brands")]
+    /// # {
+    /// let markdown = icons::brands::MARKDOWN.attribution().unwrap();
+    /// assert_eq!(markdown.license, "CC0-1.0");
+    /// # }
+    /// ```
+    pub fn attribution(&self) -> Option<Attribution> {
+        let (icons, attribution) = match variant_icons(self.variant) {
+            Ok(icons) => (icons, UPSTREAM_ATTRIBUTION),
+            Err(_) => PACKS
+                .iter()
+                .find(|(variant, _, _)| *variant == self.variant)
+                .map(|&(_, icons, attribution)| (icons, attribution))?,
+        };
+        let (_, icon) = icons.get(find_index(icons, self.name)?)?;
+        // Don't credit upstream for other paths that have been given a crate icon's name. Copies
+        // of a constant usually share its path data, but the compiler is free to duplicate it,
+        // e.g. across crates, so fall back to comparing the data itself.
+        let same = std::ptr::eq(icon.paths, self.paths)
+            || (icon.paths.len() == self.paths.len()
+                && icon
+                    .paths
+                    .iter()
+                    .zip(self.paths)
+                    .all(|(a, b)| a.opacity == b.opacity && a.els == b.els));
+        (same && icon.size == self.size).then_some(attribution)
+    }
+
+    /// PDF content stream operators that fill this icon, stretched to fill `rect`, with the
+    /// current fill color.
+    ///
+    /// `rect` is in PDF user space, whose y axis points up. Add the operators to a page's content
+    /// stream (e.g. with `printpdf`'s raw operations) to embed the icon as vector graphics. PDF
+    /// needs a graphics state resource for translucency, so translucent paths are filled opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kurbo::Rect;
+    /// // Black, then a 24pt icon 72pt from the bottom left corner.
+    /// let content = format!(
+    ///     "0 g\n{}",
+    ///     druid_material_icons::ADD.to_pdf_content(Rect::new(72., 72., 96., 96.))
+    /// );
+    /// assert!(content.ends_with("h\nf\n"));
+    /// ```
+    pub fn to_pdf_content(&self, rect: Rect) -> String {
+        // Flip the icon's y axis, which points down, to match PDF's.
+        let transform = Affine::new([
+            rect.width() / self.size.width,
+            0.,
+            0.,
+            -rect.height() / self.size.height,
+            rect.x0,
+            rect.y1,
+        ]);
+        let mut content = String::new();
+        for path in self.paths {
+            pdf::fill_path(
+                &mut content,
+                path.els.iter().map(|el| match transform * *el {
+                    PathEl::MoveTo(p) => pdf::El::MoveTo((p.x, p.y)),
+                    PathEl::LineTo(p) => pdf::El::LineTo((p.x, p.y)),
+                    PathEl::QuadTo(p1, p2) => pdf::El::QuadTo((p1.x, p1.y), (p2.x, p2.y)),
+                    PathEl::CurveTo(p1, p2, p3) => {
+                        pdf::El::CurveTo((p1.x, p1.y), (p2.x, p2.y), (p3.x, p3.y))
+                    }
+                    PathEl::ClosePath => pdf::El::ClosePath,
+                }),
+            );
+        }
+        content
+    }
+}
+
+/// One path of an icon, filled with the icon's color at `opacity`.
 #[derive(Debug, Copy, Clone)]
 pub struct IconPath {
     pub els: &'static [PathEl],
     pub opacity: f64,
 }
 
+/// Paths of an icon that are all filled at the same `opacity`; see [`IconPaths::groups`].
+#[derive(Debug, Copy, Clone)]
+pub struct OpacityGroup {
+    pub opacity: f64,
+    pub paths: &'static [IconPath],
+}
+
+impl IconPath {
+    /// A path made of `els`, painted at `opacity` times the icon's opacity.
+    ///
+    /// See [`IconPaths::custom`] for an example.
+    #[inline]
+    pub const fn new(els: &'static [PathEl], opacity: f64) -> Self {
+        IconPath { els, opacity }
+    }
+
+    /// The path as SVG path data, suitable for the `d` attribute of a `<path>` element.
+    pub fn to_svg_path_data(&self) -> String {
+        BezPath::from_vec(self.els.to_vec()).to_svg()
+    }
+
+    /// The area of the path once its curves are flattened to lines within `tolerance`.
+    ///
+    /// This is the area that code working on the flattened outline, e.g. a physics engine or a
+    /// polygon hit test, sees, and for a given tolerance it is always the same. Like
+    /// [`Shape::area`] it is signed: positive when the outline runs clockwise with `y` down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kurbo::Shape;
+    /// let path = druid_material_icons::ADD_CIRCLE.paths[0];
+    /// let coarse = path.area_with_tolerance(1.);
+    /// let fine = path.area_with_tolerance(0.001);
+    /// assert!((fine - path.area()).abs() < (coarse - path.area()).abs());
+    /// ```
+    pub fn area_with_tolerance(&self, tolerance: f64) -> f64 {
+        self.flattened(tolerance).area()
+    }
+
+    /// The length of the path once its curves are flattened to lines within `tolerance`.
+    ///
+    /// Like [`area_with_tolerance`](IconPath::area_with_tolerance), this is what code working
+    /// on the flattened outline sees, and is always the same for a given tolerance. It is never
+    /// longer than the curved outline.
+    pub fn perimeter_with_tolerance(&self, tolerance: f64) -> f64 {
+        // Lines are measured exactly, whatever the accuracy.
+        self.flattened(tolerance).perimeter(1.)
+    }
+
+    /// The path with its curves flattened to lines within `tolerance`.
+    fn flattened(&self, tolerance: f64) -> BezPath {
+        let mut flattened = BezPath::new();
+        BezPath::from_vec(self.els.to_vec()).flatten(tolerance, |el| flattened.push(el));
+        flattened
+    }
+}
+
+/// Icon paths are shapes in the icon's coordinates.
+///
+/// [`area`](Shape::area) is exact, as the area under a curve has a closed form, and
+/// [`perimeter`](Shape::perimeter) measures each segment to within `accuracy`. Use
+/// [`IconPath::area_with_tolerance`] and [`IconPath::perimeter_with_tolerance`] to measure the
+/// flattened outline instead.
 impl Shape for IconPath {
+    // Druid 0.7 uses an older kurbo, from before this iterator could borrow the shape.
+    #[cfg(not(feature = "druid-0_7"))]
     type PathElementsIter<'a> = std::iter::Copied<std::slice::Iter<'static, PathEl>>;
+    #[cfg(feature = "druid-0_7")]
+    type PathElementsIter = std::iter::Copied<std::slice::Iter<'static, PathEl>>;
+
+    #[cfg(not(feature = "druid-0_7"))]
     fn path_elements(&self, _tolerance: f64) -> Self::PathElementsIter<'_> {
         self.els.iter().copied()
     }
+    #[cfg(feature = "druid-0_7")]
+    fn path_elements(&self, _tolerance: f64) -> Self::PathElementsIter {
+        self.els.iter().copied()
+    }
 
     fn area(&self) -> f64 {
         self.els.area()
@@ -132,4 +804,335 @@ impl Shape for IconPath {
     }
 }
 
+// The build script writes a copy with only the allowed icons when `DRUID_MATERIAL_ICONS` is set.
+#[cfg(not(icon_allowlist))]
 include!("./icons.rs.in");
+#[cfg(icon_allowlist)]
+include!(concat!(env!("OUT_DIR"), "/icons.rs.in"));
+
+// Check the guarantees documented on `IconPaths`, and that the generated tables line up, so
+// that changes to the generator can't quietly break code relying on them.
+const _: () = {
+    const fn copy_send_sync<T: Copy + Send + Sync>() {}
+    copy_send_sync::<IconPaths>();
+    copy_send_sync::<IconPath>();
+    copy_send_sync::<OpacityGroup>();
+    copy_send_sync::<IconName>();
+
+    // `IconName` indexes `ALL` by its discriminant.
+    assert!(IconName::ALL.len() == ALL.len());
+    let mut idx = 0;
+    while idx < IconName::ALL.len() {
+        assert!(IconName::ALL[idx] as usize == idx);
+        idx += 1;
+    }
+};
+
+/// Every icon in the crate as `(name, icon)` pairs, sorted by name.
+///
+/// Each category module also has its own `ALL` table.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{normal::content, ALL};
+/// assert!(ALL.iter().any(|(name, _)| *name == "add"));
+/// assert!(content::ALL.len() < ALL.len());
+/// ```
+pub use normal::ALL;
+
+/// Where an icon comes from and the terms it may be used under.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Attribution {
+    /// The SPDX identifier of the license, e.g. `"Apache-2.0"`.
+    pub license: &'static str,
+    /// Who holds the copyright.
+    pub copyright: &'static str,
+    /// Where the original icons can be found.
+    pub source: &'static str,
+    /// The upstream release the icons were generated from, if generated from a release.
+    pub release: Option<&'static str>,
+    /// The upstream commit the icons were generated from, if known.
+    pub commit: Option<&'static str>,
+}
+
+/// The attribution of every icon in the crate, which all come from the same upstream.
+pub const UPSTREAM_ATTRIBUTION: Attribution = Attribution {
+    license: UPSTREAM_LICENSE,
+    copyright: "Google LLC",
+    source: "https://github.com/google/material-design-icons",
+    release: UPSTREAM_RELEASE,
+    commit: UPSTREAM_COMMIT,
+};
+
+/// A companion pack's variant, icons and attribution.
+type Pack = (
+    &'static str,
+    &'static [(&'static str, IconPaths)],
+    Attribution,
+);
+
+/// The companion packs that are built.
+const PACKS: &[Pack] = &[
+    #[cfg(feature = "brands")]
+    ("brands", brands::ALL, brands::ATTRIBUTION),
+    #[cfg(feature = "symbols_extra")]
+    (
+        "symbols_extra",
+        symbols_extra::ALL,
+        symbols_extra::ATTRIBUTION,
+    ),
+];
+
+/// The number of icons in the crate.
+pub const ICON_COUNT: usize = ALL.len();
+
+/// Every category of icons, sorted by name.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons as icons;
+/// let total: usize = icons::categories().iter().map(|c| c.icon_count()).sum();
+/// assert_eq!(total, icons::ICON_COUNT);
+/// ```
+#[inline]
+pub fn categories() -> &'static [Category] {
+    normal::CATEGORIES
+}
+
+/// A group of related icons, e.g. `"content"`.
+#[derive(Debug, Copy, Clone)]
+pub struct Category {
+    name: &'static str,
+    variant: &'static str,
+    icons: &'static [(&'static str, IconPaths)],
+    names: &'static [&'static str],
+    code_size: usize,
+}
+
+impl Category {
+    /// The name of the category, which is also the name of its module.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The style variant the category belongs to, e.g. `"normal"`, which is also the name of
+    /// the module the category's module is in.
+    #[inline]
+    pub const fn variant(&self) -> &'static str {
+        self.variant
+    }
+
+    /// The icons in this category as `(name, icon)` pairs, sorted by name.
+    #[inline]
+    pub const fn icons(&self) -> &'static [(&'static str, IconPaths)] {
+        self.icons
+    }
+
+    /// The number of icons in this category.
+    #[inline]
+    pub const fn icon_count(&self) -> usize {
+        self.icons.len()
+    }
+
+    /// The names of the icons in this category, sorted. This is the category module's `NAMES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::{categories, normal::content};
+    /// let content = categories().iter().find(|c| c.name() == "content").unwrap();
+    /// assert_eq!(content.names(), content::NAMES);
+    /// assert_eq!(content.names().len(), content::COUNT);
+    /// ```
+    #[inline]
+    pub const fn names(&self) -> &'static [&'static str] {
+        self.names
+    }
+
+    /// The size of the category's generated code in bytes, before formatting.
+    ///
+    /// This is a rough measure of how much the category adds to compile times, and what
+    /// decides whether it is behind its own `category-*` feature.
+    #[inline]
+    pub const fn code_size(&self) -> usize {
+        self.code_size
+    }
+}
+
+/// The icon used by [`lookup_or_default`] when the requested icon doesn't exist.
+pub const DEFAULT_ICON: IconPaths = normal::image::BROKEN_IMAGE;
+
+/// Find an icon by its upstream name (e.g. `"add_circle"`).
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons as icons;
+/// assert_eq!(icons::lookup("add").unwrap().name, icons::ADD.name);
+/// assert!(matches!(
+///     icons::lookup("ad_circle"),
+///     Err(icons::Error::UnknownIcon { suggestions, .. }) if suggestions[0] == "add_circle"
+/// ));
+/// ```
+#[inline]
+pub fn lookup(name: &str) -> Result<IconPaths, Error> {
+    lookup_variant("normal", name)
+}
+
+/// Find an icon by its style variant (e.g. `"normal"`) and upstream name.
+pub fn lookup_variant(variant: &str, name: &str) -> Result<IconPaths, Error> {
+    let icons = variant_icons(variant)?;
+    find(icons, name).ok_or_else(|| Error::unknown_icon(name, icons.iter()))
+}
+
+/// Every icon whose upstream name contains `query`, in name order.
+///
+/// # Examples
+///
+/// ```
+/// let names: Vec<_> = druid_material_icons::search("alarm").map(|icon| icon.name).collect();
+/// assert!(names.contains(&"alarm_add"));
+/// ```
+pub fn search(query: &str) -> impl Iterator<Item = IconPaths> + '_ {
+    ALL.iter()
+        .filter(move |(name, _)| name.contains(query))
+        .map(|(_, icon)| *icon)
+}
+
+/// Find an icon by its upstream name, or use `fallback` if there is no such icon.
+///
+/// This is useful when icon names come from configuration, and may go missing when upstream
+/// renames an icon.
+#[inline]
+pub fn lookup_or(name: &str, fallback: IconPaths) -> IconPaths {
+    find(ALL, name).unwrap_or(fallback)
+}
+
+/// Find an icon by its upstream name, or use [`DEFAULT_ICON`] if there is no such icon.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons as icons;
+/// assert_eq!(icons::lookup_or_default("no_such_icon").name, "broken_image");
+/// ```
+#[inline]
+pub fn lookup_or_default(name: &str) -> IconPaths {
+    lookup_or(name, DEFAULT_ICON)
+}
+
+/// The icons of the given style variant.
+fn variant_icons(variant: &str) -> Result<&'static [(&'static str, IconPaths)], Error> {
+    VARIANTS
+        .iter()
+        .find(|(probe, _)| *probe == variant)
+        .map(|(_, icons)| *icons)
+        .ok_or_else(|| Error::UnknownVariant(variant.to_owned()))
+}
+
+/// Find an icon in a table sorted by name.
+fn find(icons: &[(&str, IconPaths)], name: &str) -> Option<IconPaths> {
+    find_index(icons, name).map(|idx| icons[idx].1)
+}
+
+/// Find the position of an icon in a table sorted by name.
+fn find_index(icons: &[(&str, IconPaths)], name: &str) -> Option<usize> {
+    icons.binary_search_by(|(probe, _)| (*probe).cmp(name)).ok()
+}
+
+/// The names of the first `N` icons of a table, for the generated `NAMES` constants.
+const fn names<const N: usize>(icons: &[(&'static str, IconPaths)]) -> [&'static str; N] {
+    let mut names = [""; N];
+    let mut idx = 0;
+    while idx < N {
+        names[idx] = icons[idx].0;
+        idx += 1;
+    }
+    names
+}
+
+impl IconName {
+    /// The upstream name of the icon, e.g. `"add_circle"`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        ALL[self as usize].0
+    }
+
+    /// The icon with this name.
+    #[inline]
+    pub fn paths(self) -> IconPaths {
+        ALL[self as usize].1
+    }
+
+    /// The filled version of this outlined icon, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::IconName;
+    /// assert_eq!(IconName::StarBorder.filled(), Some(IconName::Star));
+    /// assert_eq!(IconName::Star.filled(), None);
+    /// ```
+    pub fn filled(self) -> Option<IconName> {
+        FILL_PAIRS
+            .binary_search_by(|(outlined, _)| outlined.cmp(&self))
+            .ok()
+            .map(|idx| FILL_PAIRS[idx].1)
+    }
+
+    /// The outlined version of this filled icon, if there is one.
+    ///
+    /// Some icons have more than one outlined version (e.g. `star_border` and `star_outline`),
+    /// in which case this is the first in name order.
+    pub fn outlined(self) -> Option<IconName> {
+        FILL_PAIRS
+            .iter()
+            .find(|(_, filled)| *filled == self)
+            .map(|(outlined, _)| *outlined)
+    }
+
+    /// Whether the icon should be mirrored in right-to-left layouts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::IconName;
+    /// assert!(IconName::ArrowBack.is_mirrorable());
+    /// assert!(!IconName::Add.is_mirrorable());
+    /// ```
+    #[inline]
+    pub fn is_mirrorable(self) -> bool {
+        MIRRORABLE.binary_search(&self).is_ok()
+    }
+}
+
+impl fmt::Display for IconName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parse an upstream icon name (e.g. `"add_circle"`).
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{Error, IconName};
+/// assert_eq!("add_circle".parse::<IconName>(), Ok(IconName::AddCircle));
+/// assert_eq!(
+///     "add_circel".parse::<IconName>().unwrap_err().to_string(),
+///     "no icon named `add_circel` (did you mean `add_circle`?)",
+/// );
+/// ```
+impl FromStr for IconName {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        find_index(ALL, name)
+            .map(|idx| IconName::ALL[idx])
+            .ok_or_else(|| Error::unknown_icon(name, ALL.iter()))
+    }
+}
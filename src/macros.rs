@@ -0,0 +1,77 @@
+/// Re-export a list of icons, optionally renaming them.
+///
+/// Use this in a module of your app to keep all the icons it uses in one place. Each name is
+/// looked up in the crate root, so it is the name the icon has there (see the [crate
+/// docs](crate)).
+///
+/// # Examples
+///
+/// ```
+/// mod icons {
+///     druid_material_icons::icons! { ADD, DELETE, SEARCH as FIND }
+/// }
+///
+/// assert_eq!(icons::FIND.name, "search");
+/// assert_eq!(icons::DELETE.name, "delete");
+/// ```
+#[macro_export]
+macro_rules! icons {
+    ($($name:ident $(as $alias:ident)?),* $(,)?) => {
+        $(
+            #[allow(unused_imports)]
+            pub use $crate::$name $(as $alias)?;
+        )*
+    };
+}
+
+/// An [`IconPath`](crate::IconPath) from SVG-like path commands, for defining icons with
+/// [`IconPaths::from_static`](crate::IconPaths::from_static).
+///
+/// The commands are `M(x, y)`, `L(x, y)`, `Q(x1, y1, x, y)`, `C(x1, y1, x2, y2, x, y)` and `Z`,
+/// all with absolute coordinates. Start with `opacity: value;` to paint the path at less than
+/// full opacity. The result is a constant expression, so it can be used in `const` items.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{icon_path, IconPath};
+///
+/// const SHADOW: IconPath =
+///     icon_path![opacity: 0.3; M(2., 2.), Q(12., 0., 22., 2.), L(12., 22.), Z];
+/// assert_eq!(SHADOW.opacity, 0.3);
+/// assert_eq!(SHADOW.els.len(), 4);
+/// ```
+#[macro_export]
+macro_rules! icon_path {
+    (@el M($x:expr, $y:expr)) => {
+        $crate::__kurbo::PathEl::MoveTo($crate::__kurbo::Point::new($x, $y))
+    };
+    (@el L($x:expr, $y:expr)) => {
+        $crate::__kurbo::PathEl::LineTo($crate::__kurbo::Point::new($x, $y))
+    };
+    (@el Q($x1:expr, $y1:expr, $x:expr, $y:expr)) => {
+        $crate::__kurbo::PathEl::QuadTo(
+            $crate::__kurbo::Point::new($x1, $y1),
+            $crate::__kurbo::Point::new($x, $y),
+        )
+    };
+    (@el C($x1:expr, $y1:expr, $x2:expr, $y2:expr, $x:expr, $y:expr)) => {
+        $crate::__kurbo::PathEl::CurveTo(
+            $crate::__kurbo::Point::new($x1, $y1),
+            $crate::__kurbo::Point::new($x2, $y2),
+            $crate::__kurbo::Point::new($x, $y),
+        )
+    };
+    (@el Z) => {
+        $crate::__kurbo::PathEl::ClosePath
+    };
+    (opacity: $opacity:expr; $($cmd:ident $(($($arg:expr),*))?),* $(,)?) => {
+        $crate::IconPath::new(
+            &[$($crate::icon_path!(@el $cmd $(($($arg),*))?)),*],
+            $opacity,
+        )
+    };
+    ($($cmd:ident $(($($arg:expr),*))?),* $(,)?) => {
+        $crate::icon_path!(opacity: 1.; $($cmd $(($($arg),*))?),*)
+    };
+}
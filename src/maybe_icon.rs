@@ -0,0 +1,176 @@
+use druid::{
+    kurbo::{PathEl, Point},
+    widget::prelude::*,
+    Color, Data,
+};
+
+use crate::{Icon, IconPath, IconPaths, DEFAULT_ICON};
+
+/// A rounded square, shown shimmering while an icon loads if there is no better silhouette.
+const SKELETON: IconPaths = IconPaths::custom(
+    "skeleton",
+    &[IconPath::new(
+        &[
+            PathEl::MoveTo(Point { x: 6., y: 2. }),
+            PathEl::LineTo(Point { x: 18., y: 2. }),
+            PathEl::QuadTo(Point { x: 22., y: 2. }, Point { x: 22., y: 6. }),
+            PathEl::LineTo(Point { x: 22., y: 18. }),
+            PathEl::QuadTo(Point { x: 22., y: 22. }, Point { x: 18., y: 22. }),
+            PathEl::LineTo(Point { x: 6., y: 22. }),
+            PathEl::QuadTo(Point { x: 2., y: 22. }, Point { x: 2., y: 18. }),
+            PathEl::LineTo(Point { x: 2., y: 6. }),
+            PathEl::QuadTo(Point { x: 2., y: 2. }, Point { x: 6., y: 2. }),
+            PathEl::ClosePath,
+        ],
+        1.,
+    )],
+    Size {
+        width: 24.,
+        height: 24.,
+    },
+);
+
+/// What a [`MaybeIcon`] shows when there is no icon.
+#[derive(Debug, Copy, Clone)]
+pub enum Placeholder {
+    /// Show nothing, and take up as little space as the constraints allow.
+    Collapse,
+    /// Show nothing, but take up the space an icon would.
+    Blank,
+    /// Show the given icon instead.
+    Icon(IconPaths),
+    /// Show that the icon is loading, with a shimmer over a faint silhouette of the given icon,
+    /// or of a rounded square if there is none.
+    Shimmer(Option<IconPaths>),
+}
+
+/// A widget that displays an optional icon from its data.
+///
+/// When the data is `None`, the widget shows its [`Placeholder`], which by default collapses
+/// the widget to nothing. Use [`Placeholder::Shimmer`] for data that is still loading.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{Color, Widget, WidgetExt};
+/// use druid_material_icons::{MaybeIcon, Placeholder, IconPaths, HELP_OUTLINE};
+/// fn status_icon() -> impl Widget<Option<IconPaths>> {
+///     MaybeIcon::new(Color::WHITE).with_placeholder(Placeholder::Icon(HELP_OUTLINE))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaybeIcon {
+    icon: Icon,
+    placeholder: Placeholder,
+}
+
+impl MaybeIcon {
+    /// Create a widget that paints its icon with the given color.
+    pub fn new(color: Color) -> Self {
+        MaybeIcon {
+            icon: Icon::new(DEFAULT_ICON, color),
+            placeholder: Placeholder::Collapse,
+        }
+    }
+
+    /// Builder-style method to set what is shown when there is no icon.
+    #[inline]
+    pub fn with_placeholder(mut self, placeholder: Placeholder) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Set what is shown when there is no icon.
+    ///
+    /// The caller is responsible for requesting layout.
+    #[inline]
+    pub fn set_placeholder(&mut self, placeholder: Placeholder) {
+        self.placeholder = placeholder;
+    }
+
+    /// Point the inner icon at whatever we should currently be showing.
+    fn sync(&mut self, data: &Option<IconPaths>) {
+        match (*data, self.placeholder) {
+            (Some(paths), _) | (None, Placeholder::Icon(paths)) => {
+                self.icon.set_icon(paths);
+                self.icon.set_loading(false);
+            }
+            (None, Placeholder::Shimmer(paths)) => {
+                self.icon.set_icon(paths.unwrap_or(SKELETON));
+                self.icon.set_loading(true);
+            }
+            (None, Placeholder::Collapse | Placeholder::Blank) => self.icon.set_loading(false),
+        }
+    }
+
+    /// Whether there is anything to paint.
+    fn is_visible(&self, data: &Option<IconPaths>) -> bool {
+        data.is_some()
+            || matches!(
+                self.placeholder,
+                Placeholder::Icon(_) | Placeholder::Shimmer(_)
+            )
+    }
+}
+
+impl Widget<Option<IconPaths>> for MaybeIcon {
+    fn event(
+        &mut self,
+        ctx: &mut EventCtx,
+        event: &Event,
+        _data: &mut Option<IconPaths>,
+        env: &Env,
+    ) {
+        self.icon.event(ctx, event, &mut (), env)
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &Option<IconPaths>,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            // The shimmer has to be running before the icon sees this to start animating.
+            self.sync(data);
+        }
+        self.icon.lifecycle(ctx, event, &(), env)
+    }
+
+    fn update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        old_data: &Option<IconPaths>,
+        data: &Option<IconPaths>,
+        env: &Env,
+    ) {
+        if !old_data.same(data) {
+            // Sync now rather than waiting for layout, so that a shimmer can start animating.
+            self.sync(data);
+            ctx.request_layout();
+        }
+        self.icon.update(ctx, &(), &(), env)
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &Option<IconPaths>,
+        env: &Env,
+    ) -> Size {
+        // Syncing here means placeholder changes are picked up by the usual request for layout.
+        self.sync(data);
+        match (data, self.placeholder) {
+            (None, Placeholder::Collapse) => bc.min(),
+            _ => self.icon.layout(ctx, bc, &(), env),
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Option<IconPaths>, env: &Env) {
+        if self.is_visible(data) {
+            self.icon.paint(ctx, &(), env)
+        }
+    }
+}
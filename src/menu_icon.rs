@@ -0,0 +1,92 @@
+use druid::{kurbo::Size, widget::prelude::*, Data, Menu, Point};
+
+use crate::{IconButton, IconButtonStyle, IconPaths, MORE_HORIZ, MORE_VERT};
+
+/// An [`IconButton`] showing [`MORE_VERT`] or [`MORE_HORIZ`] that opens a menu below itself
+/// when clicked.
+///
+/// The menu is built when it is opened, so it can depend on the data. Because of how druid shows
+/// context menus, this only works if `T` is the app's root data type.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{Data, Menu, MenuItem, Widget};
+/// use druid_material_icons::MenuIcon;
+/// #[derive(Clone, Data)]
+/// struct Message {
+///     starred: bool,
+/// }
+///
+/// fn message_menu() -> impl Widget<Message> {
+///     MenuIcon::new(|message: &Message, _env| {
+///         let label = if message.starred { "Unstar" } else { "Star" };
+///         Menu::empty().entry(
+///             MenuItem::new(label).on_activate(|_ctx, message: &mut Message, _env| {
+///                 message.starred = !message.starred
+///             }),
+///         )
+///     })
+/// }
+/// ```
+pub struct MenuIcon<T> {
+    button: IconButton<T>,
+}
+
+impl<T: Data> MenuIcon<T> {
+    /// Create a button showing [`MORE_VERT`] that opens the menu made by `menu`.
+    pub fn new(menu: impl Fn(&T, &Env) -> Menu<T> + 'static) -> Self {
+        Self::with_icon(MORE_VERT, menu)
+    }
+
+    /// Create a button showing [`MORE_HORIZ`] that opens the menu made by `menu`.
+    pub fn horizontal(menu: impl Fn(&T, &Env) -> Menu<T> + 'static) -> Self {
+        Self::with_icon(MORE_HORIZ, menu)
+    }
+
+    fn with_icon(icon: IconPaths, menu: impl Fn(&T, &Env) -> Menu<T> + 'static) -> Self {
+        let button = IconButton::new(icon).on_click(move |ctx, data, env| {
+            // Open the menu just below the button, lined up with its left edge.
+            let location = ctx.to_window(Point::new(0., ctx.size().height));
+            ctx.show_context_menu(menu(data, env), location);
+        });
+        MenuIcon { button }
+    }
+
+    /// Builder-style method to set the button's style.
+    #[inline]
+    pub fn with_style(mut self, style: IconButtonStyle) -> Self {
+        self.button.set_style(style);
+        self
+    }
+
+    /// Set the button's style.
+    ///
+    /// The caller is responsible for requesting paint.
+    #[inline]
+    pub fn set_style(&mut self, style: IconButtonStyle) {
+        self.button.set_style(style);
+    }
+}
+
+impl<T: Data> Widget<T> for MenuIcon<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.button.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.button.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.button.update(ctx, old_data, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.button.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.button.paint(ctx, data, env);
+    }
+}
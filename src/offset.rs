@@ -0,0 +1,58 @@
+//! Growing and shrinking icon outlines, for halo backgrounds, tap-target visualizations and
+//! selection highlights. Build with the `offset` feature.
+
+use flo_curves::bezier::path::{
+    path_add, path_remove_interior_points, path_sub, stroke_path, LineJoin, SimpleBezierPath,
+    StrokeOptions,
+};
+
+use crate::OwnedIconPaths;
+
+/// How close the result follows the ideal offset outline, in icon units.
+const ACCURACY: f64 = 0.01;
+
+impl OwnedIconPaths {
+    /// The icon with its outline moved out by `distance` icon units, or in if `distance` is
+    /// negative.
+    ///
+    /// Corners are rounded as they grow, like a stroke with round joins, and parts of the icon
+    /// thinner than twice `-distance` disappear. Like the boolean operations, every path counts
+    /// as ink whatever its opacity, and the result is a single opaque path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::{OwnedIconPaths, ADD};
+    /// let add = OwnedIconPaths::from(ADD);
+    /// // The plus is 14 units across; a halo 2 units wide adds 2 on each side.
+    /// let halo = add.offset(2.).ink_bounds();
+    /// assert!((halo.width() - 18.).abs() < 0.1);
+    /// // Shrinking by more than half the width of the bars leaves nothing.
+    /// assert!(add.offset(-1.5).paths[0].path.elements().is_empty());
+    /// ```
+    pub fn offset(&self, distance: f64) -> OwnedIconPaths {
+        let outline = self.outline();
+        if distance == 0. {
+            return self.with_outline(outline);
+        }
+        // The band within `distance` of the outline, which we add or take away.
+        let options = StrokeOptions::default()
+            .with_accuracy(ACCURACY)
+            .with_join(LineJoin::Round)
+            .with_closed(true)
+            .with_remove_interior_points();
+        let band = outline
+            .iter()
+            .flat_map(|subpath| {
+                stroke_path::<SimpleBezierPath, _>(subpath, 2. * distance.abs(), &options)
+            })
+            .collect::<Vec<_>>();
+        // The bands of nearby subpaths overlap each other.
+        let band = path_remove_interior_points::<_, SimpleBezierPath>(&band, ACCURACY);
+        if distance > 0. {
+            self.with_outline(path_add(&outline, &band, ACCURACY))
+        } else {
+            self.with_outline(path_sub(&outline, &band, ACCURACY))
+        }
+    }
+}
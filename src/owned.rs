@@ -0,0 +1,92 @@
+use crate::{Affine, BezPath, IconPaths, Rect, Shape, Size};
+
+/// An icon whose path data is owned rather than generated, e.g. because it has been transformed.
+///
+/// Create one with [`IconPaths::transformed`], or from an [`IconPaths`] with `From`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedIconPaths {
+    /// The upstream name of the icon this was made from.
+    pub name: &'static str,
+    /// The category of the icon this was made from.
+    pub category: &'static str,
+    /// The style variant of the icon this was made from.
+    pub variant: &'static str,
+    pub paths: Vec<OwnedIconPath>,
+    /// The box the icon is drawn in, which transforms leave alone.
+    pub size: Size,
+}
+
+/// One path of an [`OwnedIconPaths`], filled with the icon's color at `opacity`.
+#[derive(Debug, Clone)]
+pub struct OwnedIconPath {
+    pub path: BezPath,
+    pub opacity: f64,
+}
+
+// `BezPath` is only `PartialEq` from kurbo 0.8, so compare its elements for the `druid-0_7` build.
+impl PartialEq for OwnedIconPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.path.elements() == other.path.elements() && self.opacity == other.opacity
+    }
+}
+
+impl IconPaths {
+    /// A copy of the icon with `transform` applied to its paths.
+    ///
+    /// Transforming paths on every frame adds up, so bake a rotated or mirrored icon once and
+    /// draw the result instead. The icon's [`size`](OwnedIconPaths::size) stays the same, so
+    /// transform about the middle of the box to keep the icon inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid_material_icons::ARROW_FORWARD;
+    /// use kurbo::{Affine, Vec2};
+    /// // Mirror the arrow so that it points back.
+    /// let flip = Affine::translate(Vec2::new(24., 0.)) * Affine::FLIP_X;
+    /// let back = ARROW_FORWARD.transformed(flip);
+    /// let (forward, back) = (ARROW_FORWARD.ink_bounds(), back.ink_bounds());
+    /// assert!((forward.x0 - (24. - back.x1)).abs() < 1e-9);
+    /// ```
+    pub fn transformed(&self, transform: Affine) -> OwnedIconPaths {
+        OwnedIconPaths::from(*self).transformed(transform)
+    }
+}
+
+impl OwnedIconPaths {
+    /// The icon with `transform` applied to its paths.
+    pub fn transformed(mut self, transform: Affine) -> Self {
+        for path in &mut self.paths {
+            path.path.apply_affine(transform);
+        }
+        self
+    }
+
+    /// The smallest rectangle containing all of the icon's paths, in the icon's coordinates.
+    pub fn ink_bounds(&self) -> Rect {
+        self.paths
+            .iter()
+            .map(|path| path.path.bounding_box())
+            .reduce(|bounds, path| bounds.union(path))
+            .unwrap_or_else(|| self.size.to_rect())
+    }
+}
+
+impl From<IconPaths> for OwnedIconPaths {
+    fn from(icon: IconPaths) -> Self {
+        OwnedIconPaths {
+            name: icon.name,
+            category: icon.category,
+            variant: icon.variant,
+            paths: icon
+                .paths
+                .iter()
+                .map(|path| OwnedIconPath {
+                    path: BezPath::from_vec(path.els.to_vec()),
+                    opacity: path.opacity,
+                })
+                .collect(),
+            size: icon.size,
+        }
+    }
+}
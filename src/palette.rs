@@ -0,0 +1,132 @@
+//! The icons in a shape convenient for command palettes, so that apps can suggest an icon for
+//! each action and let users find one by typing.
+//!
+//! Upstream doesn't publish search tags with the icons, so the keywords of an icon are the words
+//! of its name and its category.
+
+use crate::{IconName, ALL};
+
+/// An icon as a palette lists it: its name, a display name like `"Add circle"`, and the
+/// keywords it can be found by.
+pub type Entry = (IconName, String, Vec<&'static str>);
+
+/// How well a search term matches an icon, from best to worst.
+const WHOLE_WORD: u32 = 3;
+const WORD_PREFIX: u32 = 2;
+const CATEGORY_OR_SUBSTRING: u32 = 1;
+/// Extra for a query that is the icon's whole name, so that it always comes first.
+const EXACT_NAME: u32 = 100;
+
+/// Every icon as a palette entry, in name order.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{palette, IconName};
+/// let (name, display_name, keywords) = palette::entries().next().unwrap();
+/// assert_eq!(name, IconName::_10k);
+/// assert_eq!(display_name, "10k");
+/// assert_eq!(keywords, ["10k", "av"]);
+/// ```
+pub fn entries() -> impl Iterator<Item = Entry> {
+    IconName::ALL.iter().map(|&name| entry(name))
+}
+
+/// The palette entry for `name`.
+pub fn entry(name: IconName) -> Entry {
+    (name, display_name(name), keywords(name))
+}
+
+/// The name of the icon as a person would write it, e.g. `"Add circle"` for `add_circle`.
+pub fn display_name(name: IconName) -> String {
+    let mut words = name.as_str().split('_');
+    let mut display = String::new();
+    if let Some(first) = words.next() {
+        let mut chars = first.chars();
+        display.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        display.push_str(chars.as_str());
+    }
+    for word in words {
+        display.push(' ');
+        display.push_str(word);
+    }
+    display
+}
+
+/// The words an icon can be found by: the words of its name, then its category.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{palette, IconName};
+/// assert_eq!(palette::keywords(IconName::AddCircle), ["add", "circle", "content"]);
+/// ```
+pub fn keywords(name: IconName) -> Vec<&'static str> {
+    let mut keywords: Vec<_> = name.as_str().split('_').collect();
+    let category = name.paths().category;
+    if !keywords.contains(&category) {
+        keywords.push(category);
+    }
+    keywords
+}
+
+/// The icons matching `query`, best match first.
+///
+/// Every word of the query has to match the start of a word of the icon's name, its category,
+/// or somewhere in its name. Whole words rank above prefixes, which rank above the rest, and
+/// icons with shorter names come first among equals, as they are usually the more general
+/// symbol.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{palette, IconName};
+/// let results = palette::search("add circle");
+/// assert_eq!(results[0], IconName::AddCircle);
+/// assert!(results.contains(&IconName::AddCircleOutline));
+/// assert_eq!(palette::search("Delete")[0], IconName::Delete);
+/// assert!(palette::search("no such icon").is_empty());
+/// ```
+pub fn search(query: &str) -> Vec<IconName> {
+    let query = query.to_lowercase();
+    let terms: Vec<_> = query
+        .split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|term| !term.is_empty())
+        .collect();
+    if terms.is_empty() {
+        return vec![];
+    }
+    let exact = terms.join("_");
+
+    let mut ranked: Vec<_> = ALL
+        .iter()
+        .zip(IconName::ALL)
+        .filter_map(|((name, icon), &icon_name)| {
+            let mut score = 0;
+            for term in &terms {
+                score += score_term(term, name, icon.category)?;
+            }
+            if *name == exact {
+                score += EXACT_NAME;
+            }
+            let words = name.split('_').count();
+            Some((score, words, icon_name))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+    ranked.into_iter().map(|(.., name)| name).collect()
+}
+
+/// How well `term` matches an icon, or `None` if it doesn't.
+fn score_term(term: &str, name: &str, category: &str) -> Option<u32> {
+    let mut words = name.split('_');
+    if words.clone().any(|word| word == term) {
+        Some(WHOLE_WORD)
+    } else if words.any(|word| word.starts_with(term)) {
+        Some(WORD_PREFIX)
+    } else if category.starts_with(term) || name.contains(term) {
+        Some(CATEGORY_OR_SUBSTRING)
+    } else {
+        None
+    }
+}
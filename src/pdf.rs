@@ -0,0 +1,69 @@
+//! Writing paths as PDF content stream operators.
+//!
+//! The generator includes this file too, for its template PDFs, so it only uses `std`: the two
+//! are built against different versions of kurbo.
+
+use std::fmt::Write;
+
+/// A point in PDF user space, whose y axis points up.
+pub type Point = (f64, f64);
+
+/// A path element, with its points in PDF user space.
+#[derive(Debug, Copy, Clone)]
+pub enum El {
+    MoveTo(Point),
+    LineTo(Point),
+    QuadTo(Point, Point),
+    CurveTo(Point, Point, Point),
+    ClosePath,
+}
+
+/// Append the operators that build the path `els` and fill it with the current fill color.
+pub fn fill_path(out: &mut String, els: impl IntoIterator<Item = El>) {
+    // Where the current subpath started and where it has got to, for raising degrees.
+    let (mut start, mut current) = ((0., 0.), (0., 0.));
+    // Writing to a string can't fail.
+    for el in els {
+        let _ = match el {
+            El::MoveTo(p) => {
+                start = p;
+                current = p;
+                writeln!(out, "{:.3} {:.3} m", p.0, p.1)
+            }
+            El::LineTo(p) => {
+                current = p;
+                writeln!(out, "{:.3} {:.3} l", p.0, p.1)
+            }
+            // PDF only has cubic curves, so raise the degree of quadratic ones.
+            El::QuadTo(p1, p2) => {
+                let c1 = lerp(current, p1, 2. / 3.);
+                let c2 = lerp(p2, p1, 2. / 3.);
+                current = p2;
+                writeln!(
+                    out,
+                    "{:.3} {:.3} {:.3} {:.3} {:.3} {:.3} c",
+                    c1.0, c1.1, c2.0, c2.1, p2.0, p2.1
+                )
+            }
+            El::CurveTo(p1, p2, p3) => {
+                current = p3;
+                writeln!(
+                    out,
+                    "{:.3} {:.3} {:.3} {:.3} {:.3} {:.3} c",
+                    p1.0, p1.1, p2.0, p2.1, p3.0, p3.1
+                )
+            }
+            El::ClosePath => {
+                current = start;
+                writeln!(out, "h")
+            }
+        };
+    }
+    // Fill with the nonzero winding rule, like SVG.
+    out.push_str("f\n");
+}
+
+/// The point `t` of the way from `a` to `b`.
+fn lerp(a: Point, b: Point, t: f64) -> Point {
+    (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1))
+}
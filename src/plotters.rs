@@ -0,0 +1,133 @@
+//! An icon [element](::plotters::element) for [plotters](https://github.com/plotters-rs/plotters)
+//! charts, for marking data points with recognizable glyphs. Build with the `plotters` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use druid_material_icons::{plotters::IconElement, WARNING};
+//! use ::plotters::{coord::types::RangedCoordf64, prelude::*};
+//! fn mark_outliers<DB: DrawingBackend>(
+//!     chart: &mut ChartContext<DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+//!     outliers: &[(f64, f64)],
+//! ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+//!     chart.draw_series(
+//!         outliers
+//!             .iter()
+//!             .map(|&point| IconElement::new(point, WARNING, 16, RED)),
+//!     )?;
+//!     Ok(())
+//! }
+//! ```
+
+use ::plotters::{
+    element::{Drawable, PointCollection},
+    style::{Color, ShapeStyle},
+};
+use kurbo::{flatten, Affine};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use crate::{IconPaths, PathEl, Point};
+
+/// How far the drawn outline may stray from the icon's curves, in backend pixels.
+const TOLERANCE: f64 = 0.25;
+
+/// An icon drawn centered on a data point.
+pub struct IconElement<Coord> {
+    pos: Coord,
+    icon: IconPaths,
+    /// The height of the icon in backend pixels.
+    size: u32,
+    style: ShapeStyle,
+}
+
+impl<Coord> IconElement<Coord> {
+    /// `icon` centered on `pos`, `size` pixels high, filled with the color of `style`.
+    pub fn new<P: Into<Coord>, S: Into<ShapeStyle>>(
+        pos: P,
+        icon: IconPaths,
+        size: u32,
+        style: S,
+    ) -> Self {
+        IconElement {
+            pos: pos.into(),
+            icon,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a IconElement<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for IconElement<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let scale = f64::from(self.size) / self.icon.size.height;
+        for (x, y) in points {
+            let center = Point::new(f64::from(x), f64::from(y));
+            let transform =
+                Affine::translate(center.to_vec2() - self.icon.size.to_vec2() * scale * 0.5)
+                    * Affine::scale(scale);
+            for path in self.icon.paths {
+                let style = ShapeStyle {
+                    color: self.style.color.mix(path.opacity),
+                    filled: true,
+                    stroke_width: 0,
+                };
+                backend.fill_polygon(polygon(path.els, transform), &style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `els` flattened into a single polygon, transformed by `transform`.
+///
+/// Backends only fill simple polygons, so every subpath is joined to the start of the first by a
+/// "bridge" there and back again. The two edges of a bridge cancel out when filling, so holes in
+/// the icon stay holes.
+fn polygon(els: &[PathEl], transform: Affine) -> Vec<BackendCoord> {
+    let mut vertices = vec![];
+    let mut anchor = None;
+    // The start of the current subpath, which we return to before bridging back to the anchor.
+    let mut start = None;
+    flatten(
+        els.iter().map(|el| transform * *el),
+        TOLERANCE,
+        |el| match el {
+            PathEl::MoveTo(point) => {
+                if let Some(start) = start {
+                    vertices.push(to_backend(start));
+                }
+                vertices.push(to_backend(*anchor.get_or_insert(point)));
+                vertices.push(to_backend(point));
+                start = Some(point);
+            }
+            PathEl::LineTo(point) => vertices.push(to_backend(point)),
+            // Subpaths are closed when we bridge back to the anchor.
+            PathEl::ClosePath => (),
+            // Flattening only leaves lines.
+            PathEl::QuadTo(..) | PathEl::CurveTo(..) => unreachable!(),
+        },
+    );
+    if let (Some(start), Some(anchor)) = (start, anchor) {
+        vertices.push(to_backend(start));
+        vertices.push(to_backend(anchor));
+    }
+    vertices
+}
+
+/// `point` rounded to the nearest backend pixel.
+fn to_backend(point: Point) -> BackendCoord {
+    (point.x.round() as i32, point.y.round() as i32)
+}
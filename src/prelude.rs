@@ -0,0 +1,33 @@
+//! The most commonly used parts of the crate, for glob importing.
+//!
+//! This brings in the icon types, the [`theme`] module, and a handful of the icons that appear
+//! in most apps. Everything else is still available from the crate root.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "druid")]
+//! # fn main() {
+//! # use druid::{widget::Flex, Color, Widget, WidgetExt};
+//! use druid_material_icons::prelude::*;
+//! fn toolbar() -> impl Widget<()> {
+//!     Flex::row()
+//!         .with_child(MENU.new(Color::WHITE))
+//!         .with_flex_spacer(1.)
+//!         .with_child(SEARCH.new(Color::WHITE))
+//!         .with_child(MORE_VERT.new(Color::WHITE))
+//! }
+//! # }
+//! # #[cfg(not(feature = "druid"))]
+//! # fn main() {}
+//! ```
+
+#[cfg(feature = "druid")]
+pub use crate::{theme, Icon, IconButton, IconButtonStyle};
+pub use crate::{IconName, IconPaths};
+
+#[doc(no_inline)]
+pub use crate::{
+    ADD, ARROW_BACK, ARROW_FORWARD, CHECK, CLOSE, DELETE, EDIT, FAVORITE, HOME, INFO, MENU,
+    MORE_VERT, REFRESH, SEARCH, SETTINGS, SHARE,
+};
@@ -0,0 +1,73 @@
+//! Conversion of icons to [raqote](https://github.com/jrmuizel/raqote) paths, for software
+//! rendering without piet. Build with the `raqote` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use druid_material_icons::{raqote, ADD};
+//! use kurbo::Rect;
+//! use ::raqote::{DrawTarget, SolidSource};
+//!
+//! let mut dt = DrawTarget::new(48, 48);
+//! let black = SolidSource::from_unpremultiplied_argb(0xff, 0, 0, 0);
+//! raqote::fill(&mut dt, &ADD, Rect::new(0., 0., 48., 48.), black);
+//! // The middle of the plus is painted, and the corners aren't.
+//! assert_eq!(dt.get_data()[24 * 48 + 24] >> 24, 0xff);
+//! assert_eq!(dt.get_data()[0], 0);
+//! ```
+
+use ::raqote::{
+    DrawOptions, DrawTarget, Path, PathOp, Point as RPoint, SolidSource, Source, Winding,
+};
+use kurbo::Affine;
+
+use crate::{IconPath, IconPaths, PathEl, Point, Rect};
+
+/// Each path of `icon` in the icon's own coordinates, with the opacity to fill it at.
+pub fn to_paths(icon: &IconPaths) -> Vec<(Path, f32)> {
+    icon.paths
+        .iter()
+        .map(|path| (to_path(path, Affine::IDENTITY), path.opacity as f32))
+        .collect()
+}
+
+/// Fill `icon` in `color` on `dt`, stretched to fill `rect`.
+pub fn fill(dt: &mut DrawTarget, icon: &IconPaths, rect: Rect, color: SolidSource) {
+    let transform = Affine::translate(rect.origin().to_vec2())
+        * Affine::scale_non_uniform(
+            rect.width() / icon.size.width,
+            rect.height() / icon.size.height,
+        );
+    let source = Source::Solid(color);
+    for path in icon.paths {
+        let options = DrawOptions {
+            alpha: path.opacity as f32,
+            ..DrawOptions::new()
+        };
+        dt.fill(&to_path(path, transform), &source, &options);
+    }
+}
+
+/// `path` transformed by `transform`, as a raqote path.
+fn to_path(path: &IconPath, transform: Affine) -> Path {
+    let point = |p: Point| {
+        let p = transform * p;
+        RPoint::new(p.x as f32, p.y as f32)
+    };
+    let ops = path
+        .els
+        .iter()
+        .map(|el| match *el {
+            PathEl::MoveTo(p) => PathOp::MoveTo(point(p)),
+            PathEl::LineTo(p) => PathOp::LineTo(point(p)),
+            PathEl::QuadTo(p1, p2) => PathOp::QuadTo(point(p1), point(p2)),
+            PathEl::CurveTo(p1, p2, p3) => PathOp::CubicTo(point(p1), point(p2), point(p3)),
+            PathEl::ClosePath => PathOp::Close,
+        })
+        .collect();
+    // Material icons are drawn for the nonzero fill rule, like SVG's default.
+    Path {
+        ops,
+        winding: Winding::NonZero,
+    }
+}
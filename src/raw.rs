@@ -0,0 +1,240 @@
+//! Icon path data as flat arrays of plain old data, for memory-mapping icons from a file or
+//! uploading them to the GPU without converting each element. Build with the `raw` feature.
+//!
+//! Every type here is `#[repr(C)]` and [`Pod`](bytemuck::Pod), made only of 4-byte fields, so
+//! slices of them can be cast to and from bytes with `bytemuck`. `generate-icons raw` writes the
+//! icons in this layout, either as Rust statics or as a binary file for [`RawData::from_bytes`].
+//!
+//! The binary layout is a sequence of little-endian `u32` words:
+//!
+//! 1. The header: [`MAGIC`], then the number of icons, paths and elements.
+//! 2. A [`RawIcon`] for each icon.
+//! 3. A [`RawPath`] for each path.
+//! 4. A [`RawEl`] for each element.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{BezPath, Error, IconPaths, PathEl, Point};
+
+/// The first word of the binary layout, `"MIR1"` in little-endian ASCII.
+pub const MAGIC: u32 = u32::from_le_bytes(*b"MIR1");
+
+/// One path element: an operation and the coordinates of its points.
+///
+/// Unused coordinates are zero, so every element is the same size.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default, Pod, Zeroable)]
+pub struct RawEl {
+    /// One of [`MOVE_TO`](RawEl::MOVE_TO), [`LINE_TO`](RawEl::LINE_TO),
+    /// [`QUAD_TO`](RawEl::QUAD_TO), [`CURVE_TO`](RawEl::CURVE_TO) or
+    /// [`CLOSE_PATH`](RawEl::CLOSE_PATH).
+    pub op: u32,
+    /// The `x` and `y` of up to three points.
+    pub coords: [f32; 6],
+}
+
+/// A path of an icon: a range of [`RawEl`]s filled at `opacity`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default, Pod, Zeroable)]
+pub struct RawPath {
+    /// The index of the path's first element.
+    pub first_el: u32,
+    pub el_count: u32,
+    pub opacity: f32,
+}
+
+/// An icon: a range of [`RawPath`]s drawn in a `size` by `size` box.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default, Pod, Zeroable)]
+pub struct RawIcon {
+    /// The index of the icon's first path.
+    pub first_path: u32,
+    pub path_count: u32,
+    pub size: f32,
+}
+
+impl RawEl {
+    pub const MOVE_TO: u32 = 0;
+    pub const LINE_TO: u32 = 1;
+    pub const QUAD_TO: u32 = 2;
+    pub const CURVE_TO: u32 = 3;
+    pub const CLOSE_PATH: u32 = 4;
+
+    /// `el` in the raw layout, with its coordinates rounded to `f32`.
+    pub fn from_path_el(el: PathEl) -> Self {
+        let (op, points) = match el {
+            PathEl::MoveTo(p) => (Self::MOVE_TO, [p, Point::ZERO, Point::ZERO]),
+            PathEl::LineTo(p) => (Self::LINE_TO, [p, Point::ZERO, Point::ZERO]),
+            PathEl::QuadTo(p1, p2) => (Self::QUAD_TO, [p1, p2, Point::ZERO]),
+            PathEl::CurveTo(p1, p2, p3) => (Self::CURVE_TO, [p1, p2, p3]),
+            PathEl::ClosePath => (Self::CLOSE_PATH, [Point::ZERO; 3]),
+        };
+        let mut coords = [0.; 6];
+        for (coords, point) in coords.chunks_exact_mut(2).zip(points) {
+            coords[0] = point.x as f32;
+            coords[1] = point.y as f32;
+        }
+        RawEl { op, coords }
+    }
+
+    /// The element as a kurbo path element, or `None` if `op` isn't a known operation.
+    pub fn to_path_el(self) -> Option<PathEl> {
+        let point = |idx: usize| {
+            Point::new(
+                f64::from(self.coords[2 * idx]),
+                f64::from(self.coords[2 * idx + 1]),
+            )
+        };
+        Some(match self.op {
+            Self::MOVE_TO => PathEl::MoveTo(point(0)),
+            Self::LINE_TO => PathEl::LineTo(point(0)),
+            Self::QUAD_TO => PathEl::QuadTo(point(0), point(1)),
+            Self::CURVE_TO => PathEl::CurveTo(point(0), point(1), point(2)),
+            Self::CLOSE_PATH => PathEl::ClosePath,
+            _ => return None,
+        })
+    }
+}
+
+/// Icons in the raw layout, borrowed from a buffer.
+#[derive(Debug, Copy, Clone)]
+pub struct RawData<'a> {
+    pub icons: &'a [RawIcon],
+    pub paths: &'a [RawPath],
+    pub els: &'a [RawEl],
+}
+
+/// An [`Error::DecodeError`] explaining why bytes couldn't be read as [`RawData`].
+fn decode_error(reason: &str) -> Error {
+    Error::DecodeError(format!("raw icon data {}", reason))
+}
+
+impl<'a> RawData<'a> {
+    /// Read icons in the binary layout from `bytes`, without copying.
+    ///
+    /// `bytes` must be aligned to 4 bytes, which memory-mapped files and buffers cast from
+    /// `&[u32]` always are. The ranges are checked, so indexing with them can't go out of
+    /// bounds.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
+        let words: &[u32] = bytemuck::try_cast_slice(bytes).map_err(|err| match err {
+            bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned => {
+                decode_error("is not aligned to 4 bytes")
+            }
+            _ => decode_error("is truncated"),
+        })?;
+        let (header, rest) = words.split_at(4.min(words.len()));
+        let (icons, paths, els) = match *header {
+            [MAGIC, icons, paths, els] => (icons as usize, paths as usize, els as usize),
+            [_, _, _, _] => return Err(decode_error("doesn't start with the magic number")),
+            _ => return Err(decode_error("is truncated")),
+        };
+        let (icons, rest) = take::<RawIcon>(rest, icons)?;
+        let (paths, rest) = take::<RawPath>(rest, paths)?;
+        let (els, _) = take::<RawEl>(rest, els)?;
+        let data = RawData { icons, paths, els };
+        data.check()?;
+        Ok(data)
+    }
+
+    /// The paths of the icon at `idx`, each as its opacity and elements.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is out of bounds.
+    pub fn icon_paths(&self, idx: usize) -> impl Iterator<Item = (f32, &'a [RawEl])> + 'a {
+        let icon = self.icons[idx];
+        let (paths, els) = (self.paths, self.els);
+        let first = icon.first_path as usize;
+        paths[first..first + icon.path_count as usize]
+            .iter()
+            .map(move |path| {
+                let first = path.first_el as usize;
+                (path.opacity, &els[first..first + path.el_count as usize])
+            })
+    }
+
+    /// Check that the ranges are within the tables they index.
+    fn check(&self) -> Result<(), Error> {
+        let in_range = |first: u32, count: u32, len: usize| {
+            (first as usize)
+                .checked_add(count as usize)
+                .is_some_and(|end| end <= len)
+        };
+        let icons_ok = self
+            .icons
+            .iter()
+            .all(|icon| in_range(icon.first_path, icon.path_count, self.paths.len()));
+        let paths_ok = self
+            .paths
+            .iter()
+            .all(|path| in_range(path.first_el, path.el_count, self.els.len()));
+        if icons_ok && paths_ok {
+            Ok(())
+        } else {
+            Err(decode_error("has a range out of bounds"))
+        }
+    }
+}
+
+/// Cast the first `count` `T`s from `words`, returning them and the rest.
+fn take<T: Pod>(words: &[u32], count: usize) -> Result<(&[T], &[u32]), Error> {
+    let len = count
+        .checked_mul(std::mem::size_of::<T>() / 4)
+        .ok_or_else(|| decode_error("is truncated"))?;
+    if len > words.len() {
+        return Err(decode_error("is truncated"));
+    }
+    let (taken, rest) = words.split_at(len);
+    Ok((bytemuck::cast_slice(taken), rest))
+}
+
+/// Write `icons` in the binary layout, as words so that the buffer is aligned.
+///
+/// Cast the result with `bytemuck::cast_slice` to get bytes, and write them out little-endian.
+///
+/// # Examples
+///
+/// ```
+/// use druid_material_icons::{raw, ADD, DELETE};
+/// let words = raw::encode(&[ADD, DELETE]);
+/// let data = raw::RawData::from_bytes(bytemuck::cast_slice(&words)).unwrap();
+/// assert_eq!(data.icons.len(), 2);
+/// let (opacity, els) = data.icon_paths(0).next().unwrap();
+/// assert_eq!(opacity, 1.);
+/// assert_eq!(els.len(), ADD.paths[0].els.len());
+/// ```
+pub fn encode(icons: &[IconPaths]) -> Vec<u32> {
+    let mut raw_icons = vec![];
+    let mut raw_paths = vec![];
+    let mut raw_els = vec![];
+    for icon in icons {
+        raw_icons.push(RawIcon {
+            first_path: raw_paths.len() as u32,
+            path_count: icon.paths.len() as u32,
+            size: icon.size.width as f32,
+        });
+        for path in icon.paths {
+            raw_paths.push(RawPath {
+                first_el: raw_els.len() as u32,
+                el_count: path.els.len() as u32,
+                opacity: path.opacity as f32,
+            });
+            raw_els.extend(path.els.iter().map(|&el| RawEl::from_path_el(el)));
+        }
+    }
+    let mut words = vec![
+        MAGIC,
+        raw_icons.len() as u32,
+        raw_paths.len() as u32,
+        raw_els.len() as u32,
+    ];
+    words.extend_from_slice(bytemuck::cast_slice(&raw_icons));
+    words.extend_from_slice(bytemuck::cast_slice(&raw_paths));
+    words.extend_from_slice(bytemuck::cast_slice(&raw_els));
+    words
+}
+
+/// `els` as a kurbo path, skipping elements with unknown operations.
+pub fn to_bez_path(els: &[RawEl]) -> BezPath {
+    BezPath::from_vec(els.iter().filter_map(|el| el.to_path_el()).collect())
+}
@@ -0,0 +1,89 @@
+//! Signed distance fields of icons, for GPU renderers that draw icons from distance fields.
+//!
+//! A distance field scales far better than a bitmap of the same size, and outlines and glows
+//! come almost for free in the shader. Build with the `sdf` feature.
+
+use crate::{Affine, BezPath, IconPaths, PathEl, Point, Shape};
+
+/// How far the flattened outline may stray from the curves, in pixels.
+const TOLERANCE: f64 = 0.05;
+
+impl IconPaths {
+    /// A signed distance field of the icon stretched to `width` by `height` pixels, row by row.
+    ///
+    /// Each byte is the distance from the middle of the pixel to the icon's outline, mapped so
+    /// that 128 is on the outline, 255 is `spread` pixels or more inside and 0 is `spread`
+    /// pixels or more outside. Translucent paths count as ink. Leave `spread` pixels of padding
+    /// around the glyph when picking a size if you want outlines or glows to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let sdf = druid_material_icons::ADD.to_sdf(32, 32, 4.);
+    /// // Inside the plus, and far outside it.
+    /// assert!(sdf[16 * 32 + 16] > 128);
+    /// assert_eq!(sdf[0], 0);
+    /// ```
+    pub fn to_sdf(&self, width: usize, height: usize, spread: f64) -> Vec<u8> {
+        let transform = Affine::scale_non_uniform(
+            width as f64 / self.size.width,
+            height as f64 / self.size.height,
+        );
+        let inverse = transform.inverse();
+        let segments = self.segments(transform);
+        let mut sdf = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let center = Point::new(x as f64 + 0.5, y as f64 + 0.5);
+                let distance = segments
+                    .iter()
+                    .map(|&(start, end)| distance_to_segment(center, start, end))
+                    .fold(f64::INFINITY, f64::min);
+                let icon_point = inverse * center;
+                let inside = self.paths.iter().any(|path| path.winding(icon_point) != 0);
+                let signed = if inside { distance } else { -distance };
+                let value = 127.5 + 127.5 * (signed / spread).clamp(-1., 1.);
+                sdf.push(value.round() as u8);
+            }
+        }
+        sdf
+    }
+
+    /// The outline of every path as line segments, transformed by `transform`.
+    fn segments(&self, transform: Affine) -> Vec<(Point, Point)> {
+        let mut segments = vec![];
+        for path in self.paths {
+            let mut bez = BezPath::from_vec(path.els.to_vec());
+            bez.apply_affine(transform);
+            let (mut start, mut current) = (Point::ZERO, Point::ZERO);
+            bez.flatten(TOLERANCE, |el| match el {
+                PathEl::MoveTo(point) => {
+                    start = point;
+                    current = point;
+                }
+                PathEl::LineTo(point) => {
+                    segments.push((current, point));
+                    current = point;
+                }
+                PathEl::ClosePath => {
+                    segments.push((current, start));
+                    current = start;
+                }
+                // Flattening only leaves lines.
+                PathEl::QuadTo(..) | PathEl::CurveTo(..) => unreachable!(),
+            });
+        }
+        segments
+    }
+}
+
+/// The distance from `point` to the closest point on the segment from `start` to `end`.
+fn distance_to_segment(point: Point, start: Point, end: Point) -> f64 {
+    let along = end - start;
+    let length_squared = along.hypot2();
+    if length_squared == 0. {
+        return point.distance(start);
+    }
+    let t = ((point - start).dot(along) / length_squared).clamp(0., 1.);
+    point.distance(start + along * t)
+}
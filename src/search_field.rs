@@ -0,0 +1,162 @@
+use druid::{
+    kurbo::Size,
+    theme::TEXTBOX_INSETS,
+    widget::{prelude::*, TextBox},
+    Color, Point, Rect, WidgetPod,
+};
+
+use crate::{theme, Icon, IconButton, CLEAR, SEARCH};
+
+/// The size of the leading search icon.
+const ICON_SIZE: f64 = 20.;
+/// The size of the trailing clear button, leaving room around its icon for the state layer.
+const CLEAR_SIZE: f64 = 28.;
+/// The space between the leading icon and the text.
+const GAP: f64 = 4.;
+
+/// A [`TextBox`] with a [`SEARCH`] icon at the start and a [`CLEAR`] button at the end.
+///
+/// The clear button only shows when there is text to clear, and clicking it empties the text
+/// and focuses the text box, as does clicking the search icon. The text is inset to make room
+/// for both, on top of the [`TEXTBOX_INSETS`] from the environment.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::Widget;
+/// use druid::widget::TextBox;
+/// use druid_material_icons::SearchField;
+/// fn filter() -> impl Widget<String> {
+///     SearchField::new(TextBox::new().with_placeholder("Filter icons"))
+/// }
+/// ```
+pub struct SearchField {
+    icon: WidgetPod<(), Icon>,
+    text_box: WidgetPod<String, TextBox<String>>,
+    clear: WidgetPod<String, IconButton<String>>,
+}
+
+impl SearchField {
+    /// Decorate `text_box` with the search icon and clear button.
+    pub fn new(text_box: TextBox<String>) -> Self {
+        let text_box = WidgetPod::new(text_box);
+        let text_box_id = text_box.id();
+        let clear = IconButton::new(CLEAR).on_click(move |ctx, text: &mut String, _env| {
+            text.clear();
+            ctx.set_focus(text_box_id);
+        });
+        SearchField {
+            // The color is set from the theme when painting.
+            icon: WidgetPod::new(Icon::new(SEARCH, Color::BLACK)),
+            text_box,
+            clear: WidgetPod::new(clear),
+        }
+    }
+
+    /// The decorated text box, for configuring it.
+    #[inline]
+    pub fn text_box_mut(&mut self) -> &mut TextBox<String> {
+        self.text_box.widget_mut()
+    }
+
+    /// The environment for the text box, with its insets widened to make room for the icons.
+    fn text_box_env(env: &Env) -> Env {
+        let mut insets = env.get(TEXTBOX_INSETS);
+        insets.x0 += ICON_SIZE + GAP;
+        insets.x1 += CLEAR_SIZE;
+        env.clone().adding(TEXTBOX_INSETS, insets)
+    }
+}
+
+impl Default for SearchField {
+    fn default() -> Self {
+        SearchField::new(TextBox::new().with_placeholder("Search"))
+    }
+}
+
+impl Widget<String> for SearchField {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut String, env: &Env) {
+        let showing_clear = !data.is_empty();
+        let over = |rect: Rect| match event {
+            Event::MouseDown(mouse) | Event::MouseUp(mouse) => rect.contains(mouse.pos),
+            _ => false,
+        };
+        if let Event::MouseDown(_) = event {
+            if over(self.icon.layout_rect()) && !ctx.is_disabled() {
+                ctx.set_focus(self.text_box.id());
+                ctx.set_handled();
+                return;
+            }
+        }
+        if showing_clear {
+            self.clear.event(ctx, event, data, env);
+        }
+        // Clicks on the clear button shouldn't also move the cursor.
+        let for_clear = showing_clear && (over(self.clear.layout_rect()) || self.clear.is_active());
+        if !for_clear {
+            self.text_box
+                .event(ctx, event, data, &Self::text_box_env(env));
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &String, env: &Env) {
+        self.icon.lifecycle(ctx, event, &(), env);
+        self.text_box
+            .lifecycle(ctx, event, data, &Self::text_box_env(env));
+        self.clear.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &String, data: &String, env: &Env) {
+        if old_data.is_empty() != data.is_empty() {
+            ctx.request_paint();
+        }
+        if ctx.env_changed() {
+            ctx.request_paint();
+        }
+        self.icon.update(ctx, &(), env);
+        self.text_box.update(ctx, data, &Self::text_box_env(env));
+        self.clear.update(ctx, data, env);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &String,
+        env: &Env,
+    ) -> Size {
+        let size = self
+            .text_box
+            .layout(ctx, bc, data, &Self::text_box_env(env));
+        self.text_box.set_origin(ctx, Point::ORIGIN);
+        ctx.set_baseline_offset(self.text_box.baseline_offset());
+
+        let insets = env.get(TEXTBOX_INSETS);
+        let icon_bc = BoxConstraints::tight(Size::new(ICON_SIZE, ICON_SIZE));
+        let icon_size = self.icon.layout(ctx, &icon_bc, &(), env);
+        let icon_origin = Point::new(insets.x0, 0.5 * (size.height - icon_size.height));
+        self.icon.set_origin(ctx, icon_origin);
+
+        let clear_size = CLEAR_SIZE.min(size.height);
+        let clear_bc = BoxConstraints::tight(Size::new(clear_size, clear_size));
+        let clear_size = self.clear.layout(ctx, &clear_bc, data, env);
+        let clear_origin = Point::new(
+            size.width - insets.x1 - clear_size.width,
+            0.5 * (size.height - clear_size.height),
+        );
+        self.clear.set_origin(ctx, clear_origin);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &String, env: &Env) {
+        self.text_box.paint(ctx, data, &Self::text_box_env(env));
+
+        let defaults = &theme::MATERIAL3_SCHEME;
+        let content = theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant);
+        self.icon.widget_mut().set_color(content);
+        self.icon.paint(ctx, &(), env);
+        if !data.is_empty() {
+            self.clear.paint(ctx, data, env);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! The Material state layer: a translucent overlay of a widget's content color that shows
+//! whether it is hovered, focused or pressed.
+
+use druid::{kurbo::Shape, widget::prelude::*, Color};
+
+use crate::theme;
+
+/// The opacity of the state layer for the widget being painted, or 0 if it is idle.
+///
+/// Pressed takes precedence over focused, which takes precedence over hovered.
+pub(crate) fn opacity(ctx: &PaintCtx, env: &Env) -> f64 {
+    let (key, default) = if ctx.is_disabled() {
+        return 0.;
+    } else if ctx.is_active() {
+        (theme::PRESSED_OPACITY, theme::DEFAULT_PRESSED_OPACITY)
+    } else if ctx.has_focus() {
+        (theme::FOCUS_OPACITY, theme::DEFAULT_FOCUS_OPACITY)
+    } else if ctx.is_hot() {
+        (theme::HOVER_OPACITY, theme::DEFAULT_HOVER_OPACITY)
+    } else {
+        return 0.;
+    };
+    env.try_get(key).unwrap_or(default)
+}
+
+/// Fill `shape` with the state layer for the widget being painted, using its content color.
+pub(crate) fn paint(ctx: &mut PaintCtx, shape: impl Shape, content: Color, env: &Env) {
+    let opacity = opacity(ctx, env);
+    if opacity > 0. {
+        ctx.fill(shape, &content.with_alpha(opacity));
+    }
+}
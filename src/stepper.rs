@@ -0,0 +1,297 @@
+use std::{fmt::Display, time::Duration};
+
+use druid::{
+    kurbo::Size,
+    widget::{prelude::*, DisabledIf, Label},
+    Data, Point, TimerToken, WidgetPod,
+};
+
+use crate::{IconButton, ADD, REMOVE};
+
+/// The narrowest the value display gets, so the buttons don't jump about as the value changes.
+const VALUE_WIDTH: f64 = 48.;
+/// How long a button is held before it starts repeating.
+const REPEAT_DELAY: Duration = Duration::from_millis(500);
+/// The time between the first repeats, which shrinks by `REPEAT_ACCELERATION` each time down to
+/// `REPEAT_MIN_INTERVAL`.
+const REPEAT_INTERVAL: Duration = Duration::from_millis(150);
+const REPEAT_MIN_INTERVAL: Duration = Duration::from_millis(25);
+const REPEAT_ACCELERATION: f64 = 0.85;
+
+/// A number that a [`Stepper`] can step through.
+pub trait StepperValue: Data + Copy + PartialOrd + Display {
+    /// The default step.
+    const ONE: Self;
+    /// The default range.
+    const MIN: Self;
+    const MAX: Self;
+
+    /// `self + step`, without overflowing.
+    fn saturating_add(self, step: Self) -> Self;
+    /// `self - step`, without overflowing.
+    fn saturating_sub(self, step: Self) -> Self;
+}
+
+impl StepperValue for i64 {
+    const ONE: Self = 1;
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
+
+    fn saturating_add(self, step: Self) -> Self {
+        i64::saturating_add(self, step)
+    }
+
+    fn saturating_sub(self, step: Self) -> Self {
+        i64::saturating_sub(self, step)
+    }
+}
+
+impl StepperValue for f64 {
+    const ONE: Self = 1.;
+    const MIN: Self = f64::MIN;
+    const MAX: Self = f64::MAX;
+
+    fn saturating_add(self, step: Self) -> Self {
+        self + step
+    }
+
+    fn saturating_sub(self, step: Self) -> Self {
+        self - step
+    }
+}
+
+/// A number between [`REMOVE`] and [`ADD`] buttons that step it down and up.
+///
+/// Holding a button down repeats the step, faster the longer it is held. Each button is
+/// disabled once the value reaches that end of the range.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::Widget;
+/// use druid_material_icons::Stepper;
+/// fn quantity() -> impl Widget<i64> {
+///     Stepper::new().with_range(1, 99)
+/// }
+///
+/// fn volume() -> impl Widget<f64> {
+///     Stepper::new()
+///         .with_range(0., 1.)
+///         .with_step(0.1)
+///         .with_format(|volume| format!("{:.0}%", volume * 100.))
+/// }
+/// ```
+pub struct Stepper<T> {
+    decrement: WidgetPod<T, DisabledIf<T, IconButton<T>>>,
+    value: WidgetPod<T, Label<T>>,
+    increment: WidgetPod<T, DisabledIf<T, IconButton<T>>>,
+    min: T,
+    max: T,
+    step: T,
+    held: Option<Held>,
+}
+
+/// A button being held down.
+struct Held {
+    increment: bool,
+    repeats: i32,
+    timer: TimerToken,
+}
+
+impl<T: StepperValue> Stepper<T> {
+    /// A stepper over the whole range of `T`, stepping by 1.
+    pub fn new() -> Self {
+        Stepper {
+            decrement: decrement_button(T::MIN),
+            value: WidgetPod::new(Label::new(|value: &T, _env: &Env| value.to_string())),
+            increment: increment_button(T::MAX),
+            min: T::MIN,
+            max: T::MAX,
+            step: T::ONE,
+            held: None,
+        }
+    }
+
+    /// Builder-style method to set the lowest and highest values the buttons step to.
+    ///
+    /// Values outside the range that come from elsewhere are left alone until a button is
+    /// pressed.
+    pub fn with_range(mut self, min: T, max: T) -> Self {
+        self.set_range(min, max);
+        self
+    }
+
+    /// Builder-style method to set how far each press steps.
+    #[inline]
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Builder-style method to set how the value is shown.
+    pub fn with_format(mut self, format: impl Fn(&T) -> String + 'static) -> Self {
+        self.value = WidgetPod::new(Label::new(move |value: &T, _env: &Env| format(value)));
+        self
+    }
+
+    /// Set the lowest and highest values the buttons step to.
+    ///
+    /// This rebuilds the buttons, so call it before the stepper is added to the widget tree.
+    pub fn set_range(&mut self, min: T, max: T) {
+        self.min = min;
+        self.max = max;
+        self.decrement = decrement_button(min);
+        self.increment = increment_button(max);
+    }
+
+    /// Step `value` once, returning whether it moved.
+    fn step(&self, increment: bool, value: &mut T) -> bool {
+        let stepped = if increment {
+            if *value >= self.max {
+                return false;
+            }
+            value.saturating_add(self.step)
+        } else {
+            if *value <= self.min {
+                return false;
+            }
+            value.saturating_sub(self.step)
+        };
+        // `PartialOrd` has no `clamp`.
+        *value = if stepped > self.max {
+            self.max
+        } else if stepped < self.min {
+            self.min
+        } else {
+            stepped
+        };
+        true
+    }
+}
+
+/// A button that steps down, disabled at `min`.
+fn decrement_button<T: StepperValue>(min: T) -> WidgetPod<T, DisabledIf<T, IconButton<T>>> {
+    WidgetPod::new(DisabledIf::new(
+        IconButton::new(REMOVE),
+        move |value, _env| *value <= min,
+    ))
+}
+
+/// A button that steps up, disabled at `max`.
+fn increment_button<T: StepperValue>(max: T) -> WidgetPod<T, DisabledIf<T, IconButton<T>>> {
+    WidgetPod::new(DisabledIf::new(IconButton::new(ADD), move |value, _env| {
+        *value >= max
+    }))
+}
+
+impl<T: StepperValue> Default for Stepper<T> {
+    fn default() -> Self {
+        Stepper::new()
+    }
+}
+
+impl<T: StepperValue> Widget<T> for Stepper<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(mouse) if !ctx.is_disabled() => {
+                let increment = if self.increment.layout_rect().contains(mouse.pos) {
+                    Some(true)
+                } else if self.decrement.layout_rect().contains(mouse.pos) {
+                    Some(false)
+                } else {
+                    None
+                };
+                if let Some(increment) = increment {
+                    ctx.set_active(true);
+                    if self.step(increment, data) {
+                        self.held = Some(Held {
+                            increment,
+                            repeats: 0,
+                            timer: ctx.request_timer(REPEAT_DELAY),
+                        });
+                    }
+                }
+            }
+            Event::MouseUp(_) if ctx.is_active() => {
+                ctx.set_active(false);
+                self.held = None;
+            }
+            Event::Timer(token) => {
+                if let Some(held) = &mut self.held {
+                    if *token == held.timer {
+                        let increment = held.increment;
+                        let interval = REPEAT_INTERVAL
+                            .mul_f64(REPEAT_ACCELERATION.powi(held.repeats))
+                            .max(REPEAT_MIN_INTERVAL);
+                        held.repeats = held.repeats.saturating_add(1);
+                        held.timer = ctx.request_timer(interval);
+                        if !self.step(increment, data) {
+                            self.held = None;
+                        }
+                        ctx.set_handled();
+                        return;
+                    }
+                }
+            }
+            _ => (),
+        }
+        // The buttons are only told about events so they can show their state layers; the
+        // stepping happens here, so that it can repeat.
+        self.decrement.event(ctx, event, data, env);
+        self.value.event(ctx, event, data, env);
+        self.increment.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::DisabledChanged(true) = event {
+            self.held = None;
+        }
+        self.decrement.lifecycle(ctx, event, data, env);
+        self.value.lifecycle(ctx, event, data, env);
+        self.increment.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.decrement.update(ctx, data, env);
+        self.value.update(ctx, data, env);
+        self.increment.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let loose = bc.loosen();
+        let decrement = self.decrement.layout(ctx, &loose, data, env);
+        let value = self.value.layout(ctx, &loose, data, env);
+        let increment = self.increment.layout(ctx, &loose, data, env);
+
+        let value_width = value.width.max(VALUE_WIDTH);
+        let height = decrement.height.max(value.height).max(increment.height);
+        let size = bc.constrain(Size::new(
+            decrement.width + value_width + increment.width,
+            height,
+        ));
+        let middle = |child: Size| 0.5 * (size.height - child.height);
+        self.decrement
+            .set_origin(ctx, Point::new(0., middle(decrement)));
+        self.value.set_origin(
+            ctx,
+            Point::new(
+                decrement.width + 0.5 * (value_width - value.width),
+                middle(value),
+            ),
+        );
+        self.increment.set_origin(
+            ctx,
+            Point::new(size.width - increment.width, middle(increment)),
+        );
+        ctx.set_baseline_offset(
+            self.value.baseline_offset() + size.height - middle(value) - value.height,
+        );
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.decrement.paint(ctx, data, env);
+        self.value.paint(ctx, data, env);
+        self.increment.paint(ctx, data, env);
+    }
+}
@@ -0,0 +1,340 @@
+//! Symbols that core Material doesn't have but apps often ask for: weather, currencies and arrows.
+//! Build with the `symbols_extra` feature.
+//!
+//! These icons are community-maintained rather than from upstream. They are drawn on the same
+//! 24 by 24 grid as the material icons, from the SVGs in `generate-icons/packs/symbols_extra`, and
+//! contributions of further symbols are welcome there.
+//!
+//! Generated by `generate-icons pack`; don't edit by hand.
+
+use crate::{IconPath, IconPaths, PathEl, Point, Size};
+
+pub const ARROW_LEFT_RIGHT: IconPaths = IconPaths {
+    name: "arrow_left_right",
+    category: "symbols_extra",
+    variant: "symbols_extra",
+    paths: &[IconPath {
+        els: &[
+            PathEl::MoveTo(Point { x: 2.00, y: 12.00 }),
+            PathEl::LineTo(Point { x: 7.00, y: 7.00 }),
+            PathEl::LineTo(Point { x: 7.00, y: 11.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 11.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 7.00 }),
+            PathEl::LineTo(Point { x: 22.00, y: 12.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 17.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 13.00 }),
+            PathEl::LineTo(Point { x: 7.00, y: 13.00 }),
+            PathEl::LineTo(Point { x: 7.00, y: 17.00 }),
+            PathEl::ClosePath,
+        ],
+        opacity: 1.00,
+    }],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 12,
+};
+
+pub const ARROW_UP_DOWN: IconPaths = IconPaths {
+    name: "arrow_up_down",
+    category: "symbols_extra",
+    variant: "symbols_extra",
+    paths: &[IconPath {
+        els: &[
+            PathEl::MoveTo(Point { x: 12.00, y: 2.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 7.00 }),
+            PathEl::LineTo(Point { x: 13.00, y: 7.00 }),
+            PathEl::LineTo(Point { x: 13.00, y: 17.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 17.00 }),
+            PathEl::LineTo(Point { x: 12.00, y: 22.00 }),
+            PathEl::LineTo(Point { x: 7.00, y: 17.00 }),
+            PathEl::LineTo(Point { x: 11.00, y: 17.00 }),
+            PathEl::LineTo(Point { x: 11.00, y: 7.00 }),
+            PathEl::LineTo(Point { x: 7.00, y: 7.00 }),
+            PathEl::ClosePath,
+        ],
+        opacity: 1.00,
+    }],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 12,
+};
+
+pub const CLEAR_NIGHT: IconPaths = IconPaths {
+    name: "clear_night",
+    category: "symbols_extra",
+    variant: "symbols_extra",
+    paths: &[IconPath {
+        els: &[
+            PathEl::MoveTo(Point { x: 20.78, y: 13.98 }),
+            PathEl::CurveTo(
+                Point { x: 19.77, y: 18.45 },
+                Point { x: 15.57, y: 21.44 },
+                Point { x: 11.02, y: 20.95 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 6.47, y: 20.45 },
+                Point { x: 3.02, y: 16.61 },
+                Point { x: 3.00, y: 12.03 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 2.98, y: 7.45 },
+                Point { x: 6.41, y: 3.59 },
+                Point { x: 10.95, y: 3.06 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 8.85, y: 5.93 },
+                Point { x: 9.05, y: 9.87 },
+                Point { x: 11.43, y: 12.52 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 13.80, y: 15.16 },
+                Point { x: 17.71, y: 15.77 },
+                Point { x: 20.78, y: 13.98 },
+            ),
+            PathEl::ClosePath,
+        ],
+        opacity: 1.00,
+    }],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 18,
+};
+
+pub const CURRENCY_CENT: IconPaths = IconPaths {
+    name: "currency_cent",
+    category: "symbols_extra",
+    variant: "symbols_extra",
+    paths: &[IconPath {
+        els: &[
+            PathEl::MoveTo(Point { x: 16.98, y: 7.82 }),
+            PathEl::CurveTo(
+                Point { x: 15.22, y: 5.73 },
+                Point { x: 12.34, y: 4.96 },
+                Point { x: 9.78, y: 5.89 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 7.21, y: 6.83 },
+                Point { x: 5.50, y: 9.27 },
+                Point { x: 5.50, y: 12.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 5.50, y: 14.73 },
+                Point { x: 7.21, y: 17.17 },
+                Point { x: 9.78, y: 18.11 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 12.34, y: 19.04 },
+                Point { x: 15.22, y: 18.27 },
+                Point { x: 16.98, y: 16.18 },
+            ),
+            PathEl::LineTo(Point { x: 15.45, y: 14.89 }),
+            PathEl::CurveTo(
+                Point { x: 14.23, y: 16.34 },
+                Point { x: 12.24, y: 16.88 },
+                Point { x: 10.46, y: 16.23 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 8.68, y: 15.58 },
+                Point { x: 7.50, y: 13.89 },
+                Point { x: 7.50, y: 12.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 7.50, y: 10.11 },
+                Point { x: 8.68, y: 8.42 },
+                Point { x: 10.46, y: 7.77 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 12.24, y: 7.12 },
+                Point { x: 14.23, y: 7.66 },
+                Point { x: 15.45, y: 9.11 },
+            ),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 11.00, y: 21.00 }),
+            PathEl::LineTo(Point { x: 13.00, y: 21.00 }),
+            PathEl::LineTo(Point { x: 13.00, y: 3.00 }),
+            PathEl::LineTo(Point { x: 11.00, y: 3.00 }),
+            PathEl::ClosePath,
+        ],
+        opacity: 1.00,
+    }],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 33,
+};
+
+pub const CURRENCY_WON: IconPaths = IconPaths {
+    name: "currency_won",
+    category: "symbols_extra",
+    variant: "symbols_extra",
+    paths: &[IconPath {
+        els: &[
+            PathEl::MoveTo(Point { x: 3.96, y: 3.73 }),
+            PathEl::LineTo(Point { x: 8.46, y: 19.73 }),
+            PathEl::LineTo(Point { x: 6.54, y: 20.27 }),
+            PathEl::LineTo(Point { x: 2.04, y: 4.27 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 6.56, y: 19.65 }),
+            PathEl::LineTo(Point { x: 11.06, y: 7.65 }),
+            PathEl::LineTo(Point { x: 12.94, y: 8.35 }),
+            PathEl::LineTo(Point { x: 8.44, y: 20.35 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 12.94, y: 7.65 }),
+            PathEl::LineTo(Point { x: 17.44, y: 19.65 }),
+            PathEl::LineTo(Point { x: 15.56, y: 20.35 }),
+            PathEl::LineTo(Point { x: 11.06, y: 8.35 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 15.54, y: 19.73 }),
+            PathEl::LineTo(Point { x: 20.04, y: 3.73 }),
+            PathEl::LineTo(Point { x: 21.96, y: 4.27 }),
+            PathEl::LineTo(Point { x: 17.46, y: 20.27 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 2.00, y: 9.50 }),
+            PathEl::LineTo(Point { x: 22.00, y: 9.50 }),
+            PathEl::LineTo(Point { x: 22.00, y: 11.00 }),
+            PathEl::LineTo(Point { x: 2.00, y: 11.00 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 2.00, y: 13.00 }),
+            PathEl::LineTo(Point { x: 22.00, y: 13.00 }),
+            PathEl::LineTo(Point { x: 22.00, y: 14.50 }),
+            PathEl::LineTo(Point { x: 2.00, y: 14.50 }),
+            PathEl::ClosePath,
+        ],
+        opacity: 1.00,
+    }],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 31,
+};
+
+pub const RAINY: IconPaths = IconPaths {
+    name: "rainy",
+    category: "symbols_extra",
+    variant: "symbols_extra",
+    paths: &[IconPath {
+        els: &[
+            PathEl::MoveTo(Point { x: 12.00, y: 11.00 }),
+            PathEl::CurveTo(
+                Point { x: 12.00, y: 13.21 },
+                Point { x: 10.21, y: 15.00 },
+                Point { x: 8.00, y: 15.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 5.79, y: 15.00 },
+                Point { x: 4.00, y: 13.21 },
+                Point { x: 4.00, y: 11.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 4.00, y: 8.79 },
+                Point { x: 5.79, y: 7.00 },
+                Point { x: 8.00, y: 7.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 10.21, y: 7.00 },
+                Point { x: 12.00, y: 8.79 },
+                Point { x: 12.00, y: 11.00 },
+            ),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 18.00, y: 8.50 }),
+            PathEl::CurveTo(
+                Point { x: 18.00, y: 11.26 },
+                Point { x: 15.76, y: 13.50 },
+                Point { x: 13.00, y: 13.50 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 10.24, y: 13.50 },
+                Point { x: 8.00, y: 11.26 },
+                Point { x: 8.00, y: 8.50 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 8.00, y: 5.74 },
+                Point { x: 10.24, y: 3.50 },
+                Point { x: 13.00, y: 3.50 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 15.76, y: 3.50 },
+                Point { x: 18.00, y: 5.74 },
+                Point { x: 18.00, y: 8.50 },
+            ),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 20.50, y: 11.50 }),
+            PathEl::CurveTo(
+                Point { x: 20.50, y: 13.43 },
+                Point { x: 18.93, y: 15.00 },
+                Point { x: 17.00, y: 15.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 15.07, y: 15.00 },
+                Point { x: 13.50, y: 13.43 },
+                Point { x: 13.50, y: 11.50 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 13.50, y: 9.57 },
+                Point { x: 15.07, y: 8.00 },
+                Point { x: 17.00, y: 8.00 },
+            ),
+            PathEl::CurveTo(
+                Point { x: 18.93, y: 8.00 },
+                Point { x: 20.50, y: 9.57 },
+                Point { x: 20.50, y: 11.50 },
+            ),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 8.00, y: 11.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 11.00 }),
+            PathEl::LineTo(Point { x: 17.00, y: 15.00 }),
+            PathEl::LineTo(Point { x: 8.00, y: 15.00 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 9.37, y: 17.72 }),
+            PathEl::LineTo(Point { x: 8.37, y: 21.72 }),
+            PathEl::LineTo(Point { x: 6.63, y: 21.28 }),
+            PathEl::LineTo(Point { x: 7.63, y: 17.28 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 13.87, y: 17.72 }),
+            PathEl::LineTo(Point { x: 12.87, y: 21.72 }),
+            PathEl::LineTo(Point { x: 11.13, y: 21.28 }),
+            PathEl::LineTo(Point { x: 12.13, y: 17.28 }),
+            PathEl::ClosePath,
+            PathEl::MoveTo(Point { x: 18.37, y: 17.72 }),
+            PathEl::LineTo(Point { x: 17.37, y: 21.72 }),
+            PathEl::LineTo(Point { x: 15.63, y: 21.28 }),
+            PathEl::LineTo(Point { x: 16.63, y: 17.28 }),
+            PathEl::ClosePath,
+        ],
+        opacity: 1.00,
+    }],
+    size: Size {
+        width: 24.00,
+        height: 24.00,
+    },
+    complexity: 63,
+};
+/// Every icon in the pack as `(name, icon)` pairs, sorted by name.
+pub const ALL: &[(&str, IconPaths)] = &[
+    ("arrow_left_right", ARROW_LEFT_RIGHT),
+    ("arrow_up_down", ARROW_UP_DOWN),
+    ("clear_night", CLEAR_NIGHT),
+    ("currency_cent", CURRENCY_CENT),
+    ("currency_won", CURRENCY_WON),
+    ("rainy", RAINY),
+];
+/// The number of icons in [`ALL`].
+pub const COUNT: usize = ALL.len();
+/// The names of the icons in [`ALL`], in the same order.
+pub const NAMES: &[&str] = &crate::names::<COUNT>(ALL);
+/// Where the pack's icons come from and the license they are under.
+pub const ATTRIBUTION: crate::Attribution = crate::Attribution {
+    license: "MIT OR Apache-2.0",
+    copyright: "The druid-material-icons contributors",
+    source: "https://github.com/derekdreery/druid-material-icons",
+    release: None,
+    commit: None,
+};
@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+use druid::{
+    kurbo::{RoundedRect, Size},
+    widget::{prelude::*, Label},
+    Color, Command, Data, Point, Rect, WidgetPod,
+};
+
+use crate::{
+    icon::{self, ease, progress},
+    state_layer, theme, Icon, IconPaths,
+};
+
+/// The size of the pill behind the icon of the selected tab, from the Material 3 navigation bar.
+const INDICATOR_SIZE: Size = Size::new(64., 32.);
+/// The size of the icon.
+const ICON_SIZE: f64 = 24.;
+/// The space between the indicator and the label.
+const LABEL_GAP: f64 = 4.;
+/// How long the tab takes to change between selected and unselected.
+const TRANSITION: Duration = Duration::from_millis(200);
+
+/// An item of a tab bar or bottom navigation bar: an icon with an optional label that selects a
+/// tab when clicked.
+///
+/// The data is the selected tab, and the item is selected when it is the same as the item's
+/// own value. When it becomes selected a pill of [`theme::SECONDARY_CONTAINER`] grows behind
+/// the icon, the icon changes to [`theme::ON_SECONDARY_CONTAINER`], and icons with a filled
+/// version (see [`IconPaths::fill_pair`]) fill in.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{Data, Widget};
+/// use druid::widget::Flex;
+/// use druid_material_icons::{TabItem, HOME, SEARCH, SETTINGS};
+/// #[derive(Debug, Clone, Copy, PartialEq, Data)]
+/// enum Tab {
+///     Home,
+///     Search,
+///     Settings,
+/// }
+///
+/// fn navigation_bar() -> impl Widget<Tab> {
+///     Flex::row()
+///         .with_flex_child(TabItem::new(HOME, Tab::Home).with_label("Home"), 1.)
+///         .with_flex_child(TabItem::new(SEARCH, Tab::Search).with_label("Search"), 1.)
+///         .with_flex_child(TabItem::new(SETTINGS, Tab::Settings).with_label("Settings"), 1.)
+/// }
+/// ```
+pub struct TabItem<T> {
+    icon: WidgetPod<(), Icon>,
+    label: Option<WidgetPod<T, Label<T>>>,
+    value: T,
+    command: Option<Command>,
+    /// How selected the item looks, from 0 to 1.
+    selection: f64,
+    /// An animation of `selection` from the given value towards whether we're selected.
+    transition: Option<(f64, Duration)>,
+}
+
+impl<T: Data> TabItem<T> {
+    /// An item showing `paths` that selects `value`.
+    ///
+    /// If `paths` has a filled version, the item shows the outlined version when unselected and
+    /// the filled version when selected, whichever of the two is given.
+    pub fn new(paths: IconPaths, value: T) -> Self {
+        // The color and fill are set from the selection.
+        let icon = Icon::new(paths, Color::BLACK).with_fill_animation(TRANSITION);
+        TabItem {
+            icon: WidgetPod::new(icon),
+            label: None,
+            value,
+            command: None,
+            selection: 0.,
+            transition: None,
+        }
+    }
+
+    /// Builder-style method to show `text` under the icon.
+    pub fn with_label(mut self, text: impl Into<String>) -> Self {
+        self.label = Some(WidgetPod::new(Label::new(text.into())));
+        self
+    }
+
+    /// Builder-style method to submit `command` whenever the item is clicked, including when it
+    /// is already selected, e.g. to scroll the tab back to the top.
+    pub fn on_activate(mut self, command: impl Into<Command>) -> Self {
+        self.command = Some(command.into());
+        self
+    }
+
+    /// The inner icon, for configuring its appearance.
+    #[inline]
+    pub fn icon_mut(&mut self) -> &mut Icon {
+        self.icon.widget_mut()
+    }
+
+    fn selected(&self, data: &T) -> bool {
+        data.same(&self.value)
+    }
+
+    /// The color to draw the label in.
+    fn label_color(selected: bool, env: &Env) -> Color {
+        let defaults = &theme::MATERIAL3_SCHEME;
+        if selected {
+            theme::color_or(env, &theme::ON_SURFACE, defaults.on_surface)
+        } else {
+            theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant)
+        }
+    }
+
+    /// The rectangle of the indicator when fully grown.
+    fn indicator_rect(&self, size: Size) -> Rect {
+        let origin = Point::new(0.5 * (size.width - INDICATOR_SIZE.width), 0.);
+        Rect::from_origin_size(origin, INDICATOR_SIZE)
+    }
+}
+
+impl<T: Data> Widget<T> for TabItem<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(_) if !ctx.is_disabled() => {
+                ctx.set_active(true);
+                ctx.request_paint();
+            }
+            Event::MouseUp(_) if ctx.is_active() => {
+                ctx.set_active(false);
+                ctx.request_paint();
+                if ctx.is_hot() && !ctx.is_disabled() {
+                    *data = self.value.clone();
+                    if let Some(command) = &self.command {
+                        ctx.submit_command(command.clone());
+                    }
+                }
+            }
+            Event::AnimFrame(interval) => {
+                let target = if self.selected(data) { 1. } else { 0. };
+                if let Some((from, elapsed)) = &mut self.transition {
+                    *elapsed += Duration::from_nanos(*interval);
+                    let t = progress(*elapsed, TRANSITION);
+                    self.selection = *from + (target - *from) * ease(t);
+                    if t < 1. {
+                        ctx.request_anim_frame();
+                    } else {
+                        self.transition = None;
+                    }
+                    ctx.request_paint();
+                }
+            }
+            _ => (),
+        }
+        self.icon.event(ctx, event, &mut (), env);
+        if let Some(label) = &mut self.label {
+            label.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        match event {
+            LifeCycle::WidgetAdded => {
+                let selected = self.selected(data);
+                self.selection = if selected { 1. } else { 0. };
+                self.icon.widget_mut().set_fill_amount(self.selection);
+                if let Some(label) = &mut self.label {
+                    label
+                        .widget_mut()
+                        .set_text_color(Self::label_color(selected, env));
+                }
+            }
+            LifeCycle::HotChanged(_) | LifeCycle::DisabledChanged(_) => ctx.request_paint(),
+            _ => (),
+        }
+        self.icon.lifecycle(ctx, event, &(), env);
+        if let Some(label) = &mut self.label {
+            label.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let selected = self.selected(data);
+        let changed = self.selected(old_data) != selected;
+        if changed {
+            // Start from wherever we are, in case we're part way through a transition.
+            self.transition = Some((self.selection, Duration::ZERO));
+            ctx.request_anim_frame();
+            let fill = if selected { 1. } else { 0. };
+            ctx.submit_command(icon::SET_FILL_AMOUNT.with(fill).to(self.icon.id()));
+        }
+        if let Some(label) = &mut self.label {
+            if changed || ctx.env_changed() {
+                label
+                    .widget_mut()
+                    .set_text_color(Self::label_color(selected, env));
+                ctx.request_layout();
+            }
+            label.update(ctx, data, env);
+        }
+        if ctx.env_changed() {
+            ctx.request_paint();
+        }
+        self.icon.update(ctx, &(), env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let label_size = match &mut self.label {
+            Some(label) => label.layout(ctx, &bc.loosen(), data, env),
+            None => Size::ZERO,
+        };
+        let height = match self.label {
+            Some(_) => INDICATOR_SIZE.height + LABEL_GAP + label_size.height,
+            None => INDICATOR_SIZE.height,
+        };
+        let size = bc.constrain(Size::new(
+            INDICATOR_SIZE.width.max(label_size.width),
+            height,
+        ));
+
+        let icon_bc = BoxConstraints::tight(Size::new(ICON_SIZE, ICON_SIZE));
+        let icon_size = self.icon.layout(ctx, &icon_bc, &(), env);
+        let icon_origin = Point::new(
+            0.5 * (size.width - icon_size.width),
+            0.5 * (INDICATOR_SIZE.height - icon_size.height),
+        );
+        self.icon.set_origin(ctx, icon_origin);
+        if let Some(label) = &mut self.label {
+            let origin = Point::new(
+                0.5 * (size.width - label_size.width),
+                INDICATOR_SIZE.height + LABEL_GAP,
+            );
+            label.set_origin(ctx, origin);
+        }
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let defaults = &theme::MATERIAL3_SCHEME;
+        let full = self.indicator_rect(ctx.size());
+        let radius = 0.5 * full.height();
+
+        if self.selection > 0. {
+            // The indicator grows out from the middle.
+            let width = full.width() * self.selection;
+            let indicator = Rect::from_center_size(full.center(), (width, full.height()));
+            let container = theme::color_or(
+                env,
+                &theme::SECONDARY_CONTAINER,
+                defaults.secondary_container,
+            );
+            ctx.fill(RoundedRect::from_rect(indicator, radius), &container);
+        }
+
+        let unselected =
+            theme::color_or(env, &theme::ON_SURFACE_VARIANT, defaults.on_surface_variant);
+        let selected = theme::color_or(
+            env,
+            &theme::ON_SECONDARY_CONTAINER,
+            defaults.on_secondary_container,
+        );
+        let content = mix(unselected, selected, self.selection);
+        state_layer::paint(ctx, RoundedRect::from_rect(full, radius), content, env);
+        self.icon.widget_mut().set_color(content);
+        self.icon.paint(ctx, &(), env);
+        if let Some(label) = &mut self.label {
+            label.paint(ctx, data, env);
+        }
+    }
+}
+
+/// The color `t` of the way from `from` to `to`.
+fn mix(from: Color, to: Color, t: f64) -> Color {
+    let (r0, g0, b0, a0) = from.as_rgba();
+    let (r1, g1, b1, a1) = to.as_rgba();
+    let lerp = |a: f64, b: f64| a + (b - a) * t;
+    Color::rgba(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1), lerp(a0, a1))
+}
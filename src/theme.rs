@@ -0,0 +1,187 @@
+//! [`Env`](druid::Env) keys for theming icons.
+//!
+//! None of these keys need to be set: icons fall back to sensible defaults when they are missing.
+//! The color keys default to the Material 3 baseline light scheme. Use [`material2`] or
+//! [`material3`] to set all the keys at once.
+
+use druid::{Color, Env, Key};
+
+/// The width icons prefer when their constraints allow it.
+///
+/// Without this, icons prefer the size they were designed at (usually 24px).
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{AppLauncher, WindowDesc, widget::Label};
+/// use druid_material_icons::theme;
+/// # fn launch(window: WindowDesc<()>) {
+/// // Use larger icons for a touch-friendly layout.
+/// AppLauncher::with_window(window).configure_env(|env, _| env.set(theme::ICON_SIZE, 32.0));
+/// # }
+/// ```
+pub const ICON_SIZE: Key<f64> = Key::new("druid-material-icons.theme.icon-size");
+
+/// Whether icons should be drawn for maximum legibility.
+///
+/// When set, icons ignore the opacity of their color and paths, though they still fade in and
+/// out during transitions, and are never laid out smaller than [`HIGH_CONTRAST_MIN_SIZE`] unless
+/// their constraints require it.
+pub const HIGH_CONTRAST: Key<bool> = Key::new("druid-material-icons.theme.high-contrast");
+
+/// The smallest width icons prefer when [`HIGH_CONTRAST`] is set.
+///
+/// Defaults to [`DEFAULT_HIGH_CONTRAST_MIN_SIZE`].
+pub const HIGH_CONTRAST_MIN_SIZE: Key<f64> =
+    Key::new("druid-material-icons.theme.high-contrast-min-size");
+
+/// The value used when [`HIGH_CONTRAST_MIN_SIZE`] is not set.
+pub const DEFAULT_HIGH_CONTRAST_MIN_SIZE: f64 = 24.;
+
+/// Whether [`HIGH_CONTRAST`] is set in `env`.
+pub(crate) fn high_contrast(env: &Env) -> bool {
+    env.try_get(HIGH_CONTRAST).unwrap_or(false)
+}
+
+/// Whether the layout runs right to left, so that directional icons like breadcrumb chevrons
+/// should point the other way.
+///
+/// Druid has no notion of layout direction, so set this alongside a right-to-left locale.
+pub const RIGHT_TO_LEFT: Key<bool> = Key::new("druid-material-icons.theme.right-to-left");
+
+/// Whether [`RIGHT_TO_LEFT`] is set in `env`.
+#[cfg(feature = "druid")]
+pub(crate) fn right_to_left(env: &Env) -> bool {
+    env.try_get(RIGHT_TO_LEFT).unwrap_or(false)
+}
+
+/// The container color of [`IconButtonStyle::Filled`](crate::IconButtonStyle::Filled) buttons.
+pub const PRIMARY: Key<Color> = Key::new("druid-material-icons.theme.primary");
+
+/// The icon color of [`IconButtonStyle::Filled`](crate::IconButtonStyle::Filled) buttons.
+pub const ON_PRIMARY: Key<Color> = Key::new("druid-material-icons.theme.on-primary");
+
+/// The container color of [`IconButtonStyle::FilledTonal`](crate::IconButtonStyle::FilledTonal)
+/// buttons.
+pub const SECONDARY_CONTAINER: Key<Color> =
+    Key::new("druid-material-icons.theme.secondary-container");
+
+/// The icon color of [`IconButtonStyle::FilledTonal`](crate::IconButtonStyle::FilledTonal)
+/// buttons.
+pub const ON_SECONDARY_CONTAINER: Key<Color> =
+    Key::new("druid-material-icons.theme.on-secondary-container");
+
+/// The color disabled icon buttons are drawn with, at reduced opacity.
+pub const ON_SURFACE: Key<Color> = Key::new("druid-material-icons.theme.on-surface");
+
+/// The icon color of standard and outlined icon buttons.
+pub const ON_SURFACE_VARIANT: Key<Color> =
+    Key::new("druid-material-icons.theme.on-surface-variant");
+
+/// The border color of [`IconButtonStyle::Outlined`](crate::IconButtonStyle::Outlined) buttons.
+pub const OUTLINE: Key<Color> = Key::new("druid-material-icons.theme.outline");
+
+/// Look up a color, falling back to `default` when it isn't set.
+pub(crate) fn color_or(env: &Env, key: &Key<Color>, default: Color) -> Color {
+    env.try_get(key).unwrap_or(default)
+}
+
+/// The opacity of the state layer drawn over hovered interactive icons.
+///
+/// Defaults to [`DEFAULT_HOVER_OPACITY`].
+pub const HOVER_OPACITY: Key<f64> = Key::new("druid-material-icons.theme.hover-opacity");
+
+/// The opacity of the state layer drawn over focused interactive icons.
+///
+/// Defaults to [`DEFAULT_FOCUS_OPACITY`].
+pub const FOCUS_OPACITY: Key<f64> = Key::new("druid-material-icons.theme.focus-opacity");
+
+/// The opacity of the state layer drawn over pressed interactive icons.
+///
+/// Defaults to [`DEFAULT_PRESSED_OPACITY`].
+pub const PRESSED_OPACITY: Key<f64> = Key::new("druid-material-icons.theme.pressed-opacity");
+
+/// The value used when [`HOVER_OPACITY`] is not set, from the Material spec.
+pub const DEFAULT_HOVER_OPACITY: f64 = 0.08;
+
+/// The value used when [`FOCUS_OPACITY`] is not set, from the Material spec.
+pub const DEFAULT_FOCUS_OPACITY: f64 = 0.12;
+
+/// The value used when [`PRESSED_OPACITY`] is not set, from the Material spec.
+pub const DEFAULT_PRESSED_OPACITY: f64 = 0.12;
+
+/// Set every key to the Material 2 defaults.
+///
+/// # Examples
+///
+/// ```
+/// # use druid::{AppLauncher, WindowDesc};
+/// use druid_material_icons::theme;
+/// # fn launch(window: WindowDesc<()>) {
+/// AppLauncher::with_window(window).configure_env(|env, _| theme::material2(env));
+/// # }
+/// ```
+pub fn material2(env: &mut Env) {
+    MATERIAL2_SCHEME.install(env);
+    env.set(ICON_SIZE, 24.);
+    env.set(HIGH_CONTRAST_MIN_SIZE, DEFAULT_HIGH_CONTRAST_MIN_SIZE);
+    env.set(HOVER_OPACITY, 0.04);
+    env.set(FOCUS_OPACITY, 0.12);
+    env.set(PRESSED_OPACITY, 0.1);
+}
+
+/// Set every key to the Material 3 defaults, which are also used for keys that aren't set.
+pub fn material3(env: &mut Env) {
+    MATERIAL3_SCHEME.install(env);
+    env.set(ICON_SIZE, 24.);
+    env.set(HIGH_CONTRAST_MIN_SIZE, DEFAULT_HIGH_CONTRAST_MIN_SIZE);
+    env.set(HOVER_OPACITY, DEFAULT_HOVER_OPACITY);
+    env.set(FOCUS_OPACITY, DEFAULT_FOCUS_OPACITY);
+    env.set(PRESSED_OPACITY, DEFAULT_PRESSED_OPACITY);
+}
+
+/// Values for each of the color keys.
+pub(crate) struct Scheme {
+    pub primary: Color,
+    pub on_primary: Color,
+    pub secondary_container: Color,
+    pub on_secondary_container: Color,
+    pub on_surface: Color,
+    pub on_surface_variant: Color,
+    pub outline: Color,
+}
+
+impl Scheme {
+    fn install(&self, env: &mut Env) {
+        env.set(PRIMARY, self.primary.clone());
+        env.set(ON_PRIMARY, self.on_primary.clone());
+        env.set(SECONDARY_CONTAINER, self.secondary_container.clone());
+        env.set(ON_SECONDARY_CONTAINER, self.on_secondary_container.clone());
+        env.set(ON_SURFACE, self.on_surface.clone());
+        env.set(ON_SURFACE_VARIANT, self.on_surface_variant.clone());
+        env.set(OUTLINE, self.outline.clone());
+    }
+}
+
+/// The Material 2 baseline scheme. Material 2 has no tonal containers, so the secondary color
+/// stands in for them, and icons and outlines use black at medium and low emphasis.
+const MATERIAL2_SCHEME: Scheme = Scheme {
+    primary: Color::rgb8(0x62, 0x00, 0xee),
+    on_primary: Color::WHITE,
+    secondary_container: Color::rgb8(0x03, 0xda, 0xc6),
+    on_secondary_container: Color::BLACK,
+    on_surface: Color::BLACK,
+    on_surface_variant: Color::rgba8(0, 0, 0, 138),
+    outline: Color::rgba8(0, 0, 0, 31),
+};
+
+/// The Material 3 baseline light scheme.
+pub(crate) const MATERIAL3_SCHEME: Scheme = Scheme {
+    primary: Color::rgb8(0x67, 0x50, 0xa4),
+    on_primary: Color::WHITE,
+    secondary_container: Color::rgb8(0xe8, 0xde, 0xf8),
+    on_secondary_container: Color::rgb8(0x1d, 0x19, 0x2b),
+    on_surface: Color::rgb8(0x1c, 0x1b, 0x1f),
+    on_surface_variant: Color::rgb8(0x49, 0x45, 0x4f),
+    outline: Color::rgb8(0x79, 0x74, 0x7e),
+};
@@ -0,0 +1,42 @@
+//! Javascript bindings to the icon data, so that a Rust + JS app can use a single source of
+//! icons on both sides.
+//!
+//! Build with `--no-default-features --features wasm` for `wasm32-unknown-unknown` and run
+//! `wasm-bindgen` over the result as usual.
+
+use wasm_bindgen::prelude::*;
+
+/// Every icon name, sorted.
+#[wasm_bindgen(js_name = iconNames)]
+pub fn icon_names() -> Vec<String> {
+    crate::ALL
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// The names of every icon whose name contains `query`, sorted.
+#[wasm_bindgen]
+pub fn search(query: &str) -> Vec<String> {
+    crate::search(query)
+        .map(|icon| icon.name.to_owned())
+        .collect()
+}
+
+/// The named icon as a standalone SVG document, filled with `currentColor`, or `undefined` if
+/// there is no such icon.
+#[wasm_bindgen(js_name = iconSvg)]
+pub fn icon_svg(name: &str) -> Option<String> {
+    crate::lookup(name).ok().map(|icon| icon.to_svg())
+}
+
+/// The SVG path data of each path of the named icon, or `undefined` if there is no such icon.
+#[wasm_bindgen(js_name = iconPathData)]
+pub fn icon_path_data(name: &str) -> Option<Vec<String>> {
+    crate::lookup(name).ok().map(|icon| {
+        icon.paths
+            .iter()
+            .map(|path| path.to_svg_path_data())
+            .collect()
+    })
+}
@@ -0,0 +1,181 @@
+//! Icons as [Xilem](https://github.com/linebender/xilem) views, painted with vello.
+//!
+//! This shares the icon data with the druid widgets, so an app that is moving from druid to
+//! Xilem can use the same icons on both sides while it migrates. Build with the `xilem` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use druid_material_icons::{xilem::icon, ADD};
+//! use xilem::{palette::css::WHITE, WidgetView};
+//! fn add_icon() -> impl WidgetView<u32> {
+//!     icon(ADD, WHITE)
+//! }
+//! ```
+
+use ::xilem::{
+    core::{MessageContext, MessageResult, Mut, View, ViewMarker},
+    masonry::{
+        accesskit::{Node, Role},
+        core::{
+            AccessCtx, BoxConstraints, ChildrenIds, LayoutCtx, NoAction, PaintCtx, PropertiesMut,
+            PropertiesRef, RegisterCtx, Widget, WidgetMut,
+        },
+        kurbo::{self, Affine, BezPath, Size},
+        peniko::Fill,
+        vello::Scene,
+    },
+    Color, Pod, ViewCtx,
+};
+
+use crate::{IconPaths, PathEl};
+
+/// A view showing `paths` in `color`, stretched to fill its layout size.
+///
+/// Without other constraints the icon is the size it was designed at (usually 24px).
+pub fn icon(paths: IconPaths, color: Color) -> IconView {
+    IconView { paths, color }
+}
+
+/// The [`View`] created by [`icon`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct IconView {
+    paths: IconPaths,
+    color: Color,
+}
+
+impl ViewMarker for IconView {}
+impl<State, Action> View<State, Action, ViewCtx> for IconView {
+    type Element = Pod<IconWidget>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        (ctx.create_pod(IconWidget::new(self.paths, self.color)), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        // Icons are the same if they share path data, as for `Data`.
+        if !std::ptr::eq(prev.paths.paths, self.paths.paths) || prev.paths.size != self.paths.size {
+            IconWidget::set_icon(&mut element, self.paths);
+        }
+        if prev.color != self.color {
+            IconWidget::set_color(&mut element, self.color);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        _: &mut MessageContext,
+        _: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) -> MessageResult<Action> {
+        // Icons don't send messages.
+        MessageResult::Stale
+    }
+}
+
+/// The masonry widget behind [`IconView`].
+pub struct IconWidget {
+    paths: IconPaths,
+    color: Color,
+    /// The icon's paths in masonry's version of kurbo, which may not be the one we use.
+    shapes: Vec<(BezPath, f64)>,
+}
+
+impl IconWidget {
+    /// A widget showing `paths` in `color`.
+    pub fn new(paths: IconPaths, color: Color) -> Self {
+        IconWidget {
+            paths,
+            color,
+            shapes: convert(paths),
+        }
+    }
+
+    /// Change the icon being displayed.
+    pub fn set_icon(this: &mut WidgetMut<'_, Self>, paths: IconPaths) {
+        this.widget.paths = paths;
+        this.widget.shapes = convert(paths);
+        this.ctx.request_layout();
+    }
+
+    /// Change the color the icon is painted with.
+    pub fn set_color(this: &mut WidgetMut<'_, Self>, color: Color) {
+        this.widget.color = color;
+        this.ctx.request_paint_only();
+    }
+}
+
+impl Widget for IconWidget {
+    type Action = NoAction;
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let crate::Size { width, height } = self.paths.size;
+        bc.constrain_aspect_ratio(height / width, width)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.size();
+        let transform = Affine::scale_non_uniform(
+            size.width / self.paths.size.width,
+            size.height / self.paths.size.height,
+        );
+        for (shape, opacity) in &self.shapes {
+            let color = self.color.multiply_alpha(*opacity as f32);
+            scene.fill(Fill::NonZero, transform, color, None, shape);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Image
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+}
+
+/// The paths of `icon` in masonry's kurbo, with their opacities.
+fn convert(icon: IconPaths) -> Vec<(BezPath, f64)> {
+    let point = |p: crate::Point| kurbo::Point::new(p.x, p.y);
+    icon.paths
+        .iter()
+        .map(|path| {
+            let els = path.els.iter().map(|el| match *el {
+                PathEl::MoveTo(p) => kurbo::PathEl::MoveTo(point(p)),
+                PathEl::LineTo(p) => kurbo::PathEl::LineTo(point(p)),
+                PathEl::QuadTo(p1, p2) => kurbo::PathEl::QuadTo(point(p1), point(p2)),
+                PathEl::CurveTo(p1, p2, p3) => {
+                    kurbo::PathEl::CurveTo(point(p1), point(p2), point(p3))
+                }
+                PathEl::ClosePath => kurbo::PathEl::ClosePath,
+            });
+            (els.collect(), path.opacity)
+        })
+        .collect()
+}
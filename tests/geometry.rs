@@ -0,0 +1,110 @@
+//! Check the geometry of every icon, including those of the companion packs that are built:
+//! that it lies within its declared size, as the generator checks, and that it fills the way
+//! upstream draws it.
+
+use druid_material_icons::{IconPaths, ALL};
+use kurbo::{BezPath, ParamCurve, PathEl, Shape, Vec2};
+
+/// Allow for the rounding applied to the generated coordinates.
+const EPSILON: f64 = 0.01;
+/// Moves test points off the exact coordinates of the generated points, where kurbo can count
+/// the winding wrong.
+const NUDGE: Vec2 = Vec2::new(1.3e-6, 1.7e-6);
+
+/// The material icons, then those of the companion packs.
+fn icons() -> impl Iterator<Item = &'static (&'static str, IconPaths)> {
+    let packs: &[&[(&str, IconPaths)]] = &[
+        #[cfg(feature = "brands")]
+        druid_material_icons::brands::ALL,
+        #[cfg(feature = "symbols_extra")]
+        druid_material_icons::symbols_extra::ALL,
+    ];
+    ALL.iter().chain(packs.iter().flat_map(|pack| pack.iter()))
+}
+
+#[test]
+fn geometry_within_bounds() {
+    let known = include_str!("../generate-icons/out-of-bounds.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>();
+    let mut offenders = vec![];
+    for (name, icon) in icons() {
+        if known.contains(name) {
+            continue;
+        }
+        for path in icon.paths {
+            let bbox = path.bounding_box();
+            if bbox.x0 < -EPSILON
+                || bbox.y0 < -EPSILON
+                || bbox.x1 > icon.size.width + EPSILON
+                || bbox.y1 > icon.size.height + EPSILON
+            {
+                offenders.push(format!("{} ({:?})", name, bbox));
+            }
+        }
+    }
+    assert!(
+        offenders.is_empty(),
+        "geometry out of bounds:\n{}",
+        offenders.join("\n")
+    );
+}
+
+/// Icons are filled with the non-zero rule, so a contour nested inside others has to wind so
+/// that it cuts a hole or fills one in, just as it would with the even-odd rule. The generator
+/// makes sure of this for upstream paths drawn with the even-odd rule, which would otherwise
+/// fill solid.
+#[test]
+fn nested_contours_leave_holes() {
+    let mut offenders = vec![];
+    for (name, icon) in icons() {
+        for path in icon.paths {
+            let contours = contours(path.els);
+            for (idx, inner) in contours.iter().enumerate() {
+                // The contours around this one, then itself.
+                let mut around: Vec<_> = contours
+                    .iter()
+                    .enumerate()
+                    .filter(|&(outer_idx, outer)| {
+                        outer_idx != idx
+                            && inner
+                                .segments()
+                                .all(|segment| outer.winding(segment.start() + NUDGE) != 0)
+                    })
+                    .map(|(_, outer)| outer)
+                    .collect();
+                around.push(inner);
+                let winding: f64 = around.iter().map(|outer| outer.area().signum()).sum();
+                let even_odd = around.len() % 2 == 1;
+                if (winding != 0.) != even_odd {
+                    offenders.push(format!("{} (contour {})", name, idx));
+                }
+            }
+        }
+    }
+    assert!(
+        offenders.is_empty(),
+        "contours filled differently by the non-zero and even-odd rules:\n{}",
+        offenders.join("\n")
+    );
+}
+
+/// The closed contours of a path.
+fn contours(els: &[PathEl]) -> Vec<BezPath> {
+    let mut contours = vec![];
+    let mut current = BezPath::new();
+    for &el in els {
+        if let PathEl::MoveTo(_) = el {
+            if !current.elements().is_empty() {
+                contours.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(el);
+    }
+    if !current.elements().is_empty() {
+        contours.push(current);
+    }
+    contours
+}
@@ -0,0 +1,89 @@
+//! Drive [`IconButton`]s through druid's test harness and count how often their actions run.
+
+use std::{cell::Cell, rc::Rc};
+
+use druid::{
+    tests::{harness::Harness, helpers::ModularWidget},
+    widget::prelude::*,
+    HotKey, KbKey, KeyEvent, Modifiers, Point, Selector, WidgetPod,
+};
+use druid_material_icons::{IconButton, HOTKEY_PRESSED, SAVE};
+
+/// Sent to focus the button under test.
+const FOCUS: Selector = Selector::new("druid-material-icons.tests.focus");
+
+/// `button`, inside a parent that focuses it on [`FOCUS`].
+fn focusable(button: IconButton<()>) -> impl Widget<()> {
+    ModularWidget::new(WidgetPod::new(button))
+        .event_fn(|button, ctx, event, data, env| {
+            if let Event::Command(cmd) = event {
+                if cmd.is(FOCUS) {
+                    ctx.set_focus(button.id());
+                }
+            }
+            button.event(ctx, event, data, env);
+        })
+        .lifecycle_fn(|button, ctx, event, data, env| button.lifecycle(ctx, event, data, env))
+        .update_fn(|button, ctx, _old_data, data, env| button.update(ctx, data, env))
+        .layout_fn(|button, ctx, bc, data, env| {
+            let size = button.layout(ctx, bc, data, env);
+            button.set_origin(ctx, Point::ORIGIN);
+            size
+        })
+        .paint_fn(|button, ctx, data, env| button.paint(ctx, data, env))
+}
+
+/// A button with the hotkey `s` that counts its clicks in `clicks`.
+fn counting_button(clicks: &Rc<Cell<u32>>) -> IconButton<()> {
+    let clicks = clicks.clone();
+    IconButton::new(SAVE)
+        .on_click(move |_ctx, _data, _env| clicks.set(clicks.get() + 1))
+        .with_hotkey(HotKey::new(None, "s"))
+}
+
+/// Press `key` the way an app forwarding every key press from its delegate does.
+fn press_forwarded(harness: &mut Harness<()>, key: KeyEvent) {
+    harness.submit_command(HOTKEY_PRESSED.with(key.clone()));
+    harness.event(Event::KeyDown(key));
+}
+
+#[test]
+fn forwarded_hotkey_fires_once_when_focused() {
+    let clicks = Rc::new(Cell::new(0));
+    Harness::create_simple((), focusable(counting_button(&clicks)), |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.submit_command(FOCUS);
+        press_forwarded(harness, KeyEvent::for_test(Modifiers::empty(), "s"));
+        assert_eq!(clicks.get(), 1);
+    });
+}
+
+#[test]
+fn forwarded_hotkey_fires_once_when_not_focused() {
+    let clicks = Rc::new(Cell::new(0));
+    Harness::create_simple((), focusable(counting_button(&clicks)), |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        press_forwarded(harness, KeyEvent::for_test(Modifiers::empty(), "s"));
+        assert_eq!(clicks.get(), 1);
+    });
+}
+
+#[test]
+fn space_and_enter_click_when_focused() {
+    let clicks = Rc::new(Cell::new(0));
+    Harness::create_simple((), focusable(counting_button(&clicks)), |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.event(Event::KeyDown(KeyEvent::for_test(Modifiers::empty(), " ")));
+        assert_eq!(clicks.get(), 0, "unfocused buttons ignore Space");
+        harness.submit_command(FOCUS);
+        harness.event(Event::KeyDown(KeyEvent::for_test(Modifiers::empty(), " ")));
+        harness.event(Event::KeyDown(KeyEvent::for_test(
+            Modifiers::empty(),
+            KbKey::Enter,
+        )));
+        assert_eq!(clicks.get(), 2);
+    });
+}
@@ -0,0 +1,189 @@
+//! Paint a sample of icons with piet's CPU backend and compare them against checked-in
+//! references, to catch regressions in how the widget transforms and colors icons.
+//!
+//! Run with `cargo test --features snapshot-tests`. A missing reference is a failure. Set
+//! `UPDATE_SNAPSHOTS=1` to write new references after an intended change or when adding a test,
+//! and check the images by eye before committing them.
+
+use std::{env, fs::File, io::BufWriter, path::PathBuf};
+
+use druid::{
+    kurbo::{PathEl, Point, Size},
+    piet::{Device, ImageFormat},
+    Color, Env,
+};
+use druid_material_icons::{self as icons, theme, ColorFilter, Icon, IconPath, IconPaths};
+
+/// How far a channel may differ from the reference, to allow for antialiasing differences
+/// between versions of the backend.
+const TOLERANCE: u8 = 8;
+
+/// Paint `icon` at `size` and compare the result with `tests/snapshots/{name}.png`.
+fn check(name: &str, icon: &Icon, size: Size, env: &Env) {
+    let (width, height) = (size.width as usize, size.height as usize);
+    let mut device = Device::new().expect("creating piet device");
+    let mut target = device
+        .bitmap_target(width, height, 1.)
+        .expect("creating bitmap target");
+    {
+        let mut rc = target.render_context();
+        icon.paint_into(&mut rc, size, env);
+        druid::RenderContext::finish(&mut rc).expect("finishing render");
+    }
+    let image = target
+        .to_image_buf(ImageFormat::RgbaPremul)
+        .expect("reading back pixels");
+    // Stored premultiplied, which is fine since the references are only compared against.
+    let actual = image.raw_pixels();
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.png", name));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        let file = BufWriter::new(File::create(&path).expect("creating snapshot"));
+        let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().expect("writing snapshot");
+        writer.write_image_data(actual).expect("writing snapshot");
+        return;
+    }
+
+    let file = File::open(&path).unwrap_or_else(|err| {
+        panic!(
+            "{}: can't open {} ({}); set UPDATE_SNAPSHOTS=1 to write it",
+            name,
+            path.display(),
+            err
+        )
+    });
+    let decoder = png::Decoder::new(file);
+    let mut reader = decoder.read_info().expect("reading snapshot");
+    let mut expected = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut expected).expect("reading snapshot");
+    assert_eq!(
+        (info.width as usize, info.height as usize),
+        (width, height),
+        "{}: snapshot has the wrong size",
+        name
+    );
+    let differing = actual
+        .iter()
+        .zip(&expected)
+        .filter(|(a, e)| a.abs_diff(**e) > TOLERANCE)
+        .count();
+    assert_eq!(
+        differing,
+        0,
+        "{}: {} channels differ from {}",
+        name,
+        differing,
+        path.display()
+    );
+}
+
+#[test]
+fn plain() {
+    let icon = icons::ADD.new(Color::BLACK);
+    check("plain", &icon, Size::new(48., 48.), &Env::empty());
+}
+
+#[test]
+fn non_uniform_scale() {
+    let icon = icons::ALARM.new(Color::rgb8(0x33, 0x66, 0x99));
+    check(
+        "non_uniform_scale",
+        &icon,
+        Size::new(64., 32.),
+        &Env::empty(),
+    );
+}
+
+#[test]
+fn translucent_color() {
+    let icon = icons::DELETE.new(Color::rgba8(0, 0, 0, 0x80));
+    check(
+        "translucent_color",
+        &icon,
+        Size::new(48., 48.),
+        &Env::empty(),
+    );
+}
+
+#[test]
+fn high_contrast_ignores_opacity() {
+    let icon = icons::DELETE.new(Color::rgba8(0, 0, 0, 0x80));
+    let mut env = Env::empty();
+    env.set(theme::HIGH_CONTRAST, true);
+    check("high_contrast", &icon, Size::new(48., 48.), &env);
+}
+
+#[test]
+fn halo() {
+    let icon = icons::STAR
+        .new(Color::WHITE)
+        .with_halo(2., Color::rgba8(0, 0, 0, 0xc0));
+    check("halo", &icon, Size::new(48., 48.), &Env::empty());
+}
+
+#[test]
+fn filter() {
+    let icon = icons::FAVORITE
+        .new(Color::rgb8(0xe0, 0x20, 0x40))
+        .with_filter(ColorFilter::Grayscale);
+    check("filter", &icon, Size::new(48., 48.), &Env::empty());
+}
+
+/// A square of the given half-width, centered in a 24x24 icon.
+macro_rules! square {
+    ($half:expr) => {
+        IconPaths {
+            name: "square",
+            category: "test",
+            variant: "test",
+            paths: &[IconPath {
+                els: &[
+                    PathEl::MoveTo(Point::new(12. - $half, 12. - $half)),
+                    PathEl::LineTo(Point::new(12. + $half, 12. - $half)),
+                    PathEl::LineTo(Point::new(12. + $half, 12. + $half)),
+                    PathEl::LineTo(Point::new(12. - $half, 12. + $half)),
+                    PathEl::ClosePath,
+                ],
+                opacity: 1.,
+            }],
+            size: Size::new(24., 24.),
+            complexity: 6,
+        }
+    };
+}
+
+static WEIGHT_MASTERS: &[(f64, IconPaths)] = &[(100., square!(4.)), (700., square!(10.))];
+
+#[test]
+fn weight_blend() {
+    let mut icon = icons::ADD
+        .new(Color::BLACK)
+        .with_weight_masters(WEIGHT_MASTERS);
+    // Halfway between the masters, so a square with half-width 7.
+    icon.set_weight(400.);
+    check("weight_blend", &icon, Size::new(48., 48.), &Env::empty());
+}
+
+#[test]
+fn fill_crossfade() {
+    // The outlined and filled hearts have different structures, so they are crossfaded.
+    let icon = icons::FAVORITE_BORDER
+        .new(Color::BLACK)
+        .with_fill_amount(0.5);
+    check("fill_crossfade", &icon, Size::new(48., 48.), &Env::empty());
+}
+
+#[test]
+fn to_image() {
+    let image = icons::ADD.to_image(24, 12, Color::WHITE).unwrap();
+    assert_eq!((image.width(), image.height()), (24, 12));
+    // The plus is stretched across the middle, and the corners are left empty.
+    let alpha = |x: usize, y: usize| image.raw_pixels()[(y * 24 + x) * 4 + 3];
+    assert_eq!(alpha(12, 6), 255);
+    assert_eq!(alpha(0, 0), 0);
+}